@@ -0,0 +1,81 @@
+use crate::{
+    storage::{same_layout, Storage, StorageMut},
+    MemoryPriority,
+};
+
+/// SaturatingAdd adds integers, clamping at the bounds of the type instead of wrapping.
+pub trait SaturatingAdd: Copy {
+    /// Returns the clamped sum and whether it was clamped.
+    fn saturating_add_flagged(self, rhs: Self) -> (Self, bool);
+}
+
+macro_rules! saturating_add_int {
+    ($($t:ty),*) => {
+        $(
+            impl SaturatingAdd for $t {
+                fn saturating_add_flagged(self, rhs: Self) -> (Self, bool) {
+                    match self.checked_add(rhs) {
+                        Some(sum) => (sum, false),
+                        None => (self.saturating_add(rhs), true),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+saturating_add_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Accumulate adds matrices onto long running integer counters, e.g. histograms or hit maps,
+/// where a wrap around would silently corrupt the counts.
+pub trait Accumulate<const R: usize, const C: usize, T> {
+    /// Adds every element of other to the element at the same location, clamping at the bounds
+    /// of T. Returns how many elements were clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, RowPrio, Accumulate, Export };
+    /// let mut hits = Stacktrix::<4, 2, 2, RowPrio, u8>::from_rows([[250, 1], [0, 255]]);
+    /// let frame = Stacktrix::<4, 2, 2, ColumnPrio, u8>::from_rows([[10, 1], [0, 1]]);
+    /// assert_eq!(hits.accumulate_saturating(&frame), 2);
+    /// assert_eq!(hits.to_rows(), [[255, 2], [0, 255]]);
+    /// ```
+    fn accumulate_saturating<O>(&mut self, other: &O) -> usize
+    where
+        O: Storage<R, C, T>;
+}
+
+impl<M, const R: usize, const C: usize, T> Accumulate<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+    T: SaturatingAdd,
+{
+    fn accumulate_saturating<O>(&mut self, other: &O) -> usize
+    where
+        O: Storage<R, C, T>,
+    {
+        let (dst, src) = (self.buffer_mut(), other.buffer());
+        let mut saturated = 0;
+        let mut add = |el: &mut T, x: T| {
+            let (sum, clamped) = el.saturating_add_flagged(x);
+            *el = sum;
+            saturated += clamped as usize;
+        };
+        if same_layout::<M::Priority, O::Priority>(R, C) {
+            for (el, &x) in dst.iter_mut().zip(src) {
+                add(el, x);
+            }
+        } else {
+            for row in 0..R {
+                for col in 0..C {
+                    add(
+                        &mut dst[M::Priority::offset((row, col), R, C)],
+                        src[O::Priority::offset((row, col), R, C)],
+                    );
+                }
+            }
+        }
+        saturated
+    }
+}
@@ -0,0 +1,308 @@
+use crate::{
+    sealed::Sealed, storage::Storage, ColumnPrio, MatrixError, MemoryPriority, Reftrix, RowPrio,
+    Stacktrix,
+};
+use alloc::vec::Vec;
+
+/// The memory priority of an [`AnyMatrix`], known only at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    /// See [`ColumnPrio`].
+    ColumnPrio,
+    /// See [`RowPrio`].
+    RowPrio,
+}
+
+impl Priority {
+    /// Returns the runtime counterpart of the memory priority P.
+    pub fn of<P: MemoryPriority>() -> Self {
+        if P::NAME == ColumnPrio::NAME {
+            Priority::ColumnPrio
+        } else {
+            Priority::RowPrio
+        }
+    }
+
+    fn offset(self, location: (usize, usize), rows: usize, cols: usize) -> usize {
+        match self {
+            Priority::ColumnPrio => ColumnPrio::offset(location, rows, cols),
+            Priority::RowPrio => RowPrio::offset(location, rows, cols),
+        }
+    }
+}
+
+/// The elements of an [`AnyMatrix`] in memory order, tagged with their type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyElements {
+    /// Elements of type u8.
+    U8(Vec<u8>),
+    /// Elements of type u16.
+    U16(Vec<u16>),
+    /// Elements of type u32.
+    U32(Vec<u32>),
+    /// Elements of type f32.
+    F32(Vec<f32>),
+    /// Elements of type f64.
+    F64(Vec<f64>),
+}
+
+/// A single element of an [`AnyMatrix`], tagged with its type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnyElement {
+    /// An element of type u8.
+    U8(u8),
+    /// An element of type u16.
+    U16(u16),
+    /// An element of type u32.
+    U32(u32),
+    /// An element of type f32.
+    F32(f32),
+    /// An element of type f64.
+    F64(f64),
+}
+
+impl AnyElement {
+    /// Converts the element to a f64, which holds every value of the supported types exactly.
+    pub fn to_f64(self) -> f64 {
+        match self {
+            AnyElement::U8(el) => el.into(),
+            AnyElement::U16(el) => el.into(),
+            AnyElement::U32(el) => el.into(),
+            AnyElement::F32(el) => el.into(),
+            AnyElement::F64(el) => el,
+        }
+    }
+}
+
+/// Element is implemented by the types an [`AnyMatrix`] can hold: u8, u16, u32, f32 and f64.
+///
+/// The trait is sealed.
+pub trait Element: Copy + Sealed {
+    /// Tags the elements with their type.
+    fn wrap(elements: Vec<Self>) -> AnyElements;
+    /// Returns the elements if they are of type Self.
+    fn unwrap(elements: &AnyElements) -> Option<&[Self]>;
+    /// Returns the elements mutably if they are of type Self.
+    fn unwrap_mut(elements: &mut AnyElements) -> Option<&mut [Self]>;
+    /// Tags the element with its type.
+    fn tag(self) -> AnyElement;
+}
+
+macro_rules! element {
+    ($($t:ty => $variant:ident),*) => {
+        $(
+            impl Sealed for $t {}
+
+            impl Element for $t {
+                fn wrap(elements: Vec<Self>) -> AnyElements {
+                    AnyElements::$variant(elements)
+                }
+
+                fn unwrap(elements: &AnyElements) -> Option<&[Self]> {
+                    match elements {
+                        AnyElements::$variant(elements) => Some(elements),
+                        _ => None,
+                    }
+                }
+
+                fn unwrap_mut(elements: &mut AnyElements) -> Option<&mut [Self]> {
+                    match elements {
+                        AnyElements::$variant(elements) => Some(elements),
+                        _ => None,
+                    }
+                }
+
+                fn tag(self) -> AnyElement {
+                    AnyElement::$variant(self)
+                }
+            }
+        )*
+    };
+}
+
+element!(u8 => U8, u16 => U16, u32 => U32, f32 => F32, f64 => F64);
+
+/// AnyMatrix erases the shape, the memory priority and the element type of a matrix, e.g. for
+/// pipelines that load matrices at runtime.
+///
+/// The shape and the memory priority are checked at runtime instead. A matrix of known type is
+/// recovered with [`AnyMatrix::as_reftrix`] or [`AnyMatrix::to_stacktrix`].
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ AnyElement, AnyElements, AnyMatrix, Priority, RowPrio, RowPrioMatrix };
+/// let mut m = AnyMatrix::new(2, 3, Priority::RowPrio, AnyElements::U16(vec![1, 2, 3, 4, 5, 6]))
+///     .unwrap();
+/// assert_eq!(m.get((1, 0)), Some(AnyElement::U16(4)));
+/// assert!(m.as_reftrix::<2, 3, RowPrio, u8>().is_none());
+/// let mut r = m.as_reftrix::<2, 3, RowPrio, u16>().unwrap();
+/// r.fill_row(0, &[0, 0, 0]);
+/// assert_eq!(m.get((0, 2)).map(AnyElement::to_f64), Some(0.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnyMatrix {
+    rows: usize,
+    cols: usize,
+    priority: Priority,
+    elements: AnyElements,
+}
+
+impl AnyMatrix {
+    /// Constructs an AnyMatrix of shape rows x cols from elements in memory order of priority.
+    ///
+    /// Returns [`MatrixError::InvalidShape`] if there are not rows * cols elements.
+    pub fn new(
+        rows: usize,
+        cols: usize,
+        priority: Priority,
+        elements: AnyElements,
+    ) -> Result<Self, MatrixError> {
+        let size = match &elements {
+            AnyElements::U8(elements) => elements.len(),
+            AnyElements::U16(elements) => elements.len(),
+            AnyElements::U32(elements) => elements.len(),
+            AnyElements::F32(elements) => elements.len(),
+            AnyElements::F64(elements) => elements.len(),
+        };
+        if rows.checked_mul(cols) != Some(size) {
+            return Err(MatrixError::InvalidShape { size, rows, cols });
+        }
+        Ok(Self {
+            rows,
+            cols,
+            priority,
+            elements,
+        })
+    }
+
+    /// Copies a [`Reftrix`] or [`Stacktrix`] into an AnyMatrix with the same memory priority.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ AnyMatrix, Priority, Stacktrix, ColumnPrio };
+    /// let m = Stacktrix::<4, 2, 2, ColumnPrio, f32>::identity();
+    /// let any = AnyMatrix::from_matrix(&m);
+    /// assert_eq!((any.shape(), any.priority()), ((2, 2), Priority::ColumnPrio));
+    /// ```
+    pub fn from_matrix<M, const R: usize, const C: usize, T>(matrix: &M) -> Self
+    where
+        M: Storage<R, C, T>,
+        T: Element,
+    {
+        Self {
+            rows: R,
+            cols: C,
+            priority: Priority::of::<M::Priority>(),
+            elements: T::wrap(matrix.buffer().to_vec()),
+        }
+    }
+
+    /// Returns the amount of rows and columns.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the memory priority.
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// Returns the elements in memory order.
+    pub fn elements(&self) -> &AnyElements {
+        &self.elements
+    }
+
+    /// Consumes the matrix and returns the elements in memory order.
+    pub fn into_elements(self) -> AnyElements {
+        self.elements
+    }
+
+    /// Returns the element at location, or None if it is out of bounds.
+    pub fn get(&self, location: (usize, usize)) -> Option<AnyElement> {
+        if location.0 >= self.rows || location.1 >= self.cols {
+            return None;
+        }
+        let index = self.priority.offset(location, self.rows, self.cols);
+        Some(match &self.elements {
+            AnyElements::U8(elements) => elements[index].tag(),
+            AnyElements::U16(elements) => elements[index].tag(),
+            AnyElements::U32(elements) => elements[index].tag(),
+            AnyElements::F32(elements) => elements[index].tag(),
+            AnyElements::F64(elements) => elements[index].tag(),
+        })
+    }
+
+    /// Reorders the elements into the memory order of priority.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ AnyElements, AnyMatrix, Priority };
+    /// let m = AnyMatrix::new(2, 2, Priority::RowPrio, AnyElements::U8(vec![1, 2, 3, 4])).unwrap();
+    /// let m = m.into_priority(Priority::ColumnPrio);
+    /// assert_eq!(m.elements(), &AnyElements::U8(vec![1, 3, 2, 4]));
+    /// ```
+    pub fn into_priority(self, priority: Priority) -> Self {
+        if priority == self.priority {
+            return self;
+        }
+        let elements = match &self.elements {
+            AnyElements::U8(elements) => self.reorder(elements, priority),
+            AnyElements::U16(elements) => self.reorder(elements, priority),
+            AnyElements::U32(elements) => self.reorder(elements, priority),
+            AnyElements::F32(elements) => self.reorder(elements, priority),
+            AnyElements::F64(elements) => self.reorder(elements, priority),
+        };
+        Self {
+            priority,
+            elements,
+            ..self
+        }
+    }
+
+    /// Borrows the matrix as a [`Reftrix`], or returns None if the shape, the memory priority or
+    /// the element type differ.
+    pub fn as_reftrix<const R: usize, const C: usize, P, T>(
+        &mut self,
+    ) -> Option<Reftrix<'_, R, C, P, T>>
+    where
+        P: MemoryPriority,
+        T: Element,
+    {
+        if (R, C) != self.shape() || Priority::of::<P>() != self.priority {
+            return None;
+        }
+        T::unwrap_mut(&mut self.elements).map(Reftrix::from_values)
+    }
+
+    /// Copies the matrix into a [`Stacktrix`], or returns None if the shape or the element type
+    /// differ. The elements are reordered if the memory priority differs.
+    pub fn to_stacktrix<const S: usize, const R: usize, const C: usize, P, T>(
+        &self,
+    ) -> Option<Stacktrix<S, R, C, P, T>>
+    where
+        P: MemoryPriority,
+        T: Element,
+    {
+        if (R, C) != self.shape() {
+            return None;
+        }
+        let elements = T::unwrap(&self.elements)?;
+        Some(Stacktrix::from_fn(|row, col| {
+            elements[self.priority.offset((row, col), R, C)]
+        }))
+    }
+
+    fn reorder<T: Element>(&self, elements: &[T], priority: Priority) -> AnyElements {
+        let mut reordered = elements.to_vec();
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                reordered[priority.offset((row, col), self.rows, self.cols)] =
+                    elements[self.priority.offset((row, col), self.rows, self.cols)];
+            }
+        }
+        T::wrap(reordered)
+    }
+}
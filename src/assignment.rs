@@ -0,0 +1,108 @@
+use crate::{storage::Storage, MatrixError, MemoryPriority};
+use core::ops::{Add, Sub};
+
+/// Assignment solves the linear assignment problem on cost matrices, e.g. matching workers
+/// (rows) to jobs (columns).
+pub trait Assignment<const R: usize, const C: usize, T> {
+    /// Returns the column assigned to every row, so that no column is assigned twice and the sum
+    /// of the costs of the assigned elements is minimal.
+    ///
+    /// Uses the Hungarian algorithm in O(R * R * C) time, the potentials and bookkeeping take
+    /// O(R + C) words on the stack. A matrix with fewer rows than columns is treated as
+    /// if it was padded with rows of zeros, the surplus columns stay unassigned. The potentials
+    /// are kept non negative, so unsigned element types are fine as long as the sums of costs
+    /// do not overflow.
+    ///
+    /// Returns [`MatrixError::NotSquare`] if R > C, solve the transposed problem in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, Assignment };
+    /// let cost = Stacktrix::<9, 3, 3, RowPrio, u32>::from_rows([
+    ///     [4, 1, 3],
+    ///     [2, 0, 5],
+    ///     [3, 2, 2],
+    /// ]);
+    /// // 1 + 2 + 2 beats the greedy choice of the zero in the second row.
+    /// assert_eq!(cost.min_cost_assignment(), Ok([1, 0, 2]));
+    /// ```
+    fn min_cost_assignment(&self) -> Result<[usize; R], MatrixError>;
+}
+
+impl<M, const R: usize, const C: usize, T> Assignment<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+    T: Copy + PartialOrd + From<u8> + Add<Output = T> + Sub<Output = T>,
+{
+    fn min_cost_assignment(&self) -> Result<[usize; R], MatrixError> {
+        if R > C {
+            return Err(MatrixError::NotSquare { rows: R, cols: C });
+        }
+        let buffer = self.buffer();
+        let cost = |row, col| buffer[M::Priority::offset((row, col), R, C)];
+        // The dual is kept as row potentials u and negated column potentials w, the reduced
+        // cost cost + w - u of every element stays non negative.
+        let mut u = [T::from(0); R];
+        let mut w = [T::from(0); C];
+        // assigned[col] is the row matched to col, way[col] the column before it on the
+        // alternating path, None stands for the row being inserted.
+        let mut assigned: [Option<usize>; C] = [None; C];
+        let mut way: [Option<usize>; C] = [None; C];
+        for row in 0..R {
+            let mut min_reduced: [Option<T>; C] = [None; C];
+            let mut visited = [false; C];
+            // The column the alternating path currently ends in, None is the start at row.
+            let mut current: Option<usize> = None;
+            loop {
+                let from = current.map_or(row, |col| {
+                    visited[col] = true;
+                    assigned[col].expect("visited columns are assigned")
+                });
+                let mut delta = None;
+                let mut next = 0;
+                for col in (0..C).filter(|&col| !visited[col]) {
+                    let reduced = cost(from, col) + w[col] - u[from];
+                    if min_reduced[col].is_none_or(|min| reduced < min) {
+                        min_reduced[col] = Some(reduced);
+                        way[col] = current;
+                    }
+                    let min = min_reduced[col].expect("set above");
+                    if delta.is_none_or(|delta| min < delta) {
+                        delta = Some(min);
+                        next = col;
+                    }
+                }
+                // At most row columns are assigned, so R <= C leaves an unvisited column.
+                let delta = delta.expect("an unvisited column is left");
+                u[row] = u[row] + delta;
+                for col in 0..C {
+                    if visited[col] {
+                        let matched = assigned[col].expect("visited columns are assigned");
+                        u[matched] = u[matched] + delta;
+                        w[col] = w[col] + delta;
+                    } else if let Some(min) = min_reduced[col] {
+                        min_reduced[col] = Some(min - delta);
+                    }
+                }
+                current = Some(next);
+                if assigned[next].is_none() {
+                    break;
+                }
+            }
+            // Flips the alternating path back to its start.
+            while let Some(col) = current {
+                let previous = way[col];
+                assigned[col] = previous.map_or(Some(row), |prev| assigned[prev]);
+                current = previous;
+            }
+        }
+        let mut result = [0; R];
+        for (col, row) in assigned.iter().enumerate() {
+            if let Some(row) = *row {
+                result[row] = col;
+            }
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,358 @@
+use crate::{
+    storage::{dense, StorageMut},
+    MemoryPriority,
+};
+use alloc::vec::Vec;
+use core::{fmt::Debug, ops::Range};
+
+/// Operation is a mutating call recorded by [`Audited`], with the arguments it needs to be
+/// replayed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operation<T> {
+    /// value was inserted at location.
+    Insert {
+        /// The location that was written.
+        location: (usize, usize),
+        /// The value that was written.
+        value: T,
+    },
+    /// A row was filled with data.
+    FillRow {
+        /// The row that was filled.
+        row: usize,
+        /// The values of the row.
+        data: Vec<T>,
+    },
+    /// A column was filled with data.
+    FillCol {
+        /// The column that was filled.
+        col: usize,
+        /// The values of the column.
+        data: Vec<T>,
+    },
+    /// Two rows were swapped.
+    SwapRows {
+        /// The first row.
+        a: usize,
+        /// The second row.
+        b: usize,
+    },
+    /// A closure changed the window of the given rows and columns, e.g. through
+    /// [`Audited::apply_all`]. Since a closure can not be replayed, the values it left in the
+    /// window are recorded instead.
+    Apply {
+        /// The name the closure was recorded under.
+        name: &'static str,
+        /// The rows of the window.
+        rows: Range<usize>,
+        /// The columns of the window.
+        cols: Range<usize>,
+        /// The values of the window afterwards, row by row.
+        values: Vec<T>,
+    },
+}
+
+impl<T> Operation<T> {
+    /// Returns the name of the operation, the name of the closure for [`Operation::Apply`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            Operation::Insert { .. } => "insert",
+            Operation::FillRow { .. } => "fill_row",
+            Operation::FillCol { .. } => "fill_col",
+            Operation::SwapRows { .. } => "swap_rows",
+            Operation::Apply { name, .. } => name,
+        }
+    }
+
+    /// Returns the rows and columns of the window the operation wrote to in a matrix with
+    /// R rows and C columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::Operation;
+    /// let op = Operation::SwapRows::<u8> { a: 3, b: 1 };
+    /// assert_eq!(op.affected::<4, 5>(), (1..4, 0..5));
+    /// ```
+    pub fn affected<const R: usize, const C: usize>(&self) -> (Range<usize>, Range<usize>) {
+        match self {
+            Operation::Insert { location, .. } => {
+                (location.0..location.0 + 1, location.1..location.1 + 1)
+            }
+            Operation::FillRow { row, .. } => (*row..row + 1, 0..C),
+            Operation::FillCol { col, .. } => (0..R, *col..col + 1),
+            Operation::SwapRows { a, b } => (*a.min(b)..a.max(b) + 1, 0..C),
+            Operation::Apply { rows, cols, .. } => (rows.clone(), cols.clone()),
+        }
+    }
+
+    /// Applies the operation to matrix.
+    ///
+    /// # Panics
+    ///
+    /// If the operation does not fit into the matrix.
+    fn replay_on<M, const R: usize, const C: usize>(&self, matrix: &mut M)
+    where
+        M: StorageMut<R, C, T>,
+        T: Copy,
+    {
+        let (rows, cols) = self.affected::<R, C>();
+        assert!(
+            rows.end <= R && cols.end <= C,
+            "{} at rows {rows:?} and columns {cols:?} does not fit a {R}x{C} matrix",
+            self.name()
+        );
+        let at = |location| M::Priority::offset(location, R, C);
+        let buffer = matrix.buffer_mut();
+        match self {
+            Operation::Insert { location, value } => buffer[at(*location)] = *value,
+            Operation::FillRow { row, data } => {
+                for (col, &value) in data.iter().enumerate() {
+                    buffer[at((*row, col))] = value;
+                }
+            }
+            Operation::FillCol { col, data } => {
+                for (row, &value) in data.iter().enumerate() {
+                    buffer[at((row, *col))] = value;
+                }
+            }
+            Operation::SwapRows { a, b } => {
+                for col in 0..C {
+                    buffer.swap(at((*a, col)), at((*b, col)));
+                }
+            }
+            Operation::Apply { values, .. } => {
+                let locations = rows.flat_map(|row| cols.clone().map(move |col| (row, col)));
+                for (location, &value) in locations.zip(values) {
+                    buffer[at(location)] = value;
+                }
+            }
+        }
+    }
+}
+
+/// AuditLog is the list of operations an [`Audited`] matrix went through, in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditLog<T> {
+    operations: Vec<Operation<T>>,
+}
+
+impl<T> AuditLog<T> {
+    /// Returns the recorded operations.
+    pub fn operations(&self) -> &[Operation<T>] {
+        &self.operations
+    }
+
+    /// Returns the number of recorded operations.
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns true if no operation was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Applies the recorded operations to other in order, e.g. to a matrix of another memory
+    /// priority or a copy of the starting state of a second implementation.
+    ///
+    /// # Panics
+    ///
+    /// If an operation does not fit into other, which can only happen for a log of a matrix
+    /// with another shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Audited, Stacktrix, ColumnPrio, RowPrio };
+    /// let start = [[1, 2, 3], [4, 5, 6]];
+    /// let mut audited = Audited::new(Stacktrix::<6, 2, 3, RowPrio, u8>::from_rows(start));
+    /// audited.swap_rows(0, 1);
+    /// audited.apply_all(|el| *el *= 2);
+    /// let (matrix, log) = audited.into_parts();
+    ///
+    /// let mut other = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from_rows(start);
+    /// log.replay_on(&mut other);
+    /// assert_eq!(other, matrix);
+    /// ```
+    pub fn replay_on<M, const R: usize, const C: usize>(&self, other: &mut M)
+    where
+        M: StorageMut<R, C, T>,
+        T: Copy,
+    {
+        for operation in &self.operations {
+            operation.replay_on(other);
+        }
+    }
+}
+
+impl<T> Default for AuditLog<T> {
+    fn default() -> Self {
+        Self {
+            operations: Vec::new(),
+        }
+    }
+}
+
+/// Audited wraps a matrix and records every mutating call made through it into an
+/// [`AuditLog`], which can be replayed on another matrix.
+///
+/// This is meant for debugging, e.g. to find the first operation after which two
+/// implementations of the same sequence of rounds diverge. Only mutations made through the
+/// wrapper are recorded, [`Audited::apply`] records a closure that may change any part of the
+/// matrix.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ Audited, Operation, Stacktrix, ColumnPrio };
+/// let mut audited = Audited::new(Stacktrix::<4, 2, 2, ColumnPrio, u8>::zeros());
+/// audited.insert((1, 0), 7);
+/// audited.fill_col(1, &[1, 2]);
+/// let names: Vec<_> = audited.log().operations().iter().map(Operation::name).collect();
+/// assert_eq!(names, ["insert", "fill_col"]);
+/// assert_eq!(audited.matrix().as_slice(), &[0, 7, 1, 2]);
+/// ```
+pub struct Audited<M, const R: usize, const C: usize, T> {
+    matrix: M,
+    log: AuditLog<T>,
+}
+
+impl<M, const R: usize, const C: usize, T> Audited<M, R, C, T>
+where
+    M: StorageMut<R, C, T>,
+    T: Copy,
+{
+    /// Wraps matrix with an empty log.
+    pub fn new(matrix: M) -> Self {
+        Self {
+            matrix,
+            log: AuditLog::default(),
+        }
+    }
+
+    /// Returns the wrapped matrix.
+    pub fn matrix(&self) -> &M {
+        &self.matrix
+    }
+
+    /// Returns the log of the operations so far.
+    pub fn log(&self) -> &AuditLog<T> {
+        &self.log
+    }
+
+    /// Returns the log of the operations so far and starts a new one.
+    pub fn take_log(&mut self) -> AuditLog<T> {
+        core::mem::take(&mut self.log)
+    }
+
+    /// Unwraps the matrix and its log.
+    pub fn into_parts(self) -> (M, AuditLog<T>) {
+        (self.matrix, self.log)
+    }
+
+    /// Inserts value at location, see
+    /// [`ColumnPrioMatrix::insert`](crate::ColumnPrioMatrix::insert).
+    pub fn insert(&mut self, location: (usize, usize), value: T) {
+        dense::insert(&mut self.matrix, location, value);
+        self.record(Operation::Insert { location, value });
+    }
+
+    /// Fills row row with data, see
+    /// [`ColumnPrioMatrix::fill_row`](crate::ColumnPrioMatrix::fill_row).
+    pub fn fill_row(&mut self, row: usize, data: &[T]) {
+        dense::fill_row(&mut self.matrix, row, data);
+        self.record(Operation::FillRow {
+            row,
+            data: data.to_vec(),
+        });
+    }
+
+    /// Fills column col with data, see
+    /// [`ColumnPrioMatrix::fill_col`](crate::ColumnPrioMatrix::fill_col).
+    pub fn fill_col(&mut self, col: usize, data: &[T]) {
+        dense::fill_col(&mut self.matrix, col, data);
+        self.record(Operation::FillCol {
+            col,
+            data: data.to_vec(),
+        });
+    }
+
+    /// Swaps the rows a and b, see [`Pivot::swap_rows`](crate::Pivot::swap_rows).
+    ///
+    /// # Panics
+    ///
+    /// If a or b is out of bounds.
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(a < R && b < R, "rows ({a}, {b}) out of bounds of {R} rows");
+        let buffer = self.matrix.buffer_mut();
+        for col in 0..C {
+            buffer.swap(
+                M::Priority::offset((a, col), R, C),
+                M::Priority::offset((b, col), R, C),
+            );
+        }
+        self.record(Operation::SwapRows { a, b });
+    }
+
+    /// Applies f to every element, see
+    /// [`ColumnPrioMatrix::apply_all`](crate::ColumnPrioMatrix::apply_all).
+    pub fn apply_all<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        dense::apply_all(&mut self.matrix, f);
+        self.record_window("apply_all", 0..R, 0..C);
+    }
+
+    /// Runs op on the matrix and records it under name as having changed the window of the
+    /// given rows and columns.
+    ///
+    /// Changes outside of the window are not recorded, so a replay diverges from the matrix
+    /// if op makes any.
+    ///
+    /// # Panics
+    ///
+    /// If the window does not lie inside the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Audited, Operation, Stacktrix, RowPrio, RowPrioMatrix };
+    /// let mut audited = Audited::new(Stacktrix::<6, 3, 2, RowPrio, u8>::zeros());
+    /// audited.apply("shift row", 1..2, 0..2, |m| m.get_mut_row(1).fill(9));
+    /// let op = &audited.log().operations()[0];
+    /// assert_eq!(op.name(), "shift row");
+    /// assert_eq!(op.affected::<3, 2>(), (1..2, 0..2));
+    /// ```
+    pub fn apply<F>(&mut self, name: &'static str, rows: Range<usize>, cols: Range<usize>, op: F)
+    where
+        F: FnOnce(&mut M),
+    {
+        assert!(
+            rows.start <= rows.end && rows.end <= R && cols.start <= cols.end && cols.end <= C,
+            "window of rows {rows:?} and columns {cols:?} out of bounds of a {R}x{C} matrix"
+        );
+        op(&mut self.matrix);
+        self.record_window(name, rows, cols);
+    }
+
+    fn record_window(&mut self, name: &'static str, rows: Range<usize>, cols: Range<usize>) {
+        let buffer = self.matrix.buffer();
+        let values = rows
+            .clone()
+            .flat_map(|row| cols.clone().map(move |col| (row, col)))
+            .map(|location| buffer[M::Priority::offset(location, R, C)])
+            .collect();
+        self.record(Operation::Apply {
+            name,
+            rows,
+            cols,
+            values,
+        });
+    }
+
+    fn record(&mut self, operation: Operation<T>) {
+        self.log.operations.push(operation);
+    }
+}
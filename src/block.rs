@@ -0,0 +1,117 @@
+use crate::{
+    storage::{Storage, StorageMut},
+    MatrixError, MemoryPriority, Stacktrix,
+};
+
+/// BlockMatrix assembles a [`Stacktrix`] from smaller matrices.
+///
+/// Blocks are copied to an explicit position with [`block`](BlockMatrix::block) or one after
+/// the other along the main diagonal with [`diagonal`](BlockMatrix::diagonal). Every block is
+/// checked to fit into the R x C matrix, cells that are not covered by a block keep the fill
+/// value.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ BlockMatrix, Stacktrix, RowPrio, RowPrioMatrix, ColumnPrio };
+/// let a = Stacktrix::<4, 2, 2, RowPrio, u8>::with_values([1, 2, 3, 4]);
+/// let b = Stacktrix::<1, 1, 1, ColumnPrio, u8>::with_values([5]);
+/// let m = BlockMatrix::<9, 3, 3, RowPrio, u8>::new(0)
+///     .diagonal(&a)
+///     .unwrap()
+///     .diagonal(&b)
+///     .unwrap()
+///     .build();
+/// assert_eq!(m.get_row(0), &[1, 2, 0]);
+/// assert_eq!(m.get_row(1), &[3, 4, 0]);
+/// assert_eq!(m.get_row(2), &[0, 0, 5]);
+/// ```
+pub struct BlockMatrix<const S: usize, const R: usize, const C: usize, MemoryPriority, T> {
+    matrix: Stacktrix<S, R, C, MemoryPriority, T>,
+    cursor: (usize, usize),
+}
+
+impl<const S: usize, const R: usize, const C: usize, P, T> BlockMatrix<S, R, C, P, T>
+where
+    P: MemoryPriority,
+    T: Copy,
+{
+    /// Starts an R x C matrix where every cell holds fill.
+    pub fn new(fill: T) -> Self {
+        Self {
+            matrix: Stacktrix::with_values([fill; S]),
+            cursor: (0, 0),
+        }
+    }
+
+    /// Copies the block into the matrix, the upper left corner of the block is placed at
+    /// location (x, y).
+    ///
+    /// Returns [`MatrixError::OutOfBounds`] with the location where the block ends, one past its
+    /// lower right corner, if it does not fit into the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ BlockMatrix, Stacktrix, ColumnPrio, ColumnPrioMatrix, MatrixError };
+    /// let a = Stacktrix::<2, 1, 2, ColumnPrio, u8>::with_values([1, 2]);
+    /// assert_eq!(
+    ///     BlockMatrix::<6, 2, 3, ColumnPrio, u8>::new(0).block((1, 2), &a).err(),
+    ///     Some(MatrixError::OutOfBounds { row: 2, col: 4, rows: 2, cols: 3 })
+    /// );
+    /// let m = BlockMatrix::<6, 2, 3, ColumnPrio, u8>::new(0)
+    ///     .block((1, 1), &a)
+    ///     .unwrap()
+    ///     .build();
+    /// assert_eq!(m.get_column(2), &[0, 2]);
+    /// ```
+    pub fn block<M, const BR: usize, const BC: usize>(
+        mut self,
+        location: (usize, usize),
+        block: &M,
+    ) -> Result<Self, MatrixError>
+    where
+        M: Storage<BR, BC, T>,
+    {
+        let (row, col) = location;
+        let (row_end, col_end) = (row.checked_add(BR), col.checked_add(BC));
+        if row_end.is_none_or(|end| end > R) || col_end.is_none_or(|end| end > C) {
+            return Err(MatrixError::OutOfBounds {
+                row: row_end.unwrap_or(usize::MAX),
+                col: col_end.unwrap_or(usize::MAX),
+                rows: R,
+                cols: C,
+            });
+        }
+        let src = block.buffer();
+        let dst = self.matrix.buffer_mut();
+        for r in 0..BR {
+            for c in 0..BC {
+                dst[P::offset((row + r, col + c), R, C)] = src[M::Priority::offset((r, c), BR, BC)];
+            }
+        }
+        Ok(self)
+    }
+
+    /// Copies the block below and to the right of the previous diagonal block, starting at
+    /// (0, 0). Chaining diagonal calls builds a block diagonal matrix.
+    ///
+    /// Returns [`MatrixError::OutOfBounds`] if the block does not fit into the matrix.
+    pub fn diagonal<M, const BR: usize, const BC: usize>(
+        self,
+        block: &M,
+    ) -> Result<Self, MatrixError>
+    where
+        M: Storage<BR, BC, T>,
+    {
+        let location = self.cursor;
+        let mut this = self.block(location, block)?;
+        this.cursor = (location.0 + BR, location.1 + BC);
+        Ok(this)
+    }
+
+    /// Finishes the assembly and returns the matrix.
+    pub fn build(self) -> Stacktrix<S, R, C, P, T> {
+        self.matrix
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{
+    simd::Lane,
+    storage::{assert_col, assert_row, same_layout, Storage, StorageMut},
+    MemoryPriority,
+};
+
+/// BulkOps provides whole matrix and whole lane operations on matrices of `u8` and `u32`.
+///
+/// Continuous memory, i.e. the whole buffer or a row / column along the memory priority, is
+/// processed in bulk: with the `simd` feature 16 bytes at a time on x86_64. Strided lanes and
+/// matrices of different memory priorities fall back to element wise access.
+pub trait BulkOps<const R: usize, const C: usize, T> {
+    /// Substitutes every byte of every element with its entry in table, e.g. the AES SubBytes
+    /// step with the S-box as table. Elements of `u32` are substituted byte wise.
+    ///
+    /// The buffer is processed in one continuous pass regardless of the memory priority.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, BulkOps };
+    /// let mut table = [0u8; 256];
+    /// for (i, el) in table.iter_mut().enumerate() {
+    ///     *el = (i as u8).rotate_left(1);
+    /// }
+    /// let mut data = vec![0x01, 0x80, 0x0f, 0x10];
+    /// let mut m = Reftrix::<2, 2, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// m.substitute(&table);
+    /// assert_eq!(&data[..], &[0x02, 0x01, 0x1e, 0x20]);
+    /// ```
+    fn substitute(&mut self, table: &[u8; 256]);
+    /// Reverts [`substitute`](BulkOps::substitute) with the same table, e.g. the AES
+    /// InvSubBytes step with the forward S-box as table.
+    ///
+    /// # Panics
+    ///
+    /// If table is not a permutation of all 256 byte values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, BulkOps };
+    /// let mut table = [0u8; 256];
+    /// for (i, el) in table.iter_mut().enumerate() {
+    ///     *el = (i as u8).rotate_left(1) ^ 0x63;
+    /// }
+    /// let mut m = Stacktrix::<4, 2, 2, RowPrio, u32>::with_values([1, 2, 0xdead, 0xbeef]);
+    /// m.substitute(&table);
+    /// m.substitute_inverse(&table);
+    /// assert_eq!(m, Stacktrix::<4, 2, 2, RowPrio, u32>::with_values([1, 2, 0xdead, 0xbeef]));
+    /// ```
+    fn substitute_inverse(&mut self, table: &[u8; 256]);
+    /// Xors values onto column col.
+    ///
+    /// # Panics
+    ///
+    /// If col is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, BulkOps };
+    /// let mut data = vec![1u32, 2, 3, 4];
+    /// let mut m = Reftrix::<2, 2, RowPrio, u32>::from_values(&mut data[..]);
+    /// m.xor_col(1, &[0xff, 0xff]);
+    /// assert_eq!(&data[..], &[1, 0xfd, 3, 0xfb]);
+    /// ```
+    fn xor_col(&mut self, col: usize, values: &[T; R]);
+    /// Xors values onto row row.
+    ///
+    /// # Panics
+    ///
+    /// If row is out of bounds.
+    fn xor_row(&mut self, row: usize, values: &[T; C]);
+    /// Xors every element of other onto the element at the same location, e.g. the AES
+    /// AddRoundKey step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, Stacktrix, ColumnPrio, RowPrio, BulkOps };
+    /// let mut data = vec![0u8, 1, 2, 3];
+    /// let mut m = Reftrix::<2, 2, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// m.xor_assign(&Stacktrix::<4, 2, 2, RowPrio, u8>::with_values([1, 1, 0, 0]));
+    /// assert_eq!(&data[..], &[1, 1, 3, 3]);
+    /// ```
+    fn xor_assign<O>(&mut self, other: &O)
+    where
+        O: Storage<R, C, T>;
+    /// Adds every element of other to the element at the same location, wrapping on overflow.
+    fn wrapping_add_assign<O>(&mut self, other: &O)
+    where
+        O: Storage<R, C, T>;
+}
+
+impl<M, const R: usize, const C: usize, T> BulkOps<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+    T: Lane,
+{
+    fn substitute(&mut self, table: &[u8; 256]) {
+        T::substitute_bytes(self.buffer_mut(), table);
+    }
+
+    fn substitute_inverse(&mut self, table: &[u8; 256]) {
+        T::substitute_bytes(self.buffer_mut(), &invert_table(table));
+    }
+
+    fn xor_col(&mut self, col: usize, values: &[T; R]) {
+        assert_col::<C>(col);
+        let buffer = self.buffer_mut();
+        if M::Priority::row_stride(R, C) == 1 {
+            let start = M::Priority::offset((0, col), R, C);
+            T::xor(&mut buffer[start..start + R], values);
+        } else {
+            for (row, &value) in values.iter().enumerate() {
+                let el = &mut buffer[M::Priority::offset((row, col), R, C)];
+                *el = el.xor_one(value);
+            }
+        }
+    }
+
+    fn xor_row(&mut self, row: usize, values: &[T; C]) {
+        assert_row::<R>(row);
+        let buffer = self.buffer_mut();
+        if M::Priority::col_stride(R, C) == 1 {
+            let start = M::Priority::offset((row, 0), R, C);
+            T::xor(&mut buffer[start..start + C], values);
+        } else {
+            for (col, &value) in values.iter().enumerate() {
+                let el = &mut buffer[M::Priority::offset((row, col), R, C)];
+                *el = el.xor_one(value);
+            }
+        }
+    }
+
+    fn xor_assign<O>(&mut self, other: &O)
+    where
+        O: Storage<R, C, T>,
+    {
+        zip_with::<M, O, R, C, T>(self, other, T::xor, T::xor_one);
+    }
+
+    fn wrapping_add_assign<O>(&mut self, other: &O)
+    where
+        O: Storage<R, C, T>,
+    {
+        zip_with::<M, O, R, C, T>(self, other, T::wrapping_add, T::wrapping_add_one);
+    }
+}
+
+/// Combines the elements at the same locations, in bulk if both matrices share their memory
+/// priority.
+fn zip_with<M, O, const R: usize, const C: usize, T>(
+    m: &mut M,
+    other: &O,
+    bulk: fn(&mut [T], &[T]),
+    one: fn(T, T) -> T,
+) where
+    M: StorageMut<R, C, T>,
+    O: Storage<R, C, T>,
+    T: Copy,
+{
+    let (dst, src) = (m.buffer_mut(), other.buffer());
+    if same_layout::<M::Priority, O::Priority>(R, C) {
+        bulk(dst, src);
+        return;
+    }
+    for row in 0..R {
+        for col in 0..C {
+            let el = &mut dst[M::Priority::offset((row, col), R, C)];
+            *el = one(*el, src[O::Priority::offset((row, col), R, C)]);
+        }
+    }
+}
+
+/// Builds the table that maps every entry of table back to its index.
+///
+/// # Panics
+///
+/// If table is not a permutation of all 256 byte values.
+fn invert_table(table: &[u8; 256]) -> [u8; 256] {
+    let mut inverse = [0u8; 256];
+    let mut seen = [false; 256];
+    for (i, &el) in table.iter().enumerate() {
+        assert!(
+            !seen[el as usize],
+            "substitution table maps more than one byte to {el:#04x}"
+        );
+        seen[el as usize] = true;
+        inverse[el as usize] = i as u8;
+    }
+    inverse
+}
@@ -0,0 +1,137 @@
+//! Byte views of matrices, enabled by the `bytemuck` feature.
+//!
+//! Matrices of [`Pod`] elements are viewed as bytes and byte buffers as matrices without copying,
+//! e.g. to hand a state matrix to a cipher or to read it from a network buffer. The bytes hold
+//! the elements in memory order with the native endianness.
+use crate::{
+    storage::{Storage, StorageMut},
+    MatrixError, Reftrix, Stacktrix,
+};
+use bytemuck::{Pod, PodCastError};
+use core::mem::{align_of, size_of};
+
+/// ByteCast views the buffer of a matrix of [`Pod`] elements as bytes.
+pub trait ByteCast<const R: usize, const C: usize, T> {
+    /// Returns the buffer in memory order as bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ByteCast };
+    /// let m = Stacktrix::<2, 1, 2, ColumnPrio, u16>::with_values([0x0102, 0x0304]);
+    /// let expected: Vec<u8> = [0x0102u16, 0x0304].iter().flat_map(|el| el.to_ne_bytes()).collect();
+    /// assert_eq!(m.as_bytes(), &expected[..]);
+    /// ```
+    fn as_bytes(&self) -> &[u8];
+    /// Returns the buffer in memory order as mutable bytes.
+    fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        Self: StorageMut<R, C, T>;
+}
+
+impl<M, const R: usize, const C: usize, T> ByteCast<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+    T: Pod,
+{
+    fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.buffer())
+    }
+
+    fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        bytemuck::cast_slice_mut(self.buffer_mut())
+    }
+}
+
+impl<'a, const R: usize, const C: usize, MemoryPriority, T> Reftrix<'a, R, C, MemoryPriority, T>
+where
+    T: Pod,
+{
+    /// Constructs a Reftrix that views a byte buffer in place, the bytes hold the elements in
+    /// memory order with the native endianness.
+    ///
+    /// Returns [`MatrixError::SizeMismatch`] with sizes in bytes if the buffer does not hold
+    /// R * C elements, and [`MatrixError::Misaligned`] if it is not aligned for T.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix, MatrixError };
+    /// let mut words = [0u32; 4];
+    /// let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut words[..]);
+    /// let mut m = Reftrix::<2, 2, RowPrio, u32>::try_from_bytes_mut(bytes).unwrap();
+    /// m.fill_row(1, &[7, 7]);
+    /// assert_eq!(words, [0, 0, 7, 7]);
+    /// let mut bytes = [0u8; 15];
+    /// assert_eq!(
+    ///     Reftrix::<2, 2, RowPrio, u32>::try_from_bytes_mut(&mut bytes).err(),
+    ///     Some(MatrixError::SizeMismatch { expected: 16, got: 15 })
+    /// );
+    /// ```
+    pub fn try_from_bytes_mut(bytes: &'a mut [u8]) -> Result<Self, MatrixError> {
+        check_size::<T>(bytes.len(), R * C)?;
+        let len = bytes.len();
+        bytemuck::try_cast_slice_mut(bytes)
+            .map(Self::from_values)
+            .map_err(|error| match error {
+                PodCastError::TargetAlignmentGreaterAndInputNotAligned
+                | PodCastError::AlignmentMismatch => MatrixError::Misaligned {
+                    align: align_of::<T>(),
+                },
+                // Only zero sized elements pass the size check but can not be cast.
+                PodCastError::OutputSliceWouldHaveSlop | PodCastError::SizeMismatch => {
+                    MatrixError::SizeMismatch {
+                        expected: len,
+                        got: len,
+                    }
+                }
+            })
+    }
+}
+
+impl<const S: usize, const R: usize, const C: usize, MemoryPriority, T>
+    Stacktrix<S, R, C, MemoryPriority, T>
+where
+    T: Pod,
+{
+    /// Copies a Stacktrix out of a byte buffer of any alignment, the bytes hold the elements in
+    /// memory order with the native endianness.
+    ///
+    /// Returns [`MatrixError::SizeMismatch`] with sizes in bytes if the buffer does not hold
+    /// S elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio };
+    /// let bytes: Vec<u8> = [1u64, 2, 3, 4].iter().flat_map(|el| el.to_ne_bytes()).collect();
+    /// let m = Stacktrix::<4, 2, 2, ColumnPrio, u64>::try_from_bytes(&bytes[1..]);
+    /// assert!(m.is_err());
+    /// let m = Stacktrix::<4, 2, 2, ColumnPrio, u64>::try_from_bytes(&bytes).unwrap();
+    /// assert_eq!(m.as_slice(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, MatrixError> {
+        check_size::<T>(bytes.len(), S)?;
+        let mut chunks = bytes.chunks_exact(size_of::<T>().max(1));
+        let inner: [T; S] = core::array::from_fn(|_| {
+            chunks
+                .next()
+                .map_or_else(T::zeroed, bytemuck::pod_read_unaligned)
+        });
+        Ok(Self::with_values(inner))
+    }
+}
+
+/// Checks that len bytes hold exactly elements values of T.
+fn check_size<T>(len: usize, elements: usize) -> Result<(), MatrixError> {
+    if len != elements * size_of::<T>() {
+        return Err(MatrixError::SizeMismatch {
+            expected: elements * size_of::<T>(),
+            got: len,
+        });
+    }
+    Ok(())
+}
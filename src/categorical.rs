@@ -0,0 +1,101 @@
+use crate::{
+    storage::{assert_col, Storage, StorageMut},
+    MemoryPriority,
+};
+
+/// Categorical treats columns of a matrix as categorical features, e.g. to prepare them for a
+/// linear model.
+pub trait Categorical<const R: usize, const C: usize, T> {
+    /// Writes the one hot encoding of the column col to dst: the element (row, k) of dst is 1 if
+    /// the element (row, col) equals categories\[k\] and 0 otherwise. A row whose value is not
+    /// among the categories is all zeros.
+    ///
+    /// # Panics
+    ///
+    /// If the column is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, RowPrio, Categorical, Export };
+    /// let m = Stacktrix::<6, 3, 2, ColumnPrio, char>::from_rows([['r', 'x'], ['g', 'y'], ['r', 'z']]);
+    /// let mut encoded = Stacktrix::<9, 3, 3, RowPrio, f32>::zeros();
+    /// m.one_hot_encode_col(0, &['r', 'g', 'b'], &mut encoded);
+    /// assert_eq!(
+    ///     encoded.to_rows(),
+    ///     [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 0.0, 0.0]]
+    /// );
+    /// ```
+    fn one_hot_encode_col<D, U, const K: usize>(
+        &self,
+        col: usize,
+        categories: &[T; K],
+        dst: &mut D,
+    ) where
+        T: PartialEq,
+        D: StorageMut<R, K, U>,
+        U: From<u8>;
+    /// Replaces every element of the column col for which lookup returns a value with that
+    /// value, the other elements stay as they are. Returns how many elements were replaced.
+    ///
+    /// lookup can be backed by anything that maps categories, e.g. a `HashMap` or a `match`.
+    ///
+    /// # Panics
+    ///
+    /// If the column is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use mightrix::{ Stacktrix, RowPrio, Categorical, Export };
+    /// let mut m = Stacktrix::<4, 2, 2, RowPrio, u8>::from_rows([[1, b'a'], [2, b'c']]);
+    /// let codes = HashMap::from([(b'a', 0), (b'b', 1)]);
+    /// assert_eq!(m.map_col_categorical(1, |el| codes.get(el).copied()), 1);
+    /// assert_eq!(m.to_rows(), [[1, 0], [2, b'c']]);
+    /// ```
+    fn map_col_categorical<F>(&mut self, col: usize, lookup: F) -> usize
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&T) -> Option<T>;
+}
+
+impl<M, const R: usize, const C: usize, T> Categorical<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+{
+    fn one_hot_encode_col<D, U, const K: usize>(&self, col: usize, categories: &[T; K], dst: &mut D)
+    where
+        T: PartialEq,
+        D: StorageMut<R, K, U>,
+        U: From<u8>,
+    {
+        assert_col::<C>(col);
+        let buffer = self.buffer();
+        let dst = dst.buffer_mut();
+        for row in 0..R {
+            let value = &buffer[M::Priority::offset((row, col), R, C)];
+            for (k, category) in categories.iter().enumerate() {
+                dst[D::Priority::offset((row, k), R, K)] = U::from((value == category) as u8);
+            }
+        }
+    }
+
+    fn map_col_categorical<F>(&mut self, col: usize, mut lookup: F) -> usize
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&T) -> Option<T>,
+    {
+        assert_col::<C>(col);
+        let buffer = self.buffer_mut();
+        let mut replaced = 0;
+        for row in 0..R {
+            let el = &mut buffer[M::Priority::offset((row, col), R, C)];
+            if let Some(value) = lookup(el) {
+                *el = value;
+                replaced += 1;
+            }
+        }
+        replaced
+    }
+}
@@ -0,0 +1,276 @@
+use crate::MemoryPriority;
+use core::{
+    iter::StepBy,
+    ops::Range,
+    ops::{Index, IndexMut},
+    slice,
+};
+
+/// The elements of a diagonal lie at a constant distance in memory, the anti diagonal of a
+/// [`ColumnPrio`](crate::ColumnPrio) matrix is walked backwards.
+#[derive(Clone, Copy)]
+struct Layout {
+    step: usize,
+    len: usize,
+    reversed: bool,
+}
+
+impl Layout {
+    /// Returns the range of the buffer spanned by the main diagonal (i, i) or the anti
+    /// diagonal (i, C - 1 - i) of an R x C matrix and how to walk it.
+    fn new<P: MemoryPriority, const R: usize, const C: usize>(anti: bool) -> (Range<usize>, Self) {
+        let len = R.min(C);
+        let (row_stride, col_stride) = (P::row_stride(R, C), P::col_stride(R, C));
+        let (start, layout) = if !anti || len == 0 {
+            let step = row_stride + col_stride;
+            (
+                0,
+                Self {
+                    step,
+                    len,
+                    reversed: false,
+                },
+            )
+        } else {
+            let reversed = row_stride < col_stride;
+            let first = if reversed { len - 1 } else { 0 };
+            // A 1 x 1 matrix has the same strides for both directions.
+            let step = row_stride.abs_diff(col_stride).max(1);
+            (
+                P::offset((first, C - 1 - first), R, C),
+                Self {
+                    step,
+                    len,
+                    reversed,
+                },
+            )
+        };
+        let end = match len {
+            0 => start,
+            len => start + (len - 1) * layout.step + 1,
+        };
+        (start..end, layout)
+    }
+
+    fn position(&self, index: usize) -> usize {
+        if self.reversed {
+            (self.len - 1 - index) * self.step
+        } else {
+            index * self.step
+        }
+    }
+}
+
+/// DiagonalSlice is a view of the main diagonal or the anti diagonal of a matrix.
+///
+/// The diagonal of an R x C matrix has min(R, C) elements, the element i lies at (i, i) for
+/// the main diagonal and at (i, C - 1 - i) for the anti diagonal.
+pub struct DiagonalSlice<'a, T> {
+    buffer: &'a [T],
+    layout: Layout,
+}
+
+impl<'a, T> DiagonalSlice<'a, T> {
+    pub(crate) fn new<P: MemoryPriority, const R: usize, const C: usize>(
+        buffer: &'a [T],
+        anti: bool,
+    ) -> Self {
+        let (range, layout) = Layout::new::<P, R, C>(anti);
+        Self {
+            buffer: &buffer[range],
+            layout,
+        }
+    }
+
+    /// Returns the number of elements on the diagonal.
+    pub fn len(&self) -> usize {
+        self.layout.len
+    }
+
+    /// Returns true if the diagonal has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.layout.len == 0
+    }
+
+    /// Returns the element i of the diagonal or None if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        (index < self.layout.len).then(|| &self.buffer[self.layout.position(index)])
+    }
+
+    /// Returns an iterator over the diagonal starting at row 0.
+    pub fn iter(&self) -> DiagonalIter<'a, T> {
+        DiagonalIter {
+            inner: self.buffer.iter().step_by(self.layout.step),
+            reversed: self.layout.reversed,
+        }
+    }
+}
+
+impl<T> Index<usize> for DiagonalSlice<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(el) => el,
+            None => panic!("Index {index} out of bounds {}", self.layout.len),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for DiagonalSlice<'a, T> {
+    type Item = &'a T;
+
+    type IntoIter = DiagonalIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// DiagonalSliceMut is a mutable view of the main diagonal or the anti diagonal of a matrix.
+///
+/// See [`DiagonalSlice`].
+pub struct DiagonalSliceMut<'a, T> {
+    buffer: &'a mut [T],
+    layout: Layout,
+}
+
+impl<'a, T> DiagonalSliceMut<'a, T> {
+    pub(crate) fn new<P: MemoryPriority, const R: usize, const C: usize>(
+        buffer: &'a mut [T],
+        anti: bool,
+    ) -> Self {
+        let (range, layout) = Layout::new::<P, R, C>(anti);
+        Self {
+            buffer: &mut buffer[range],
+            layout,
+        }
+    }
+
+    /// Returns the number of elements on the diagonal.
+    pub fn len(&self) -> usize {
+        self.layout.len
+    }
+
+    /// Returns true if the diagonal has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.layout.len == 0
+    }
+
+    /// Returns the element i of the diagonal or None if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        (index < self.layout.len).then(|| &self.buffer[self.layout.position(index)])
+    }
+
+    /// Returns the element i of the diagonal mutably or None if it is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.layout.len {
+            return None;
+        }
+        Some(&mut self.buffer[self.layout.position(index)])
+    }
+
+    /// Returns an iterator over the diagonal starting at row 0.
+    pub fn iter(&self) -> DiagonalIter<'_, T> {
+        DiagonalIter {
+            inner: self.buffer.iter().step_by(self.layout.step),
+            reversed: self.layout.reversed,
+        }
+    }
+
+    /// Returns a mutable iterator over the diagonal starting at row 0.
+    pub fn iter_mut(&mut self) -> DiagonalIterMut<'_, T> {
+        DiagonalIterMut {
+            inner: self.buffer.iter_mut().step_by(self.layout.step),
+            reversed: self.layout.reversed,
+        }
+    }
+
+    /// Sets every element of the diagonal to value.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for el in self.iter_mut() {
+            *el = value.clone();
+        }
+    }
+}
+
+impl<T> Index<usize> for DiagonalSliceMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(el) => el,
+            None => panic!("Index {index} out of bounds {}", self.layout.len),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for DiagonalSliceMut<'_, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.layout.len;
+        match self.get_mut(index) {
+            Some(el) => el,
+            None => panic!("Index {index} out of bounds {}", len),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for DiagonalSliceMut<'a, T> {
+    type Item = &'a mut T;
+
+    type IntoIter = DiagonalIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DiagonalIterMut {
+            inner: self.buffer.iter_mut().step_by(self.layout.step),
+            reversed: self.layout.reversed,
+        }
+    }
+}
+
+/// Iterator over the elements of a [`DiagonalSlice`] / [`DiagonalSliceMut`].
+pub struct DiagonalIter<'a, T> {
+    inner: StepBy<slice::Iter<'a, T>>,
+    reversed: bool,
+}
+
+impl<'a, T> Iterator for DiagonalIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reversed {
+            self.inner.next_back()
+        } else {
+            self.inner.next()
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Mutable iterator over the elements of a [`DiagonalSliceMut`].
+pub struct DiagonalIterMut<'a, T> {
+    inner: StepBy<slice::IterMut<'a, T>>,
+    reversed: bool,
+}
+
+impl<'a, T> Iterator for DiagonalIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reversed {
+            self.inner.next_back()
+        } else {
+            self.inner.next()
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
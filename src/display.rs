@@ -0,0 +1,125 @@
+use crate::MemoryPriority;
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    marker::PhantomData,
+};
+
+/// Grid formats a matrix as a grid, one line per row with aligned columns.
+///
+/// A Grid is created by `display_with` on [`ColumnPrioMatrix`](crate::ColumnPrioMatrix) /
+/// [`RowPrioMatrix`](crate::RowPrioMatrix), the given function is used to format every cell.
+/// Cells are right aligned and separated by a single space unless a different separator is set.
+pub struct Grid<'a, const R: usize, const C: usize, MemoryPriority, T, F> {
+    buffer: &'a [T],
+    cell: F,
+    separator: &'a str,
+    _prio: PhantomData<MemoryPriority>,
+}
+
+impl<'a, const R: usize, const C: usize, P, T, F> Grid<'a, R, C, P, T, F>
+where
+    P: MemoryPriority,
+    F: Fn(&T, &mut Formatter<'_>) -> fmt::Result,
+{
+    pub(crate) fn new(buffer: &'a [T], cell: F) -> Self {
+        Self {
+            buffer,
+            cell,
+            separator: " ",
+            _prio: PhantomData,
+        }
+    }
+
+    /// Sets the separator that is written between two cells of a row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix };
+    /// let mut data = vec![1, 2, 3, 4];
+    /// let m = Reftrix::<2, 2, RowPrio, u8>::from_values(&mut data[..]);
+    /// let grid = m.display_with(|el, f| write!(f, "{:02x}", el)).separator("-");
+    /// assert_eq!(grid.to_string(), "01-02\n03-04\n");
+    /// ```
+    pub fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+}
+
+struct Cell<'c, T, F>(&'c T, &'c F);
+
+impl<T, F> Display for Cell<'_, T, F>
+where
+    F: Fn(&T, &mut Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.1)(self.0, f)
+    }
+}
+
+/// Counts the characters written to it, used to measure cells without allocating.
+struct Width(usize);
+
+impl Write for Width {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+impl<const R: usize, const C: usize, P, T, F> Grid<'_, R, C, P, T, F>
+where
+    P: MemoryPriority,
+    F: Fn(&T, &mut Formatter<'_>) -> fmt::Result,
+{
+    fn cell(&self, location: (usize, usize)) -> Cell<'_, T, F> {
+        Cell(&self.buffer[P::offset(location, R, C)], &self.cell)
+    }
+
+    fn width(&self, location: (usize, usize)) -> Result<usize, fmt::Error> {
+        let mut width = Width(0);
+        write!(width, "{}", self.cell(location))?;
+        Ok(width.0)
+    }
+}
+
+impl<const R: usize, const C: usize, P, T, F> Display for Grid<'_, R, C, P, T, F>
+where
+    P: MemoryPriority,
+    F: Fn(&T, &mut Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Every cell is formatted twice, once to measure the width of its column and once to
+        // write it.
+        let mut widths = [0; C];
+        for (col, width) in widths.iter_mut().enumerate() {
+            for row in 0..R {
+                *width = (*width).max(self.width((row, col))?);
+            }
+        }
+        for row in 0..R {
+            for (col, width) in widths.iter().enumerate() {
+                if col != 0 {
+                    f.write_str(self.separator)?;
+                }
+                for _ in self.width((row, col))?..*width {
+                    f.write_char(' ')?;
+                }
+                write!(f, "{}", self.cell((row, col)))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats a cell with Display, passing on the precision of the formatter of the matrix.
+pub(crate) fn display_cell<T: Display>(
+    precision: Option<usize>,
+) -> impl Fn(&T, &mut Formatter<'_>) -> fmt::Result {
+    move |el, f| match precision {
+        Some(precision) => write!(f, "{:.*}", precision, el),
+        None => write!(f, "{}", el),
+    }
+}
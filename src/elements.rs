@@ -0,0 +1,312 @@
+use crate::{
+    storage::{Storage, StorageMut},
+    MemoryPriority, Position, Reftrix, Stacktrix,
+};
+use core::{iter::FusedIterator, marker::PhantomData, ptr::NonNull};
+
+/// The walk over the locations of a matrix in logical row major order.
+#[derive(Clone)]
+pub(crate) struct Cursor {
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+    index: usize,
+    len: usize,
+}
+
+impl Cursor {
+    pub(crate) fn new<P: MemoryPriority>(rows: usize, cols: usize) -> Self {
+        Self {
+            cols,
+            row_stride: P::row_stride(rows, cols),
+            col_stride: P::col_stride(rows, cols),
+            index: 0,
+            len: rows * cols,
+        }
+    }
+
+    /// Returns the next location and its offset in memory.
+    pub(crate) fn next(&mut self) -> Option<(Position, usize)> {
+        if self.index == self.len {
+            return None;
+        }
+        let (row, col) = (self.index / self.cols, self.index % self.cols);
+        self.index += 1;
+        Some(((row, col), row * self.row_stride + col * self.col_stride))
+    }
+
+    pub(crate) fn remaining(&self) -> usize {
+        self.len - self.index
+    }
+}
+
+/// Iterator over the elements of a matrix and their locations in logical row major order, see
+/// [`Elements::iter_indexed`].
+#[derive(Clone)]
+pub struct IterIndexed<'a, T> {
+    buffer: &'a [T],
+    cursor: Cursor,
+}
+
+impl<'a, T> Iterator for IterIndexed<'a, T> {
+    type Item = (Position, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (location, offset) = self.cursor.next()?;
+        Some((location, &self.buffer[offset]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cursor.remaining(), Some(self.cursor.remaining()))
+    }
+}
+
+impl<T> ExactSizeIterator for IterIndexed<'_, T> {}
+
+impl<T> FusedIterator for IterIndexed<'_, T> {}
+
+/// Iterator over the elements of a matrix mutably and their locations in logical row major
+/// order, see [`Elements::iter_mut_indexed`].
+pub struct IterMutIndexed<'a, T> {
+    start: NonNull<T>,
+    cursor: Cursor,
+    _borrow: PhantomData<&'a mut T>,
+}
+
+unsafe impl<T: Send> Send for IterMutIndexed<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMutIndexed<'_, T> {}
+
+impl<'a, T> IterMutIndexed<'a, T> {
+    fn new<P: MemoryPriority>(buffer: &'a mut [T], rows: usize, cols: usize) -> Self {
+        assert!(buffer.len() == rows * cols);
+        Self {
+            start: NonNull::from(buffer).cast(),
+            cursor: Cursor::new::<P>(rows, cols),
+            _borrow: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMutIndexed<'a, T> {
+    type Item = (Position, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (location, offset) = self.cursor.next()?;
+        // SAFETY: the cursor yields every offset of the mutably borrowed buffer at most once.
+        let el = unsafe { &mut *self.start.as_ptr().add(offset) };
+        Some((location, el))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cursor.remaining(), Some(self.cursor.remaining()))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMutIndexed<'_, T> {}
+
+impl<T> FusedIterator for IterMutIndexed<'_, T> {}
+
+/// Iterator over the elements of a matrix in logical row major order, see [`Elements::iter`].
+#[derive(Clone)]
+pub struct Iter<'a, T>(IterIndexed<'a, T>);
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, el)| el)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// Iterator over the elements of a matrix mutably in logical row major order, see
+/// [`Elements::iter_mut`].
+pub struct IterMut<'a, T>(IterMutIndexed<'a, T>);
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, el)| el)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// Elements iterates the elements of a matrix in logical order, independent of its memory
+/// priority.
+pub trait Elements<const R: usize, const C: usize, T> {
+    /// Returns an iterator over ((row, col), &element) in row major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Elements };
+    /// let m = Stacktrix::<4, 2, 2, ColumnPrio, u8>::from_rows([[1, 2], [3, 4]]);
+    /// let mut iter = m.iter_indexed();
+    /// assert_eq!(iter.next(), Some(((0, 0), &1)));
+    /// assert_eq!(iter.next(), Some(((0, 1), &2)));
+    /// assert_eq!(iter.next(), Some(((1, 0), &3)));
+    /// ```
+    fn iter_indexed(&self) -> IterIndexed<'_, T>;
+    /// Returns an iterator over ((row, col), &mut element) in row major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Elements };
+    /// let mut m = Stacktrix::<4, 2, 2, ColumnPrio, usize>::zeros();
+    /// for ((row, col), el) in m.iter_mut_indexed() {
+    ///     *el = row * 10 + col;
+    /// }
+    /// assert_eq!(m.as_slice(), &[0, 10, 1, 11]);
+    /// ```
+    fn iter_mut_indexed(&mut self) -> IterMutIndexed<'_, T>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Returns an iterator over the elements in row major order.
+    ///
+    /// Borrowed matrices implement IntoIterator with the same order, a [`Stacktrix`] by value
+    /// yields copies of its elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Elements };
+    /// let m = Stacktrix::<4, 2, 2, ColumnPrio, u8>::from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(m.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// assert_eq!((&m).into_iter().max(), Some(&4));
+    /// assert_eq!(m.into_iter().sum::<u8>(), 10);
+    /// ```
+    fn iter(&self) -> Iter<'_, T> {
+        Iter(self.iter_indexed())
+    }
+    /// Returns an iterator over the elements mutably in row major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Elements };
+    /// let mut m = Stacktrix::<4, 2, 2, ColumnPrio, u8>::zeros();
+    /// for (i, el) in m.iter_mut().enumerate() {
+    ///     *el = i as u8;
+    /// }
+    /// assert_eq!(m.as_slice(), &[0, 2, 1, 3]);
+    /// ```
+    fn iter_mut(&mut self) -> IterMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        IterMut(self.iter_mut_indexed())
+    }
+}
+
+impl<M, const R: usize, const C: usize, T> Elements<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+{
+    fn iter_indexed(&self) -> IterIndexed<'_, T> {
+        IterIndexed {
+            buffer: self.buffer(),
+            cursor: Cursor::new::<M::Priority>(R, C),
+        }
+    }
+
+    fn iter_mut_indexed(&mut self) -> IterMutIndexed<'_, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        IterMutIndexed::new::<M::Priority>(self.buffer_mut(), R, C)
+    }
+}
+
+impl<'b, const R: usize, const C: usize, P, T> IntoIterator for &'b Reftrix<'_, R, C, P, T>
+where
+    P: MemoryPriority,
+{
+    type Item = &'b T;
+    type IntoIter = Iter<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'b, const R: usize, const C: usize, P, T> IntoIterator for &'b mut Reftrix<'_, R, C, P, T>
+where
+    P: MemoryPriority,
+{
+    type Item = &'b mut T;
+    type IntoIter = IterMut<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// A Reftrix by value yields its elements mutably for the whole lifetime of the slice.
+impl<'a, const R: usize, const C: usize, P, T> IntoIterator for Reftrix<'a, R, C, P, T>
+where
+    P: MemoryPriority,
+{
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut(IterMutIndexed::new::<P>(self.into_inner(), R, C))
+    }
+}
+
+impl<'b, const S: usize, const R: usize, const C: usize, P, T> IntoIterator
+    for &'b Stacktrix<S, R, C, P, T>
+where
+    P: MemoryPriority,
+{
+    type Item = &'b T;
+    type IntoIter = Iter<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'b, const S: usize, const R: usize, const C: usize, P, T> IntoIterator
+    for &'b mut Stacktrix<S, R, C, P, T>
+where
+    P: MemoryPriority,
+{
+    type Item = &'b mut T;
+    type IntoIter = IterMut<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// A Stacktrix by value yields copies of its elements in logical row major order.
+impl<const S: usize, const R: usize, const C: usize, P, T> IntoIterator for Stacktrix<S, R, C, P, T>
+where
+    P: MemoryPriority,
+    T: Copy,
+{
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let buffer = self.as_slice();
+        core::array::from_fn(|index| buffer[P::offset((index / C, index % C), R, C)]).into_iter()
+    }
+}
@@ -0,0 +1,112 @@
+use core::fmt::Display;
+
+/// MatrixError is returned by the fallible constructors and operations on matrices.
+///
+/// New variants may be added in minor releases, so a match on it needs a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MatrixError {
+    /// The location (row, col) lies outside of a matrix with the given amount of rows and
+    /// columns.
+    OutOfBounds {
+        /// The requested row.
+        row: usize,
+        /// The requested column.
+        col: usize,
+        /// The amount of rows in the matrix.
+        rows: usize,
+        /// The amount of columns in the matrix.
+        cols: usize,
+    },
+    /// The row lies outside of a matrix with the given amount of rows.
+    RowOutOfBounds {
+        /// The requested row.
+        row: usize,
+        /// The amount of rows in the matrix.
+        rows: usize,
+    },
+    /// The column lies outside of a matrix with the given amount of columns.
+    ColumnOutOfBounds {
+        /// The requested column.
+        col: usize,
+        /// The amount of columns in the matrix.
+        cols: usize,
+    },
+    /// The given data does not have the amount of elements the operation requires.
+    SizeMismatch {
+        /// The amount of elements that was required.
+        expected: usize,
+        /// The amount of elements that was given.
+        got: usize,
+    },
+    /// The size of the storage does not equal rows * cols.
+    InvalidShape {
+        /// The amount of elements the storage holds.
+        size: usize,
+        /// The amount of rows of the matrix.
+        rows: usize,
+        /// The amount of columns of the matrix.
+        cols: usize,
+    },
+    /// The operation requires a square matrix.
+    NotSquare {
+        /// The amount of rows of the matrix.
+        rows: usize,
+        /// The amount of columns of the matrix.
+        cols: usize,
+    },
+    /// The matrix is singular, its rows are not linearly independent.
+    Singular,
+    /// The memory is not aligned for the element type.
+    Misaligned {
+        /// The alignment the element type requires in bytes.
+        align: usize,
+    },
+    /// The progress callback of the operation cancelled it.
+    Cancelled,
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            MatrixError::OutOfBounds {
+                row,
+                col,
+                rows,
+                cols,
+            } => write!(
+                f,
+                "location ({row}, {col}) out of bounds of a {rows}x{cols} matrix"
+            ),
+            MatrixError::RowOutOfBounds { row, rows } => {
+                write!(f, "row {row} out of bounds of a matrix with {rows} rows")
+            }
+            MatrixError::ColumnOutOfBounds { col, cols } => {
+                write!(
+                    f,
+                    "column {col} out of bounds of a matrix with {cols} columns"
+                )
+            }
+            MatrixError::SizeMismatch { expected, got } => {
+                write!(f, "expected {expected} elements but got {got}")
+            }
+            MatrixError::InvalidShape { size, rows, cols } => {
+                write!(
+                    f,
+                    "storage of size {size} can not hold a {rows}x{cols} matrix"
+                )
+            }
+            MatrixError::NotSquare { rows, cols } => {
+                write!(f, "expected a square matrix but got a {rows}x{cols} matrix")
+            }
+            MatrixError::Singular => write!(f, "the matrix is singular"),
+            MatrixError::Misaligned { align } => {
+                write!(f, "the memory is not aligned to {align} bytes")
+            }
+            MatrixError::Cancelled => write!(f, "the operation was cancelled"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MatrixError {}
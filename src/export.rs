@@ -0,0 +1,115 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    storage::{same_layout, Storage, StorageMut},
+    MemoryPriority,
+};
+
+/// Export copies a matrix out in logical order, independent of its memory priority.
+pub trait Export<const R: usize, const C: usize, T> {
+    /// Copies the matrix into an array of its rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Export };
+    /// let m = Stacktrix::<6, 2, 3, ColumnPrio, u8>::with_values([1, 4, 2, 5, 3, 6]);
+    /// assert_eq!(m.to_rows(), [[1, 2, 3], [4, 5, 6]]);
+    /// ```
+    fn to_rows(&self) -> [[T; C]; R];
+    /// Copies the matrix into an array of its columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, Export };
+    /// let m = Stacktrix::<6, 2, 3, RowPrio, u8>::with_values([1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(m.to_cols(), [[1, 4], [2, 5], [3, 6]]);
+    /// ```
+    fn to_cols(&self) -> [[T; R]; C];
+    /// Copies the matrix into a Vec that holds the rows one after the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, Export };
+    /// let mut data = vec![1, 4, 2, 5, 3, 6];
+    /// let m = Reftrix::<2, 3, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(m.to_row_major_vec(), vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_row_major_vec(&self) -> Vec<T>;
+    /// Copies the matrix into dst of the same shape, reordering the elements if the memory
+    /// priorities differ. Nothing is allocated, the reordering transposes the buffer in blocks
+    /// that fit into the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, Stacktrix, ColumnPrio, RowPrio, Export };
+    /// let m = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let mut data = [0; 6];
+    /// m.convert_prio_into(&mut Reftrix::<2, 3, RowPrio, u8>::from_array_mut(&mut data));
+    /// assert_eq!(data, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn convert_prio_into<D>(&self, dst: &mut D)
+    where
+        D: StorageMut<R, C, T>;
+}
+
+/// The edge length of the square blocks [`Export::convert_prio_into`] transposes at once.
+const BLOCK: usize = 16;
+
+impl<M, const R: usize, const C: usize, T> Export<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+    T: Copy,
+{
+    fn to_rows(&self) -> [[T; C]; R] {
+        let buffer = self.buffer();
+        core::array::from_fn(|row| {
+            core::array::from_fn(|col| buffer[M::Priority::offset((row, col), R, C)])
+        })
+    }
+
+    fn to_cols(&self) -> [[T; R]; C] {
+        let buffer = self.buffer();
+        core::array::from_fn(|col| {
+            core::array::from_fn(|row| buffer[M::Priority::offset((row, col), R, C)])
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_row_major_vec(&self) -> Vec<T> {
+        let buffer = self.buffer();
+        if M::Priority::col_stride(R, C) == 1 {
+            return buffer.to_vec();
+        }
+        (0..R)
+            .flat_map(|row| (0..C).map(move |col| (row, col)))
+            .map(|location| buffer[M::Priority::offset(location, R, C)])
+            .collect()
+    }
+
+    fn convert_prio_into<D>(&self, dst: &mut D)
+    where
+        D: StorageMut<R, C, T>,
+    {
+        let (src, dst) = (self.buffer(), dst.buffer_mut());
+        if same_layout::<M::Priority, D::Priority>(R, C) {
+            dst.copy_from_slice(src);
+            return;
+        }
+        for row_block in (0..R).step_by(BLOCK) {
+            for col_block in (0..C).step_by(BLOCK) {
+                for row in row_block..R.min(row_block + BLOCK) {
+                    for col in col_block..C.min(col_block + BLOCK) {
+                        dst[D::Priority::offset((row, col), R, C)] =
+                            src[M::Priority::offset((row, col), R, C)];
+                    }
+                }
+            }
+        }
+    }
+}
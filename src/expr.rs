@@ -0,0 +1,221 @@
+//! Lazily evaluated element wise expressions.
+//!
+//! [`ToExpr::expr`] wraps a matrix in an [`Expr`], the arithmetic operators combine expressions
+//! and scalars into a tree that is only evaluated by [`Expr::eval_into`]. The whole chain is
+//! evaluated in one pass over the buffer of the target, without intermediate matrices.
+//! Matrices that share the memory priority of the target are read in the same continuous order,
+//! the others element wise. Combining matrices of different shapes does not compile.
+//!
+//! ```
+//! # use mightrix::{ Stacktrix, ColumnPrio, RowPrio, ToExpr, Export };
+//! let a = Stacktrix::<4, 2, 2, RowPrio, i32>::from_rows([[1, 2], [3, 4]]);
+//! let b = Stacktrix::<4, 2, 2, ColumnPrio, i32>::from_rows([[1, 0], [0, 1]]);
+//! let mut out = Stacktrix::<4, 2, 2, RowPrio, i32>::zeros();
+//! (a.expr() + b.expr() * 3 - 1).eval_into(&mut out);
+//! assert_eq!(out.to_rows(), [[3, 1], [2, 6]]);
+//! ```
+use crate::{
+    storage::{location, same_layout, Storage, StorageMut},
+    MemoryPriority,
+};
+use core::{
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// Node is an element wise expression over R x C matrices of T.
+pub trait Node<const R: usize, const C: usize, T> {
+    /// Evaluates the element at index of the buffer of a target with memory priority P.
+    fn value<P: MemoryPriority>(&self, index: usize) -> T;
+}
+
+/// Expr wraps an expression with elements of T so it can be combined with the arithmetic
+/// operators.
+#[derive(Debug)]
+pub struct Expr<E, T>(E, PhantomData<T>);
+
+impl<E: Clone, T> Clone for Expr<E, T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<E: Copy, T> Copy for Expr<E, T> {}
+
+impl<E, T> Expr<E, T> {
+    fn new(node: E) -> Self {
+        Self(node, PhantomData)
+    }
+
+    /// Evaluates the expression into dst in one pass over its buffer.
+    pub fn eval_into<D, const R: usize, const C: usize>(&self, dst: &mut D)
+    where
+        D: StorageMut<R, C, T>,
+        E: Node<R, C, T>,
+    {
+        for (index, el) in dst.buffer_mut().iter_mut().enumerate() {
+            *el = self.0.value::<D::Priority>(index);
+        }
+    }
+
+    /// Returns the wrapped expression.
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+/// ToExpr starts an [`Expr`] from a matrix.
+pub trait ToExpr<const R: usize, const C: usize, T>: Sized {
+    /// Borrows the matrix as the leaf of an expression.
+    fn expr(&self) -> Expr<Leaf<'_, Self>, T>;
+}
+
+impl<M, const R: usize, const C: usize, T> ToExpr<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+{
+    fn expr(&self) -> Expr<Leaf<'_, Self>, T> {
+        Expr::new(Leaf(self))
+    }
+}
+
+/// Leaf reads the elements of a borrowed matrix.
+#[derive(Debug)]
+pub struct Leaf<'a, M>(&'a M);
+
+impl<M> Clone for Leaf<'_, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M> Copy for Leaf<'_, M> {}
+
+impl<M, const R: usize, const C: usize, T> Node<R, C, T> for Leaf<'_, M>
+where
+    M: Storage<R, C, T>,
+    T: Copy,
+{
+    fn value<P: MemoryPriority>(&self, index: usize) -> T {
+        let buffer = self.0.buffer();
+        if same_layout::<P, M::Priority>(R, C) {
+            buffer[index]
+        } else {
+            buffer[M::Priority::offset(location::<P>(index, R, C), R, C)]
+        }
+    }
+}
+
+/// Scalar is the same value at every location.
+#[derive(Debug, Clone, Copy)]
+pub struct Scalar<T>(T);
+
+impl<const R: usize, const C: usize, T> Node<R, C, T> for Scalar<T>
+where
+    T: Copy,
+{
+    fn value<P: MemoryPriority>(&self, _index: usize) -> T {
+        self.0
+    }
+}
+
+/// Negation negates every element of an expression.
+#[derive(Debug, Clone, Copy)]
+pub struct Negation<E>(E);
+
+impl<const R: usize, const C: usize, E, T> Node<R, C, T> for Negation<E>
+where
+    E: Node<R, C, T>,
+    T: Neg<Output = T>,
+{
+    fn value<P: MemoryPriority>(&self, index: usize) -> T {
+        -self.0.value::<P>(index)
+    }
+}
+
+impl<E, T> Neg for Expr<E, T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Expr<Negation<E>, T>;
+
+    fn neg(self) -> Self::Output {
+        Expr::new(Negation(self.0))
+    }
+}
+
+macro_rules! binary_node {
+    ($($(#[$doc:meta])* $node:ident: $op:ident::$f:ident;)*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy)]
+            pub struct $node<A, B>(A, B);
+
+            impl<const R: usize, const C: usize, A, B, T> Node<R, C, T> for $node<A, B>
+            where
+                A: Node<R, C, T>,
+                B: Node<R, C, T>,
+                T: $op<Output = T>,
+            {
+                fn value<P: MemoryPriority>(&self, index: usize) -> T {
+                    $op::$f(self.0.value::<P>(index), self.1.value::<P>(index))
+                }
+            }
+
+            impl<A, B, T> $op<Expr<B, T>> for Expr<A, T>
+            where
+                T: $op<Output = T>,
+            {
+                type Output = Expr<$node<A, B>, T>;
+
+                fn $f(self, rhs: Expr<B, T>) -> Self::Output {
+                    Expr::new($node(self.0, rhs.0))
+                }
+            }
+
+            impl<A, T> $op<T> for Expr<A, T>
+            where
+                T: $op<Output = T>,
+            {
+                type Output = Expr<$node<A, Scalar<T>>, T>;
+
+                fn $f(self, rhs: T) -> Self::Output {
+                    Expr::new($node(self.0, Scalar(rhs)))
+                }
+            }
+        )*
+    };
+}
+
+binary_node! {
+    /// Sum adds the elements of two expressions.
+    Sum: Add::add;
+    /// Difference subtracts the elements of the second expression from the first.
+    Difference: Sub::sub;
+    /// Product multiplies the elements of two expressions.
+    Product: Mul::mul;
+    /// Quotient divides the elements of the first expression by the second.
+    Quotient: Div::div;
+}
+
+macro_rules! scalar_lhs {
+    ($($t:ty),*) => {
+        $(
+            scalar_lhs!(@op $t, Sum: Add::add);
+            scalar_lhs!(@op $t, Difference: Sub::sub);
+            scalar_lhs!(@op $t, Product: Mul::mul);
+            scalar_lhs!(@op $t, Quotient: Div::div);
+        )*
+    };
+    (@op $t:ty, $node:ident: $op:ident::$f:ident) => {
+        impl<B> $op<Expr<B, $t>> for $t {
+            type Output = Expr<$node<Scalar<$t>, B>, $t>;
+
+            fn $f(self, rhs: Expr<B, $t>) -> Self::Output {
+                Expr::new($node(Scalar(self), rhs.0))
+            }
+        }
+    };
+}
+
+scalar_lhs!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
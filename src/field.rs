@@ -0,0 +1,223 @@
+use crate::{
+    elements::Cursor,
+    storage::{assert_col, assert_row},
+    MemoryPriority, Position,
+};
+use core::{
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem::size_of,
+    ops::{Index, IndexMut},
+    ptr::NonNull,
+};
+
+/// FieldMatrix is a matrix over one field of a slice of structs, e.g. the positions of an array
+/// of entities.
+///
+/// The fields lie `size_of::<S>()` bytes apart in memory, where S is the struct. As with
+/// [`Reftrix`](crate::Reftrix) the structs are interpreted as an R x C matrix in the order given
+/// by MemoryPriority, the other fields of the structs are never accessed.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ FieldMatrix, RowPrio };
+/// struct Particle {
+///     mass: f32,
+///     charge: i8,
+/// }
+/// let mut particles: Vec<_> = (0..6).map(|i| Particle { mass: 1.0, charge: i }).collect();
+/// let mut charges =
+///     FieldMatrix::<2, 3, RowPrio, i8>::from_struct_slice_field(&mut particles, |p| &mut p.charge);
+/// assert_eq!(charges[(1, 0)], 3);
+/// charges[(0, 2)] = -1;
+/// for (_, charge) in charges.iter_mut_indexed() {
+///     *charge *= 2;
+/// }
+/// assert_eq!(particles[2].charge, -2);
+/// assert_eq!(particles[5].charge, 10);
+/// ```
+pub struct FieldMatrix<'a, const R: usize, const C: usize, MemoryPriority, F> {
+    start: NonNull<u8>,
+    stride: usize,
+    _prio: PhantomData<MemoryPriority>,
+    _borrow: PhantomData<&'a mut F>,
+}
+
+unsafe impl<const R: usize, const C: usize, P, F: Send> Send for FieldMatrix<'_, R, C, P, F> {}
+unsafe impl<const R: usize, const C: usize, P, F: Sync> Sync for FieldMatrix<'_, R, C, P, F> {}
+
+impl<'a, const R: usize, const C: usize, P, F> FieldMatrix<'a, R, C, P, F>
+where
+    P: MemoryPriority,
+{
+    /// Constructs a FieldMatrix over the field the projector returns for every struct of the
+    /// slice.
+    ///
+    /// # Panics
+    ///
+    /// If the slice does not hold R * C structs, or if the projector does not return the same
+    /// field of every struct, e.g. a reference into another allocation or different fields for
+    /// different enum variants.
+    pub fn from_struct_slice_field<S>(
+        slice: &'a mut [S],
+        mut projector: impl FnMut(&mut S) -> &mut F,
+    ) -> Self {
+        assert!(slice.len() == R * C);
+        let mut field_offset = None;
+        for s in slice.iter_mut() {
+            let base = s as *mut S as usize;
+            let offset = (projector(s) as *mut F as usize).wrapping_sub(base);
+            assert!(
+                offset
+                    .checked_add(size_of::<F>())
+                    .is_some_and(|end| end <= size_of::<S>()),
+                "the projector has to return a field of the struct it is given"
+            );
+            assert!(
+                *field_offset.get_or_insert(offset) == offset,
+                "the projector has to return the same field of every struct"
+            );
+        }
+        let start = NonNull::from(slice).cast::<u8>();
+        Self {
+            // SAFETY: the offset lies within the first struct, or is 0 for an empty slice.
+            start: unsafe { start.add(field_offset.unwrap_or(0)) },
+            stride: size_of::<S>(),
+            _prio: PhantomData,
+            _borrow: PhantomData,
+        }
+    }
+
+    /// Returns the amount of rows and columns.
+    pub fn shape(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    /// Returns the field at location, or None if it is out of bounds.
+    pub fn get(&self, location: Position) -> Option<&F> {
+        let offset = self.offset(location)?;
+        // SAFETY: the constructor checked that a F lives at every offset, the shared borrow of
+        // self prevents mutable access.
+        Some(unsafe { &*self.start.as_ptr().add(offset).cast::<F>() })
+    }
+
+    /// Returns the field at location mutably, or None if it is out of bounds.
+    pub fn get_mut(&mut self, location: Position) -> Option<&mut F> {
+        let offset = self.offset(location)?;
+        // SAFETY: as in get, the mutable borrow of self makes the access exclusive.
+        Some(unsafe { &mut *self.start.as_ptr().add(offset).cast::<F>() })
+    }
+
+    /// Returns an iterator over ((row, col), &field) in row major order.
+    pub fn iter_indexed(&self) -> FieldIterIndexed<'_, F> {
+        FieldIterIndexed {
+            start: self.start,
+            stride: self.stride,
+            cursor: Cursor::new::<P>(R, C),
+            _borrow: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over ((row, col), &mut field) in row major order.
+    pub fn iter_mut_indexed(&mut self) -> FieldIterMutIndexed<'_, F> {
+        FieldIterMutIndexed {
+            start: self.start,
+            stride: self.stride,
+            cursor: Cursor::new::<P>(R, C),
+            _borrow: PhantomData,
+        }
+    }
+
+    /// Returns the byte offset of the field at location from the start.
+    fn offset(&self, location: Position) -> Option<usize> {
+        if location.0 >= R || location.1 >= C {
+            return None;
+        }
+        Some(P::offset(location, R, C) * self.stride)
+    }
+}
+
+impl<const R: usize, const C: usize, P, F> Index<Position> for FieldMatrix<'_, R, C, P, F>
+where
+    P: MemoryPriority,
+{
+    type Output = F;
+
+    fn index(&self, location: Position) -> &F {
+        assert_row::<R>(location.0);
+        assert_col::<C>(location.1);
+        self.get(location).expect("the location is in bounds")
+    }
+}
+
+impl<const R: usize, const C: usize, P, F> IndexMut<Position> for FieldMatrix<'_, R, C, P, F>
+where
+    P: MemoryPriority,
+{
+    fn index_mut(&mut self, location: Position) -> &mut F {
+        assert_row::<R>(location.0);
+        assert_col::<C>(location.1);
+        self.get_mut(location).expect("the location is in bounds")
+    }
+}
+
+/// Iterator over the fields of a [`FieldMatrix`] and their locations in row major order.
+pub struct FieldIterIndexed<'a, F> {
+    start: NonNull<u8>,
+    stride: usize,
+    cursor: Cursor,
+    _borrow: PhantomData<&'a F>,
+}
+
+unsafe impl<F: Sync> Send for FieldIterIndexed<'_, F> {}
+unsafe impl<F: Sync> Sync for FieldIterIndexed<'_, F> {}
+
+impl<'a, F> Iterator for FieldIterIndexed<'a, F> {
+    type Item = (Position, &'a F);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (location, index) = self.cursor.next()?;
+        // SAFETY: see FieldMatrix::get.
+        let field = unsafe { &*self.start.as_ptr().add(index * self.stride).cast::<F>() };
+        Some((location, field))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cursor.remaining(), Some(self.cursor.remaining()))
+    }
+}
+
+impl<F> ExactSizeIterator for FieldIterIndexed<'_, F> {}
+
+impl<F> FusedIterator for FieldIterIndexed<'_, F> {}
+
+/// Iterator over the fields of a [`FieldMatrix`] mutably and their locations in row major order.
+pub struct FieldIterMutIndexed<'a, F> {
+    start: NonNull<u8>,
+    stride: usize,
+    cursor: Cursor,
+    _borrow: PhantomData<&'a mut F>,
+}
+
+unsafe impl<F: Send> Send for FieldIterMutIndexed<'_, F> {}
+unsafe impl<F: Sync> Sync for FieldIterMutIndexed<'_, F> {}
+
+impl<'a, F> Iterator for FieldIterMutIndexed<'a, F> {
+    type Item = (Position, &'a mut F);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (location, index) = self.cursor.next()?;
+        // SAFETY: the cursor yields every struct at most once, see FieldMatrix::get_mut.
+        let field = unsafe { &mut *self.start.as_ptr().add(index * self.stride).cast::<F>() };
+        Some((location, field))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cursor.remaining(), Some(self.cursor.remaining()))
+    }
+}
+
+impl<F> ExactSizeIterator for FieldIterMutIndexed<'_, F> {}
+
+impl<F> FusedIterator for FieldIterMutIndexed<'_, F> {}
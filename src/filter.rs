@@ -0,0 +1,132 @@
+use crate::{
+    network::{comparators, compare_exchange},
+    policy::IndexPolicy,
+    storage::{Storage, StorageMut},
+    MemoryPriority,
+};
+
+/// RankFilter replaces every element by an order statistic of its K x K neighbourhood, e.g. the
+/// median to remove salt and pepper noise from a grid.
+///
+/// K has to be odd so the neighbourhood is centered on the element, an even K does not compile.
+/// Neighbours outside of the matrix are mapped inside by the [`IndexPolicy`] I, every
+/// neighbourhood is sorted with a [`SortingNetwork`](crate::SortingNetwork).
+///
+/// ```compile_fail
+/// # use mightrix::{ Stacktrix, RowPrio, RankFilter, policy::Clamp };
+/// let m = Stacktrix::<4, 2, 2, RowPrio, u8>::zeros();
+/// let mut out = Stacktrix::<4, 2, 2, RowPrio, u8>::zeros();
+/// m.median_filter_into::<Clamp, 2, _>(&mut out);
+/// ```
+pub trait RankFilter<const R: usize, const C: usize, T> {
+    /// Writes the median of the K x K neighbourhood of every element to dst.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, ColumnPrio, RankFilter, Export, policy::Clamp };
+    /// let noisy = Stacktrix::<9, 3, 3, RowPrio, u8>::from_rows([[1, 1, 1], [1, 99, 1], [1, 1, 0]]);
+    /// let mut out = Stacktrix::<9, 3, 3, ColumnPrio, u8>::zeros();
+    /// noisy.median_filter_into::<Clamp, 3, _>(&mut out);
+    /// assert_eq!(out.to_rows(), [[1, 1, 1], [1, 1, 1], [1, 1, 1]]);
+    /// ```
+    fn median_filter_into<I, const K: usize, D>(&self, dst: &mut D)
+    where
+        I: IndexPolicy,
+        D: StorageMut<R, C, T>,
+        T: PartialOrd + Copy;
+    /// Writes the percentile of the K x K neighbourhood of every element to dst, 0 is the
+    /// minimum and 100 the maximum. Percentiles between the ranks round to the nearest rank.
+    ///
+    /// # Panics
+    ///
+    /// If percentile is not within 0..=100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, RankFilter, Export, policy::Wrap };
+    /// let m = Stacktrix::<3, 1, 3, RowPrio, i32>::from_rows([[4, 8, 6]]);
+    /// let mut out = Stacktrix::<3, 1, 3, RowPrio, i32>::zeros();
+    /// m.percentile_filter_into::<Wrap, 3, _>(100.0, &mut out);
+    /// assert_eq!(out.to_rows(), [[8, 8, 8]]);
+    /// m.percentile_filter_into::<Wrap, 3, _>(0.0, &mut out);
+    /// assert_eq!(out.to_rows(), [[4, 4, 4]]);
+    /// ```
+    fn percentile_filter_into<I, const K: usize, D>(&self, percentile: f64, dst: &mut D)
+    where
+        I: IndexPolicy,
+        D: StorageMut<R, C, T>,
+        T: PartialOrd + Copy;
+}
+
+impl<M, const R: usize, const C: usize, T> RankFilter<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+{
+    fn median_filter_into<I, const K: usize, D>(&self, dst: &mut D)
+    where
+        I: IndexPolicy,
+        D: StorageMut<R, C, T>,
+        T: PartialOrd + Copy,
+    {
+        rank_filter::<I, K, M, D, R, C, T>(self, (K * K) / 2, dst);
+    }
+
+    fn percentile_filter_into<I, const K: usize, D>(&self, percentile: f64, dst: &mut D)
+    where
+        I: IndexPolicy,
+        D: StorageMut<R, C, T>,
+        T: PartialOrd + Copy,
+    {
+        assert!(
+            (0.0..=100.0).contains(&percentile),
+            "percentile {percentile} is not within 0..=100"
+        );
+        // Adding a half before truncating rounds, the rank is never negative.
+        let rank = ((K * K - 1) as f64 * percentile / 100.0 + 0.5) as usize;
+        rank_filter::<I, K, M, D, R, C, T>(self, rank, dst);
+    }
+}
+
+struct Window<const K: usize>;
+
+impl<const K: usize> Window<K> {
+    /// Evaluated by [`rank_filter`], which turns an even K into a compile error.
+    const CHECK: () = assert!(K % 2 == 1, "a filter window has to have an odd size");
+}
+
+fn rank_filter<I, const K: usize, M, D, const R: usize, const C: usize, T>(
+    src: &M,
+    rank: usize,
+    dst: &mut D,
+) where
+    I: IndexPolicy,
+    M: Storage<R, C, T>,
+    D: StorageMut<R, C, T>,
+    T: PartialOrd + Copy,
+{
+    let () = Window::<K>::CHECK;
+    let buffer = src.buffer();
+    let Some(&first) = buffer.first() else {
+        return;
+    };
+    let mut window = [[first; K]; K];
+    let radius = (K / 2) as isize;
+    for row in 0..R {
+        for col in 0..C {
+            for (dr, window_row) in window.iter_mut().enumerate() {
+                let r = I::resolve(row as isize + dr as isize - radius, R);
+                for (dc, el) in window_row.iter_mut().enumerate() {
+                    let c = I::resolve(col as isize + dc as isize - radius, C);
+                    *el = buffer[M::Priority::offset((r, c), R, C)];
+                }
+            }
+            let lane = window.as_flattened_mut();
+            for (a, b) in comparators(K * K) {
+                compare_exchange(lane, a, b);
+            }
+            dst.buffer_mut()[D::Priority::offset((row, col), R, C)] = lane[rank];
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{storage::StorageMut, MatrixError, MemoryPriority, Progress};
+use core::ops::{Add, ControlFlow, Sub};
+
+/// Flow computes flows through networks given as square capacity matrices, where (i, j) is the
+/// capacity of the edge from node i to node j.
+pub trait Flow<const R: usize, const C: usize, T> {
+    /// Returns the value of the maximum flow from source to sink and replaces the capacities
+    /// with the residual network.
+    ///
+    /// Augments along shortest paths found by breadth first search (Edmonds-Karp), the queue
+    /// and the path take 2 * R words on the stack. Afterwards the flow over the edge (i, j) is
+    /// its capacity minus the residual (i, j), clamped at zero if the network has edges in both
+    /// directions. The nodes reachable from source in the residual network form a minimum cut.
+    /// The flow is 0 if source equals sink.
+    ///
+    /// Returns [`MatrixError::NotSquare`] if R != C. Returns [`MatrixError::RowOutOfBounds`] if
+    /// source or sink is not a node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix, Flow };
+    /// let mut network = Stacktrix::<16, 4, 4, RowPrio, u32>::from_rows([
+    ///     [0, 3, 2, 0],
+    ///     [0, 0, 1, 2],
+    ///     [0, 0, 0, 3],
+    ///     [0, 0, 0, 0],
+    /// ]);
+    /// assert_eq!(network.max_flow(0, 3), Ok(5));
+    /// // Both edges out of the source are saturated.
+    /// assert_eq!(&network.get_row(0)[1..3], &[0, 0]);
+    /// ```
+    fn max_flow(&mut self, source: usize, sink: usize) -> Result<T, MatrixError>;
+    /// Returns the value of the maximum flow like [`Flow::max_flow`], reporting the augmenting
+    /// paths that are found to progress. Their amount is not known in advance.
+    ///
+    /// Returns [`MatrixError::Cancelled`] if progress cancelled the search, the matrix then
+    /// holds the residual network of the flow along the paths reported so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// # use mightrix::{ Stacktrix, RowPrio, Flow, MatrixError };
+    /// let mut network = Stacktrix::<9, 3, 3, RowPrio, u32>::from_rows([
+    ///     [0, 3, 2],
+    ///     [0, 0, 3],
+    ///     [0, 0, 0],
+    /// ]);
+    /// let mut paths = 0;
+    /// let flow = network.max_flow_with_progress(0, 2, |progress| {
+    ///     paths = progress.done;
+    ///     ControlFlow::Continue(())
+    /// });
+    /// assert_eq!((flow, paths), (Ok(5), 2));
+    /// let cancelled = network.max_flow_with_progress(2, 0, |_| ControlFlow::Break(()));
+    /// assert_eq!(cancelled, Err(MatrixError::Cancelled));
+    /// ```
+    fn max_flow_with_progress<F>(
+        &mut self,
+        source: usize,
+        sink: usize,
+        progress: F,
+    ) -> Result<T, MatrixError>
+    where
+        F: FnMut(Progress) -> ControlFlow<()>;
+}
+
+impl<M, const R: usize, const C: usize, T> Flow<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+    T: Copy + PartialOrd + From<u8> + Add<Output = T> + Sub<Output = T>,
+{
+    fn max_flow(&mut self, source: usize, sink: usize) -> Result<T, MatrixError> {
+        self.max_flow_with_progress(source, sink, |_| ControlFlow::Continue(()))
+    }
+
+    fn max_flow_with_progress<F>(
+        &mut self,
+        source: usize,
+        sink: usize,
+        mut progress: F,
+    ) -> Result<T, MatrixError>
+    where
+        F: FnMut(Progress) -> ControlFlow<()>,
+    {
+        if R != C {
+            return Err(MatrixError::NotSquare { rows: R, cols: C });
+        }
+        for node in [source, sink] {
+            if node >= R {
+                return Err(MatrixError::RowOutOfBounds { row: node, rows: R });
+            }
+        }
+        let zero = T::from(0);
+        let mut flow = zero;
+        if source == sink {
+            return Ok(flow);
+        }
+        let at = |row, col| M::Priority::offset((row, col), R, C);
+        let residual = self.buffer_mut();
+        let mut paths = 0;
+        loop {
+            // parent[node] is the node before it on the shortest path from source.
+            let mut parent: [Option<usize>; R] = [None; R];
+            let mut queue = [0; R];
+            let (mut head, mut tail) = (0, 1);
+            queue[0] = source;
+            parent[source] = Some(source);
+            while head < tail && parent[sink].is_none() {
+                let from = queue[head];
+                head += 1;
+                for to in 0..R {
+                    if parent[to].is_none() && residual[at(from, to)] > zero {
+                        parent[to] = Some(from);
+                        queue[tail] = to;
+                        tail += 1;
+                    }
+                }
+            }
+            if parent[sink].is_none() {
+                return Ok(flow);
+            }
+            let mut bottleneck = None;
+            let mut node = sink;
+            while node != source {
+                let from = parent[node].expect("the path reaches source");
+                let capacity = residual[at(from, node)];
+                if bottleneck.is_none_or(|bottleneck| capacity < bottleneck) {
+                    bottleneck = Some(capacity);
+                }
+                node = from;
+            }
+            let bottleneck = bottleneck.expect("the path has at least one edge");
+            let mut node = sink;
+            while node != source {
+                let from = parent[node].expect("the path reaches source");
+                residual[at(from, node)] = residual[at(from, node)] - bottleneck;
+                residual[at(node, from)] = residual[at(node, from)] + bottleneck;
+                node = from;
+            }
+            flow = flow + bottleneck;
+            paths += 1;
+            let done = Progress {
+                done: paths,
+                total: None,
+            };
+            if progress(done).is_break() {
+                return Err(MatrixError::Cancelled);
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    storage::{assert_col, assert_row, location, Storage, StorageMut},
+    MemoryPriority,
+};
+
+/// Gather copies selected rows and columns of a matrix into another matrix.
+pub trait Gather<const R: usize, const C: usize, T> {
+    /// Copies the element (row_map\[i\], col_map\[j\]) of the matrix to (i, j) of dst in a
+    /// single pass.
+    ///
+    /// The maps can permute, select and repeat rows and columns, the memory priorities of the
+    /// matrix and dst may differ. dst is written in its memory order.
+    ///
+    /// # Panics
+    ///
+    /// If an index of row_map or col_map is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, Stacktrix, ColumnPrio, RowPrio, RowPrioMatrix, Gather };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6];
+    /// let m = Reftrix::<2, 3, RowPrio, u8>::from_values(&mut data[..]);
+    /// let mut dst = Stacktrix::<4, 2, 2, ColumnPrio, u8>::zeros();
+    /// // Swaps the rows and keeps the columns 2 and 0.
+    /// m.gather_into(&mut dst, &[1, 0], &[2, 0]);
+    /// assert_eq!(dst, Stacktrix::<4, 2, 2, RowPrio, u8>::with_values([6, 4, 3, 1]));
+    /// ```
+    fn gather_into<D, const DR: usize, const DC: usize>(
+        &self,
+        dst: &mut D,
+        row_map: &[usize; DR],
+        col_map: &[usize; DC],
+    ) where
+        D: StorageMut<DR, DC, T>;
+    /// Copies the columns cols\[j\] of the matrix to the columns j of dst, e.g. to extract a
+    /// subset of features from a wide table.
+    ///
+    /// dst is written in its memory order. If both matrices are [`ColumnPrio`](crate::ColumnPrio)
+    /// every column is copied as one continuous block.
+    ///
+    /// # Panics
+    ///
+    /// If an index of cols is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Export, Gather };
+    /// let table = Stacktrix::<8, 2, 4, ColumnPrio, u8>::from_rows([[1, 2, 3, 4], [5, 6, 7, 8]]);
+    /// let mut features = Stacktrix::<6, 2, 3, ColumnPrio, u8>::zeros();
+    /// table.gather_cols_into(&[3, 0, 3], &mut features);
+    /// assert_eq!(features.to_rows(), [[4, 1, 4], [8, 5, 8]]);
+    /// ```
+    fn gather_cols_into<D, const K: usize>(&self, cols: &[usize; K], dst: &mut D)
+    where
+        D: StorageMut<R, K, T>;
+}
+
+impl<M, const R: usize, const C: usize, T> Gather<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+    T: Copy,
+{
+    fn gather_into<D, const DR: usize, const DC: usize>(
+        &self,
+        dst: &mut D,
+        row_map: &[usize; DR],
+        col_map: &[usize; DC],
+    ) where
+        D: StorageMut<DR, DC, T>,
+    {
+        for &row in row_map {
+            assert_row::<R>(row);
+        }
+        for &col in col_map {
+            assert_col::<C>(col);
+        }
+        let src = self.buffer();
+        for (index, el) in dst.buffer_mut().iter_mut().enumerate() {
+            let (row, col) = location::<D::Priority>(index, DR, DC);
+            *el = src[M::Priority::offset((row_map[row], col_map[col]), R, C)];
+        }
+    }
+
+    fn gather_cols_into<D, const K: usize>(&self, cols: &[usize; K], dst: &mut D)
+    where
+        D: StorageMut<R, K, T>,
+    {
+        for &col in cols {
+            assert_col::<C>(col);
+        }
+        let (src, dst) = (self.buffer(), dst.buffer_mut());
+        // A matrix without rows has no columns to chunk, it is handled by the element loop.
+        if R > 0 && M::Priority::row_stride(R, C) == 1 && D::Priority::row_stride(R, K) == 1 {
+            for (dst_col, &col) in dst.chunks_exact_mut(R).zip(cols) {
+                dst_col.copy_from_slice(&src[col * R..(col + 1) * R]);
+            }
+            return;
+        }
+        for (index, el) in dst.iter_mut().enumerate() {
+            let (row, col) = location::<D::Priority>(index, R, K);
+            *el = src[M::Priority::offset((row, cols[col]), R, C)];
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use crate::{
+    storage::{Storage, StorageMut},
+    MatrixError, MemoryPriority, RowPrio, Stacktrix,
+};
+
+/// BitMatrix treats a matrix of bytes as a packed matrix over GF(2).
+///
+/// A matrix with R rows and C columns of `u8` represents a bit matrix with R rows and 8 * C
+/// columns. The byte at (row, col) holds the bits 8 * col to 8 * col + 7 of the row, the most
+/// significant bit first. All operations work on whole bytes and words instead of single bits.
+pub trait BitMatrix<const R: usize, const C: usize> {
+    /// Returns the number of set bits in the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, BitMatrix };
+    /// let mut data = vec![0b1000_0001, 0xff, 0, 0b0000_0100];
+    /// let m = Reftrix::<2, 2, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(m.hamming_weight(), 11);
+    /// ```
+    fn hamming_weight(&self) -> usize;
+    /// Returns the parity of every row, true if the row contains an odd number of set bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, BitMatrix };
+    /// let mut data = vec![0b1000_0001, 0b0000_0001, 0b0110_0000, 0];
+    /// let m = Reftrix::<2, 2, RowPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(m.row_parities(), [true, false]);
+    /// ```
+    fn row_parities(&self) -> [bool; R];
+    /// Computes the syndrome H * v over GF(2), where the matrix is the parity check matrix H and
+    /// vector holds the 8 * C bits of v packed like a row of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, BitMatrix };
+    /// // The parity check matrix of a [7, 4] hamming code, bits 0 to 6 of each byte.
+    /// let mut h = vec![0b1010_1010, 0b0110_0110, 0b0001_1110];
+    /// let m = Reftrix::<3, 1, RowPrio, u8>::from_values(&mut h[..]);
+    /// assert_eq!(m.syndrome(&[0b1110_0000]), [false, false, false]);
+    /// // Flipping bit 4 results in the syndrome 0b101 (the position 5).
+    /// assert_eq!(m.syndrome(&[0b1110_1000]), [true, false, true]);
+    /// ```
+    fn syndrome(&self, vector: &[u8; C]) -> [bool; R];
+    /// Transforms the matrix into its reduced row echelon form over GF(2) using row swaps and
+    /// row additions, returns the rank of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, BitMatrix };
+    /// let mut data = vec![0b1100_0000, 0b0110_0000, 0b1010_0000];
+    /// let mut m = Reftrix::<3, 1, RowPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(m.row_reduce(), 2);
+    /// assert_eq!(&data[..], &[0b1010_0000, 0b0110_0000, 0]);
+    /// ```
+    fn row_reduce(&mut self) -> usize
+    where
+        Self: StorageMut<R, C, u8>;
+    /// Transforms a generator matrix into systematic form [I | P], where I is the R x R
+    /// identity.
+    ///
+    /// Returns [`MatrixError::Singular`] if the first R bit columns are not linearly
+    /// independent, in that case the matrix is left in reduced row echelon form.
+    fn to_systematic(&mut self) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, u8>;
+    /// Computes the parity check matrix H = [P^T | I] of the [8 * C, R] code generated by the
+    /// matrix, which is brought into systematic form [I | P] first.
+    ///
+    /// HR has to be the redundancy 8 * C - R of the code, otherwise
+    /// [`MatrixError::SizeMismatch`] is returned with the expected and the given redundancy.
+    /// [`MatrixError::Singular`] is returned if the generator has no systematic form. Like for
+    /// any [`Stacktrix`], HS != HR * C is a compile time error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, BitMatrix };
+    /// // The generator of the [8, 4] extended hamming code.
+    /// let g = Stacktrix::<4, 4, 1, ColumnPrio, u8>::with_values([
+    ///     0b1000_0111,
+    ///     0b0100_1011,
+    ///     0b0010_1101,
+    ///     0b0001_1110,
+    /// ]);
+    /// let h = g.generator_to_parity_check::<4, 4>().unwrap();
+    /// let codeword = g.encode(&[true, false, true, true]);
+    /// assert_eq!(codeword, [0b1011_0100]);
+    /// assert_eq!(h.syndrome(&codeword), [false; 4]);
+    /// ```
+    fn generator_to_parity_check<const HR: usize, const HS: usize>(
+        &self,
+    ) -> Result<Stacktrix<HS, HR, C, RowPrio, u8>, MatrixError>;
+    /// Encodes a message of R bits into a codeword of 8 * C bits by computing m * G over GF(2),
+    /// where the matrix is the generator G.
+    fn encode(&self, message: &[bool; R]) -> [u8; C];
+}
+
+/// Counts the set bits of bytes, processing 8 bytes at a time.
+fn popcount(bytes: &[u8]) -> usize {
+    let mut chunks = bytes.chunks_exact(8);
+    let mut count: usize = chunks
+        .by_ref()
+        .map(|chunk| u64::from_ne_bytes(chunk.try_into().unwrap()).count_ones() as usize)
+        .sum();
+    for byte in chunks.remainder() {
+        count += byte.count_ones() as usize;
+    }
+    count
+}
+
+fn bit<const C: usize>(row: &[u8; C], index: usize) -> bool {
+    row[index / 8] & (0x80 >> (index % 8)) != 0
+}
+
+fn set_bit<const C: usize>(row: &mut [u8; C], index: usize, value: bool) {
+    if value {
+        row[index / 8] |= 0x80 >> (index % 8);
+    } else {
+        row[index / 8] &= !(0x80 >> (index % 8));
+    }
+}
+
+fn xor_into<const C: usize>(dst: &mut [u8; C], src: &[u8; C]) {
+    for (d, s) in dst.iter_mut().zip(src.iter()) {
+        *d ^= s;
+    }
+}
+
+/// Brings the rows into reduced row echelon form, records the pivot column of every non zero
+/// row in pivots and returns the rank.
+fn reduce<const C: usize>(rows: &mut [[u8; C]], pivots: &mut [usize]) -> usize {
+    let mut rank = 0;
+    for col in 0..8 * C {
+        if rank == rows.len() {
+            break;
+        }
+        let Some(pivot) = (rank..rows.len()).find(|&r| bit(&rows[r], col)) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let pivot_row = rows[rank];
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != rank && bit(row, col) {
+                xor_into(row, &pivot_row);
+            }
+        }
+        pivots[rank] = col;
+        rank += 1;
+    }
+    rank
+}
+
+fn systematic<const R: usize, const C: usize>(rows: &mut [[u8; C]; R]) -> Result<(), MatrixError> {
+    let mut pivots = [0; R];
+    let rank = reduce(rows, &mut pivots);
+    if rank == R && pivots.iter().enumerate().all(|(i, &p)| i == p) {
+        Ok(())
+    } else {
+        Err(MatrixError::Singular)
+    }
+}
+
+fn load_rows<M, const R: usize, const C: usize>(m: &M) -> [[u8; C]; R]
+where
+    M: Storage<R, C, u8>,
+{
+    let buffer = m.buffer();
+    core::array::from_fn(|row| {
+        core::array::from_fn(|col| buffer[M::Priority::offset((row, col), R, C)])
+    })
+}
+
+fn store_rows<M, const R: usize, const C: usize>(m: &mut M, rows: &[[u8; C]])
+where
+    M: StorageMut<R, C, u8>,
+{
+    let buffer = m.buffer_mut();
+    for (row, values) in rows.iter().enumerate() {
+        for (col, value) in values.iter().enumerate() {
+            buffer[M::Priority::offset((row, col), R, C)] = *value;
+        }
+    }
+}
+
+impl<M, const R: usize, const C: usize> BitMatrix<R, C> for M
+where
+    M: Storage<R, C, u8>,
+{
+    fn hamming_weight(&self) -> usize {
+        popcount(self.buffer())
+    }
+
+    fn row_parities(&self) -> [bool; R] {
+        let buffer = self.buffer();
+        core::array::from_fn(|row| {
+            let folded = (0..C).fold(0u8, |acc, col| {
+                acc ^ buffer[M::Priority::offset((row, col), R, C)]
+            });
+            folded.count_ones() % 2 == 1
+        })
+    }
+
+    fn syndrome(&self, vector: &[u8; C]) -> [bool; R] {
+        let buffer = self.buffer();
+        core::array::from_fn(|row| {
+            let folded = (0..C).fold(0u8, |acc, col| {
+                acc ^ (buffer[M::Priority::offset((row, col), R, C)] & vector[col])
+            });
+            folded.count_ones() % 2 == 1
+        })
+    }
+
+    fn row_reduce(&mut self) -> usize
+    where
+        Self: StorageMut<R, C, u8>,
+    {
+        let mut rows = load_rows(self);
+        let rank = reduce(&mut rows, &mut [0; R]);
+        store_rows(self, &rows);
+        rank
+    }
+
+    fn to_systematic(&mut self) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, u8>,
+    {
+        let mut rows = load_rows(self);
+        let result = systematic(&mut rows);
+        store_rows(self, &rows);
+        result
+    }
+
+    fn generator_to_parity_check<const HR: usize, const HS: usize>(
+        &self,
+    ) -> Result<Stacktrix<HS, HR, C, RowPrio, u8>, MatrixError> {
+        if R + HR != 8 * C {
+            return Err(MatrixError::SizeMismatch {
+                expected: (8 * C).saturating_sub(R),
+                got: HR,
+            });
+        }
+        let mut g = load_rows(self);
+        systematic(&mut g)?;
+        let mut h = [[0u8; C]; HR];
+        for (i, h_row) in h.iter_mut().enumerate() {
+            for (j, g_row) in g.iter().enumerate() {
+                set_bit(h_row, j, bit(g_row, R + i));
+            }
+            set_bit(h_row, R + i, true);
+        }
+        Ok(Stacktrix::from_values(h.as_flattened()))
+    }
+
+    fn encode(&self, message: &[bool; R]) -> [u8; C] {
+        let buffer = self.buffer();
+        let mut codeword = [0u8; C];
+        for row in (0..R).filter(|&row| message[row]) {
+            for (col, byte) in codeword.iter_mut().enumerate() {
+                *byte ^= buffer[M::Priority::offset((row, col), R, C)];
+            }
+        }
+        codeword
+    }
+}
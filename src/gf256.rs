@@ -0,0 +1,216 @@
+//! Arithmetic in GF(2^8) with the AES reduction polynomial x^8 + x^4 + x^3 + x + 1.
+//!
+//! [`mix_single_column`] and [`inv_mix_single_column`] look up the products in the
+//! precomputed `MUL*` tables and [`sub_byte`] looks up the [`SBOX`]. Table lookups leak the
+//! looked up byte through the cache, with the `constant-time` feature all three compute their
+//! result with [`xtime`] and [`mul`] instead, which neither branch nor index memory with secret
+//! data.
+
+/// The low byte of the AES reduction polynomial.
+const POLY: u8 = 0x1b;
+
+/// Multiplies b by x, i.e. by 2.
+///
+/// Runs in constant time.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::gf256::xtime;
+/// assert_eq!(xtime(0x57), 0xae);
+/// assert_eq!(xtime(0xae), 0x47);
+/// ```
+pub const fn xtime(b: u8) -> u8 {
+    (b << 1) ^ (POLY & 0u8.wrapping_sub(b >> 7))
+}
+
+/// Multiplies a by b.
+///
+/// Runs in constant time.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::gf256::mul;
+/// assert_eq!(mul(0x57, 0x83), 0xc1);
+/// assert_eq!(mul(0x57, 0x13), 0xfe);
+/// ```
+pub const fn mul(a: u8, b: u8) -> u8 {
+    let mut a = a;
+    let mut product = 0;
+    let mut i = 0;
+    while i < 8 {
+        product ^= a & 0u8.wrapping_sub((b >> i) & 1);
+        a = xtime(a);
+        i += 1;
+    }
+    product
+}
+
+/// Tabulates the product of every byte with factor.
+const fn table(factor: u8) -> [u8; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = mul(i as u8, factor);
+        i += 1;
+    }
+    table
+}
+
+/// The products of every byte with 2.
+pub static MUL2: [u8; 256] = table(2);
+/// The products of every byte with 3.
+pub static MUL3: [u8; 256] = table(3);
+/// The products of every byte with 9.
+pub static MUL9: [u8; 256] = table(9);
+/// The products of every byte with 11.
+pub static MUL11: [u8; 256] = table(11);
+/// The products of every byte with 13.
+pub static MUL13: [u8; 256] = table(13);
+/// The products of every byte with 14.
+pub static MUL14: [u8; 256] = table(14);
+
+/// Multiplies the column with the AES MixColumns matrix.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::gf256::mix_single_column;
+/// let mut col = [0xdb, 0x13, 0x53, 0x45];
+/// mix_single_column(&mut col);
+/// assert_eq!(col, [0x8e, 0x4d, 0xa1, 0xbc]);
+/// ```
+pub fn mix_single_column(col: &mut [u8; 4]) {
+    #[cfg(not(feature = "constant-time"))]
+    {
+        let [a0, a1, a2, a3] = col.map(usize::from);
+        *col = [
+            MUL2[a0] ^ MUL3[a1] ^ col[2] ^ col[3],
+            col[0] ^ MUL2[a1] ^ MUL3[a2] ^ col[3],
+            col[0] ^ col[1] ^ MUL2[a2] ^ MUL3[a3],
+            MUL3[a0] ^ col[1] ^ col[2] ^ MUL2[a3],
+        ];
+    }
+    #[cfg(feature = "constant-time")]
+    {
+        let [a0, a1, a2, a3] = *col;
+        let all = a0 ^ a1 ^ a2 ^ a3;
+        *col = [
+            a0 ^ all ^ xtime(a0 ^ a1),
+            a1 ^ all ^ xtime(a1 ^ a2),
+            a2 ^ all ^ xtime(a2 ^ a3),
+            a3 ^ all ^ xtime(a3 ^ a0),
+        ];
+    }
+}
+
+/// Multiplies the column with the AES InvMixColumns matrix, the inverse of
+/// [`mix_single_column`].
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::gf256::inv_mix_single_column;
+/// let mut col = [0x8e, 0x4d, 0xa1, 0xbc];
+/// inv_mix_single_column(&mut col);
+/// assert_eq!(col, [0xdb, 0x13, 0x53, 0x45]);
+/// ```
+pub fn inv_mix_single_column(col: &mut [u8; 4]) {
+    #[cfg(not(feature = "constant-time"))]
+    {
+        let [a0, a1, a2, a3] = col.map(usize::from);
+        *col = [
+            MUL14[a0] ^ MUL11[a1] ^ MUL13[a2] ^ MUL9[a3],
+            MUL9[a0] ^ MUL14[a1] ^ MUL11[a2] ^ MUL13[a3],
+            MUL13[a0] ^ MUL9[a1] ^ MUL14[a2] ^ MUL11[a3],
+            MUL11[a0] ^ MUL13[a1] ^ MUL9[a2] ^ MUL14[a3],
+        ];
+    }
+    #[cfg(feature = "constant-time")]
+    {
+        // InvMixColumns factors into MixColumns after a multiplication with
+        // [5, 0, 4, 0] rotated along the column.
+        let u = xtime(xtime(col[0] ^ col[2]));
+        let v = xtime(xtime(col[1] ^ col[3]));
+        col[0] ^= u;
+        col[1] ^= v;
+        col[2] ^= u;
+        col[3] ^= v;
+        mix_single_column(col);
+    }
+}
+
+/// Computes the multiplicative inverse of a, 0 is mapped to 0.
+///
+/// Runs in constant time.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::gf256::{inv, mul};
+/// assert_eq!(mul(0x53, inv(0x53)), 1);
+/// assert_eq!(inv(0), 0);
+/// ```
+pub const fn inv(a: u8) -> u8 {
+    // a^254 = a^-1, by squaring and multiplying along the bits of 254.
+    let mut result = 1;
+    let mut i = 0;
+    while i < 7 {
+        result = mul(mul(result, result), a);
+        i += 1;
+    }
+    mul(result, result)
+}
+
+/// The AES affine transformation of the S-box.
+const fn affine(b: u8) -> u8 {
+    b ^ b.rotate_left(1) ^ b.rotate_left(2) ^ b.rotate_left(3) ^ b.rotate_left(4) ^ 0x63
+}
+
+const fn sbox() -> [u8; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = affine(inv(i as u8));
+        i += 1;
+    }
+    table
+}
+
+const fn inv_sbox() -> [u8; 256] {
+    let sbox = sbox();
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[sbox[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+}
+
+/// The AES S-box, e.g. the table for [`BulkOps::substitute`](crate::BulkOps::substitute).
+pub static SBOX: [u8; 256] = sbox();
+/// The inverse of the AES S-box.
+pub static INV_SBOX: [u8; 256] = inv_sbox();
+
+/// Substitutes b with its entry of the AES S-box.
+///
+/// With the `constant-time` feature the entry is computed instead of looked up in [`SBOX`].
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::gf256::sub_byte;
+/// assert_eq!(sub_byte(0x53), 0xed);
+/// ```
+pub fn sub_byte(b: u8) -> u8 {
+    #[cfg(not(feature = "constant-time"))]
+    {
+        SBOX[b as usize]
+    }
+    #[cfg(feature = "constant-time")]
+    {
+        affine(inv(b))
+    }
+}
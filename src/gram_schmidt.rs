@@ -0,0 +1,102 @@
+use crate::{
+    storage::{Storage, StorageMut},
+    MemoryPriority, ScaleAdd,
+};
+use core::ops::{Add, Div, Mul, Sub};
+
+/// SquareRoot provides the square root [`Orthonormalize`] normalizes with.
+///
+/// It is implemented for `f32` and `f64` with the `std` feature, without it the square root
+/// has to come from elsewhere, e.g. from a crate like `libm` through a newtype.
+pub trait SquareRoot {
+    /// Returns the square root of self.
+    fn sqrt(self) -> Self;
+}
+
+#[cfg(feature = "std")]
+impl SquareRoot for f32 {
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl SquareRoot for f64 {
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+}
+
+/// Orthonormalize turns the columns of a matrix into an orthonormal basis of their span.
+pub trait Orthonormalize<const R: usize, const C: usize, T> {
+    /// Orthonormalizes the columns in place with the modified Gram-Schmidt process and returns
+    /// the rank of the matrix.
+    ///
+    /// Column j is projected off every column before it, one column at a time, and then scaled
+    /// to norm 1. If the norm left after the projections is at most tolerance, the column lies
+    /// in the span of the columns before it and is set to zero instead, it does not count
+    /// towards the rank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Orthonormalize, Export };
+    /// # #[cfg(feature = "std")] {
+    /// let mut m = Stacktrix::<6, 2, 3, ColumnPrio, f64>::from_rows([
+    ///     [3.0, 6.0, 1.0],
+    ///     [4.0, 8.0, 0.0],
+    /// ]);
+    /// assert_eq!(m.orthonormalize_cols(1e-9), 2);
+    /// let [q0, q1] = m.to_rows();
+    /// let expected = [[0.6, 0.0, 0.8], [0.8, 0.0, -0.6]];
+    /// assert!(q0.iter().chain(&q1).zip(expected.as_flattened()).all(|(a, b)| (a - b).abs() < 1e-12));
+    /// # }
+    /// ```
+    fn orthonormalize_cols(&mut self, tolerance: T) -> usize;
+}
+
+impl<M, const R: usize, const C: usize, T> Orthonormalize<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+    T: Copy
+        + PartialOrd
+        + From<u8>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + SquareRoot,
+{
+    fn orthonormalize_cols(&mut self, tolerance: T) -> usize {
+        let zero = T::from(0);
+        let mut rank = 0;
+        for col in 0..C {
+            for basis in 0..col {
+                // Projecting the updated column keeps the rounding errors from adding up.
+                let r = col_dot::<M, R, C, T>(self, basis, col);
+                self.add_scaled_col(basis, col, zero - r);
+            }
+            let norm = col_dot::<M, R, C, T>(self, col, col).sqrt();
+            if norm > tolerance {
+                self.copy_scaled_col(col, col, T::from(1) / norm);
+                rank += 1;
+            } else {
+                self.copy_scaled_col(col, col, zero);
+            }
+        }
+        rank
+    }
+}
+
+/// Returns the dot product of the columns a and b.
+fn col_dot<M, const R: usize, const C: usize, T>(m: &M, a: usize, b: usize) -> T
+where
+    M: Storage<R, C, T>,
+    T: Copy + From<u8> + Add<Output = T> + Mul<Output = T>,
+{
+    let buffer = m.buffer();
+    (0..R).fold(T::from(0), |acc, row| {
+        acc + buffer[M::Priority::offset((row, a), R, C)]
+            * buffer[M::Priority::offset((row, b), R, C)]
+    })
+}
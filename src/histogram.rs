@@ -0,0 +1,181 @@
+use crate::{
+    display::display_cell,
+    storage::{Storage, StorageMut},
+    Grid, MemoryPriority,
+};
+use core::{
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+    ops::Add,
+};
+
+/// Bins splits the half open range [start, end) into N intervals.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bins<const N: usize> {
+    starts: [f64; N],
+    end: f64,
+}
+
+impl<const N: usize> Bins<N> {
+    /// Constructs N bins of equal width covering [start, end).
+    ///
+    /// # Panics
+    ///
+    /// If start is not smaller than end.
+    pub fn uniform(start: f64, end: f64) -> Self {
+        assert!(start < end, "the bins have to cover a non empty range");
+        let width = (end - start) / N as f64;
+        Self {
+            starts: core::array::from_fn(|i| start + width * i as f64),
+            end,
+        }
+    }
+
+    /// Constructs bins from the start of every bin and the end of the last one, bin i covers
+    /// [starts\[i\], starts\[i + 1\]).
+    ///
+    /// # Panics
+    ///
+    /// If the edges are not strictly increasing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::Bins;
+    /// let bins = Bins::from_edges([0.0, 1.0, 10.0], 100.0);
+    /// assert_eq!(bins.bin(5.0), Some(1));
+    /// assert_eq!(bins.bin(100.0), None);
+    /// ```
+    pub fn from_edges(starts: [f64; N], end: f64) -> Self {
+        assert!(
+            starts.windows(2).all(|pair| pair[0] < pair[1])
+                && starts.last().is_none_or(|&last| last < end),
+            "the bin edges have to be strictly increasing"
+        );
+        Self { starts, end }
+    }
+
+    /// Returns the bin value falls into, or None if it lies outside of all bins or is NaN.
+    pub fn bin(&self, value: f64) -> Option<usize> {
+        let first = *self.starts.first()?;
+        if !(first <= value && value < self.end) {
+            return None;
+        }
+        Some(self.starts.partition_point(|&start| start <= value) - 1)
+    }
+}
+
+/// Histogram2D counts pairs of values in an R x C matrix, e.g. to accumulate a heatmap over two
+/// variables. The first value selects the row, the second the column.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ Bins, Histogram2D, Stacktrix, RowPrio, Export };
+/// let mut heatmap = Histogram2D::new(
+///     Stacktrix::<4, 2, 2, RowPrio, u32>::zeros(),
+///     Bins::uniform(0.0, 2.0),
+///     Bins::from_edges([0.0, 10.0], 20.0),
+/// );
+/// for (x, y) in [(0.5, 3.0), (1.5, 12.0), (0.2, 1.0), (5.0, 1.0)] {
+///     heatmap.record(x, y);
+/// }
+/// assert_eq!(heatmap.counts().to_rows(), [[2, 0], [0, 1]]);
+/// assert_eq!(heatmap.outside(), 1);
+/// assert_eq!(heatmap.to_string(), "2 0\n0 1\n");
+/// ```
+pub struct Histogram2D<M, const R: usize, const C: usize, T> {
+    counts: M,
+    rows: Bins<R>,
+    cols: Bins<C>,
+    recorded: usize,
+    outside: usize,
+    _elements: PhantomData<T>,
+}
+
+impl<M, const R: usize, const C: usize, T> Histogram2D<M, R, C, T>
+where
+    M: StorageMut<R, C, T>,
+    T: Copy + From<u8> + Add<Output = T>,
+{
+    /// Constructs an empty Histogram2D, the counts are set to zero.
+    pub fn new(mut counts: M, rows: Bins<R>, cols: Bins<C>) -> Self {
+        counts.buffer_mut().fill(T::from(0));
+        Self {
+            counts,
+            rows,
+            cols,
+            recorded: 0,
+            outside: 0,
+            _elements: PhantomData,
+        }
+    }
+
+    /// Counts the pair (x, y) in its bin and returns true, or returns false if it lies outside
+    /// of the bins.
+    pub fn record(&mut self, x: f64, y: f64) -> bool {
+        let (Some(row), Some(col)) = (self.rows.bin(x), self.cols.bin(y)) else {
+            self.outside += 1;
+            return false;
+        };
+        let el = &mut self.counts.buffer_mut()[M::Priority::offset((row, col), R, C)];
+        *el = *el + T::from(1);
+        self.recorded += 1;
+        true
+    }
+
+    /// Returns the fraction of the recorded pairs that fell into every bin in logical order, all
+    /// zero if nothing was recorded.
+    pub fn normalized(&self) -> [[f64; C]; R]
+    where
+        T: Into<f64>,
+    {
+        let counts = self.counts.buffer();
+        let total = self.recorded.max(1) as f64;
+        core::array::from_fn(|row| {
+            core::array::from_fn(|col| counts[M::Priority::offset((row, col), R, C)].into() / total)
+        })
+    }
+
+    /// Returns the matrix of counts.
+    pub fn counts(&self) -> &M {
+        &self.counts
+    }
+
+    /// Returns the amount of pairs that were counted in a bin.
+    pub fn recorded(&self) -> usize {
+        self.recorded
+    }
+
+    /// Returns the amount of pairs that lay outside of the bins.
+    pub fn outside(&self) -> usize {
+        self.outside
+    }
+
+    /// Returns the bins of the first value, one per row.
+    pub fn row_bins(&self) -> &Bins<R> {
+        &self.rows
+    }
+
+    /// Returns the bins of the second value, one per column.
+    pub fn col_bins(&self) -> &Bins<C> {
+        &self.cols
+    }
+
+    /// Consumes the Histogram2D and returns the matrix of counts.
+    pub fn into_inner(self) -> M {
+        self.counts
+    }
+}
+
+/// Formats the counts as a grid, one line per row.
+impl<M, const R: usize, const C: usize, T> Display for Histogram2D<M, R, C, T>
+where
+    M: Storage<R, C, T>,
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Grid::<R, C, M::Priority, T, _>::new(self.counts.buffer(), display_cell(f.precision()))
+            .fmt(f)
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{storage::StorageMut, MatrixError, MemoryPriority, Progress};
+use core::ops::{ControlFlow, Div, Mul, Sub};
+
+/// Inverse inverts square matrices in their own memory.
+pub trait Inverse<const R: usize, const C: usize, T> {
+    /// Replaces the matrix with its inverse using Gauss-Jordan elimination with full pivoting.
+    ///
+    /// No second matrix is needed, the bookkeeping of the pivots takes 3 * R words on the stack.
+    /// The elimination divides, so the result is only exact for types that form a field, e.g.
+    /// floating point numbers.
+    ///
+    /// Returns [`MatrixError::NotSquare`] if R != C. Returns [`MatrixError::Singular`] if the
+    /// matrix has no inverse, the matrix is left in an unspecified state in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, Inverse };
+    /// let mut data = vec![0.0, 2.0, 4.0, 0.0];
+    /// let mut m = Reftrix::<2, 2, RowPrio, f64>::from_values(&mut data[..]);
+    /// m.invert_in_place().unwrap();
+    /// assert_eq!(&data[..], &[0.0, 0.25, 0.5, 0.0]);
+    /// ```
+    fn invert_in_place(&mut self) -> Result<(), MatrixError>;
+    /// Replaces the matrix with its inverse like [`Inverse::invert_in_place`], reporting the
+    /// pivots that are eliminated to progress.
+    ///
+    /// Returns [`MatrixError::Cancelled`] if progress cancelled the inversion, the matrix is
+    /// left in an unspecified state in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Inverse };
+    /// let mut m = Stacktrix::<4, 2, 2, ColumnPrio, f64>::from_rows([[0.0, 2.0], [4.0, 0.0]]);
+    /// let mut steps = 0;
+    /// m.invert_in_place_with_progress(|progress| {
+    ///     steps = progress.done;
+    ///     ControlFlow::Continue(())
+    /// })
+    /// .unwrap();
+    /// assert_eq!(steps, 2);
+    /// assert_eq!(m.as_slice(), &[0.0, 0.5, 0.25, 0.0]);
+    /// ```
+    fn invert_in_place_with_progress<F>(&mut self, progress: F) -> Result<(), MatrixError>
+    where
+        F: FnMut(Progress) -> ControlFlow<()>;
+}
+
+impl<M, const R: usize, const C: usize, T> Inverse<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+    T: Copy + PartialOrd + From<u8> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    fn invert_in_place(&mut self) -> Result<(), MatrixError> {
+        self.invert_in_place_with_progress(|_| ControlFlow::Continue(()))
+    }
+
+    fn invert_in_place_with_progress<F>(&mut self, mut progress: F) -> Result<(), MatrixError>
+    where
+        F: FnMut(Progress) -> ControlFlow<()>,
+    {
+        if R != C {
+            return Err(MatrixError::NotSquare { rows: R, cols: C });
+        }
+        let (zero, one) = (T::from(0), T::from(1));
+        let abs = |el: T| if el < zero { zero - el } else { el };
+        let at = |row, col| M::Priority::offset((row, col), R, C);
+        let a = self.buffer_mut();
+        // pivoted[k] is set once column k held a pivot, the pivot of step i was found at
+        // (pivot_rows[i], pivot_cols[i]) and moved onto the diagonal by a row swap.
+        let mut pivoted = [false; R];
+        let mut pivot_rows = [0; R];
+        let mut pivot_cols = [0; R];
+        for i in 0..R {
+            let mut max = zero;
+            let (mut row, mut col) = (R, R);
+            for j in (0..R).filter(|&j| !pivoted[j]) {
+                for k in (0..R).filter(|&k| !pivoted[k]) {
+                    let candidate = abs(a[at(j, k)]);
+                    if candidate > max {
+                        max = candidate;
+                        (row, col) = (j, k);
+                    }
+                }
+            }
+            if row == R {
+                return Err(MatrixError::Singular);
+            }
+            pivoted[col] = true;
+            if row != col {
+                for k in 0..R {
+                    a.swap(at(row, k), at(col, k));
+                }
+            }
+            pivot_rows[i] = row;
+            pivot_cols[i] = col;
+
+            let pivot = a[at(col, col)];
+            a[at(col, col)] = one;
+            for k in 0..R {
+                a[at(col, k)] = a[at(col, k)] / pivot;
+            }
+            for j in (0..R).filter(|&j| j != col) {
+                let factor = a[at(j, col)];
+                a[at(j, col)] = zero;
+                for k in 0..R {
+                    a[at(j, k)] = a[at(j, k)] - factor * a[at(col, k)];
+                }
+            }
+            if progress(Progress::of(i + 1, R)).is_break() {
+                return Err(MatrixError::Cancelled);
+            }
+        }
+        // The row swaps of the elimination turn into column swaps of the inverse, undone in
+        // reverse order.
+        for i in (0..R).rev() {
+            if pivot_rows[i] != pivot_cols[i] {
+                for j in 0..R {
+                    a.swap(at(j, pivot_rows[i]), at(j, pivot_cols[i]));
+                }
+            }
+        }
+        Ok(())
+    }
+}
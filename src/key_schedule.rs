@@ -0,0 +1,82 @@
+use crate::{gf256, ColumnPrio, ColumnPrioMatrix, Stacktrix};
+
+/// A round key, the columns of the AES state are the words of the key schedule.
+type RoundKey = Stacktrix<16, 4, 4, ColumnPrio, u8>;
+
+/// The amount of words of the longest key schedule, the one of AES-256.
+const MAX_WORDS: usize = 60;
+
+/// Expands an AES-128 key into the 11 round keys of the key schedule.
+///
+/// The bytes of the key fill the columns one after the other, as in the AES state.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ Stacktrix, ColumnPrio, expand_key_aes128 };
+/// let key = Stacktrix::<16, 4, 4, ColumnPrio, u8>::with_values([
+///     0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f,
+///     0x3c,
+/// ]);
+/// let round_keys = expand_key_aes128(&key);
+/// assert_eq!(round_keys[0], key);
+/// assert_eq!(
+///     round_keys[10],
+///     Stacktrix::<16, 4, 4, ColumnPrio, u8>::with_values([
+///         0xd0, 0x14, 0xf9, 0xa8, 0xc9, 0xee, 0x25, 0x89, 0xe1, 0x3f, 0x0c, 0xc8, 0xb6, 0x63,
+///         0x0c, 0xa6,
+///     ])
+/// );
+/// ```
+pub fn expand_key_aes128(key: &Stacktrix<16, 4, 4, ColumnPrio, u8>) -> [RoundKey; 11] {
+    expand::<16, 4, 11>(key)
+}
+
+/// Expands an AES-192 key into the 13 round keys of the key schedule.
+///
+/// See [`expand_key_aes128`].
+pub fn expand_key_aes192(key: &Stacktrix<24, 4, 6, ColumnPrio, u8>) -> [RoundKey; 13] {
+    expand::<24, 6, 13>(key)
+}
+
+/// Expands an AES-256 key into the 15 round keys of the key schedule.
+///
+/// See [`expand_key_aes128`].
+pub fn expand_key_aes256(key: &Stacktrix<32, 4, 8, ColumnPrio, u8>) -> [RoundKey; 15] {
+    expand::<32, 8, 15>(key)
+}
+
+/// Runs the key schedule for a key of NK words, producing ROUNDS round keys.
+fn expand<const S: usize, const NK: usize, const ROUNDS: usize>(
+    key: &Stacktrix<S, 4, NK, ColumnPrio, u8>,
+) -> [RoundKey; ROUNDS] {
+    let mut words = [[0u8; 4]; MAX_WORDS];
+    for (i, word) in words.iter_mut().enumerate().take(NK) {
+        word.copy_from_slice(key.get_column(i));
+    }
+    let mut rcon = 1;
+    for i in NK..4 * ROUNDS {
+        let mut temp = words[i - 1];
+        if i % NK == 0 {
+            temp.rotate_left(1);
+            temp = temp.map(gf256::sub_byte);
+            temp[0] ^= rcon;
+            rcon = gf256::xtime(rcon);
+        } else if NK > 6 && i % NK == 4 {
+            temp = temp.map(gf256::sub_byte);
+        }
+        for (t, w) in temp.iter_mut().zip(words[i - NK]) {
+            *t ^= w;
+        }
+        words[i] = temp;
+    }
+    core::array::from_fn(|round| {
+        let mut round_key = RoundKey::with_values([0; 16]);
+        for col in 0..4 {
+            round_key
+                .get_mut_column(col)
+                .copy_from_slice(&words[4 * round + col]);
+        }
+        round_key
+    })
+}
@@ -1,5 +1,6 @@
 #![doc = include_str!("../README.md")]
 #![warn(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 //! This library does not aim to be a math library and therefore does not implement
 //! common matrix operations, though they might be implemented over time.
 //!
@@ -15,15 +16,97 @@
 //! * [`Stacktrix`]:
 //! This matrix copies the data and uses a fixed size array on the stack, this way the original
 //! data is not manipulated.
-use std::ops::{Index, IndexMut};
+//!
+//! Types of your own become matrices by implementing [`Storage`].
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::{
+    fmt,
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+#[cfg(feature = "std")]
+use std::io;
 
+mod accumulate;
+#[cfg(feature = "alloc")]
+mod any;
+mod assignment;
+#[cfg(feature = "audit")]
+mod audit;
+mod block;
+mod bulk;
+#[cfg(feature = "bytemuck")]
+mod bytes;
+mod categorical;
+mod diagonal;
+mod display;
+mod elements;
+mod error;
+mod export;
+pub mod expr;
+mod field;
+mod filter;
+mod flow;
+mod gather;
+mod gf2;
+pub mod gf256;
+mod gram_schmidt;
+mod histogram;
+mod inverse;
+mod key_schedule;
+mod macros;
+mod network;
+#[cfg(feature = "npy")]
+mod npy;
+mod orthogonal;
+#[cfg(feature = "std")]
+mod par;
+#[cfg(feature = "alloc")]
+mod patch;
+mod paths;
+mod permutation;
+mod ping_pong;
+mod pivot;
+pub mod policy;
+mod power;
+mod progress;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+mod property;
+mod random;
+mod reduce;
 #[doc(hidden)]
 pub mod reftrix;
+mod reorder;
+mod ring;
+mod scale_add;
+mod search;
+mod semiring;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "verify")]
+mod shadow;
+mod simd;
+mod smoothing;
 #[doc(hidden)]
 pub mod stacktrix;
+mod stats;
+mod storage;
+mod strided;
+mod structure;
+mod triangular;
+mod view;
+mod windows;
+mod zip;
 
 type Position = (usize, usize);
 
+mod sealed {
+    pub trait Sealed {}
+}
+
 /// Matrices ([`Reftrix`], [`Stacktrix`]) with Columnprio use a column first memory representation.
 ///
 /// An array of [1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4]
@@ -50,8 +133,128 @@ pub struct ColumnPrio;
 /// |Row3      | 4       | 4       | 4       | 4       |
 pub struct RowPrio;
 
+/// MemoryPriority describes how the logical (row, col) locations of a matrix map onto its
+/// continuous memory.
+///
+/// The trait is sealed and only implemented by [`ColumnPrio`] and [`RowPrio`].
+pub trait MemoryPriority: sealed::Sealed {
+    /// The name of the memory priority, it identifies the layout in serialized matrices.
+    const NAME: &'static str;
+    /// The distance in memory between (row, col) and (row + 1, col) in a matrix with the given
+    /// amount of rows and columns.
+    fn row_stride(rows: usize, cols: usize) -> usize;
+    /// The distance in memory between (row, col) and (row, col + 1) in a matrix with the given
+    /// amount of rows and columns.
+    fn col_stride(rows: usize, cols: usize) -> usize;
+    /// The offset of location (x, y) in the memory of a matrix with the given amount of rows and
+    /// columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ ColumnPrio, MemoryPriority, RowPrio };
+    /// assert_eq!(ColumnPrio::offset((1, 2), 3, 4), 7);
+    /// assert_eq!(RowPrio::offset((1, 2), 3, 4), 6);
+    /// ```
+    fn offset(location: (usize, usize), rows: usize, cols: usize) -> usize {
+        location.0 * Self::row_stride(rows, cols) + location.1 * Self::col_stride(rows, cols)
+    }
+}
+
+impl sealed::Sealed for ColumnPrio {}
+
+impl MemoryPriority for ColumnPrio {
+    const NAME: &'static str = "ColumnPrio";
+
+    fn row_stride(_rows: usize, _cols: usize) -> usize {
+        1
+    }
+
+    fn col_stride(rows: usize, _cols: usize) -> usize {
+        rows
+    }
+}
+
+impl sealed::Sealed for RowPrio {}
+
+impl MemoryPriority for RowPrio {
+    const NAME: &'static str = "RowPrio";
+
+    fn row_stride(_rows: usize, cols: usize) -> usize {
+        cols
+    }
+
+    fn col_stride(_rows: usize, _cols: usize) -> usize {
+        1
+    }
+}
+
+pub use accumulate::{Accumulate, SaturatingAdd};
+#[cfg(feature = "alloc")]
+pub use any::{AnyElement, AnyElements, AnyMatrix, Element, Priority};
+pub use assignment::Assignment;
+#[cfg(feature = "audit")]
+pub use audit::{AuditLog, Audited, Operation};
+pub use block::BlockMatrix;
+pub use bulk::BulkOps;
+#[cfg(feature = "bytemuck")]
+pub use bytes::ByteCast;
+pub use categorical::Categorical;
+pub use diagonal::{DiagonalIter, DiagonalIterMut, DiagonalSlice, DiagonalSliceMut};
+pub use display::Grid;
+pub use elements::{Elements, Iter, IterIndexed, IterMut, IterMutIndexed};
+pub use error::MatrixError;
+pub use export::Export;
+pub use expr::ToExpr;
+pub use field::{FieldIterIndexed, FieldIterMutIndexed, FieldMatrix};
+pub use filter::RankFilter;
+pub use flow::Flow;
+pub use gather::Gather;
+pub use gf2::BitMatrix;
+pub use gram_schmidt::{Orthonormalize, SquareRoot};
+pub use histogram::{Bins, Histogram2D};
+pub use inverse::Inverse;
+pub use key_schedule::{expand_key_aes128, expand_key_aes192, expand_key_aes256};
+pub use network::SortingNetwork;
+#[cfg(feature = "npy")]
+pub use npy::{Npy, NpyElement};
+pub use orthogonal::OrthogonalTransform;
+#[cfg(feature = "std")]
+pub use par::ParColMap;
+#[cfg(feature = "alloc")]
+pub use patch::{MatrixPatch, Patch};
+pub use paths::Paths;
+pub use permutation::{Canonicalize, LanePermutation, Permutation};
+pub use ping_pong::PingPong;
+pub use pivot::Pivot;
+pub use policy::{IndexPolicy, Sample};
+pub use power::{Convergence, PowerIteration};
+pub use progress::Progress;
+#[cfg(feature = "proptest")]
+pub use property::StacktrixStrategy;
+#[cfg(feature = "rand")]
+pub use random::RandomFill;
+pub use random::{PseudoRandom, PseudoRandomFill};
+pub use reduce::Reduce;
 pub use reftrix::Reftrix;
+pub use reorder::Reorder;
+pub use ring::RowRing;
+pub use scale_add::ScaleAdd;
+pub use search::Search;
+pub use semiring::{Arithmetic, Boolean, MinPlus, Semiring, SemiringOps, Tropical};
+#[cfg(feature = "verify")]
+pub use shadow::Shadow;
+pub use simd::Lane;
+pub use smoothing::Smoothing;
 pub use stacktrix::Stacktrix;
+pub use stats::OnlineStats;
+pub use storage::{Storage, StorageMut};
+pub use strided::{Strided, StridedIter, StridedIterMut, StridedMut, StridedView};
+pub use structure::Structure;
+pub use triangular::{Diagonal, TriangularSolve};
+pub use view::{MatrixView, MatrixViewMut, SliceAxis, SliceInfo, View};
+pub use windows::{Chunks, ChunksMut, Windows};
+pub use zip::Zip;
 
 /// ColumnPrioMatrix encapsulates all functionality a matrix has that uses the memory
 /// interpretation ColumnPrio.
@@ -72,7 +275,9 @@ pub trait ColumnPrioMatrix<'a, const R: usize, const C: usize, T> {
     /// assert_eq!(reftrix.get((3, 0)), &0);
     /// assert_eq!(data[3], 0);
     /// ```
-    fn insert(&mut self, location: (usize, usize), value: T);
+    fn insert(&mut self, location: (usize, usize), value: T)
+    where
+        Self: StorageMut<R, C, T>;
     /// Get a immutable reference to a value in the matrix at location (x, y)
     ///
     /// # Panics
@@ -93,7 +298,9 @@ pub trait ColumnPrioMatrix<'a, const R: usize, const C: usize, T> {
     /// # Panics
     ///
     /// If the location given is out of bounds in x or y the function panics.
-    fn get_mut(&'a mut self, location: (usize, usize)) -> &'a mut T;
+    fn get_mut(&'a mut self, location: (usize, usize)) -> &'a mut T
+    where
+        Self: StorageMut<R, C, T>;
     /// Fills an entire column with the given data.
     ///
     /// # Panics
@@ -111,7 +318,9 @@ pub trait ColumnPrioMatrix<'a, const R: usize, const C: usize, T> {
     /// reftrix.fill_col(1, &[7,7,7,7]);
     /// assert_eq!(&data[4..8], &[7,7,7,7]);
     /// ```
-    fn fill_col(&mut self, col: usize, data: &[T]);
+    fn fill_col(&mut self, col: usize, data: &[T])
+    where
+        Self: StorageMut<R, C, T>;
     /// Fills an entire row with the given data.
     ///
     /// # Panics
@@ -136,7 +345,91 @@ pub trait ColumnPrioMatrix<'a, const R: usize, const C: usize, T> {
     /// assert_eq!(data[9], 7);
     /// assert_eq!(data[13], 7);
     /// ```
-    fn fill_row(&mut self, row: usize, data: &[T]);
+    fn fill_row(&mut self, row: usize, data: &[T])
+    where
+        Self: StorageMut<R, C, T>;
+    /// Sets every element to value, for bytes this is a single memset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut m = Stacktrix::<6, 2, 3, ColumnPrio, u8>::with_values([1, 2, 3, 4, 5, 6]);
+    /// m.fill(0xff);
+    /// assert_eq!(m.as_slice(), &[0xff; 6]);
+    /// ```
+    fn fill(&mut self, value: T)
+    where
+        Self: StorageMut<R, C, T>;
+    /// Sets every element to the next value of the generator, in the order the elements lie in
+    /// memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut m = Stacktrix::<4, 2, 2, ColumnPrio, u32>::zeros();
+    /// let mut next = 0;
+    /// m.fill_with(|| {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// assert_eq!(m.as_slice(), &[1, 2, 3, 4]);
+    /// ```
+    fn fill_with<F>(&mut self, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut() -> T;
+    /// Sets every element to its default value, e.g. zero for numbers.
+    fn clear(&mut self)
+    where
+        Self: StorageMut<R, C, T>;
+    /// Applies a function on every element of the row row, in order.
+    ///
+    /// # Panics
+    ///
+    /// If the row is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut m = Stacktrix::<4, 2, 2, ColumnPrio, u8>::from_rows([[1, 2], [3, 4]]);
+    /// let mut offset = 0;
+    /// m.apply_row(1, |el| {
+    ///     *el += offset;
+    ///     offset += 10;
+    /// });
+    /// assert_eq!(m.get((1, 0)), &3);
+    /// assert_eq!(m.get((1, 1)), &14);
+    /// ```
+    fn apply_row<F>(&mut self, row: usize, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T);
+    /// Applies a function on every element of the column col, in order.
+    ///
+    /// # Panics
+    ///
+    /// If the column is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut m = Stacktrix::<4, 2, 2, ColumnPrio, u8>::from_rows([[1, 2], [3, 4]]);
+    /// let mut offset = 0;
+    /// m.apply_col(1, |el| {
+    ///     *el += offset;
+    ///     offset += 10;
+    /// });
+    /// assert_eq!(m.get((0, 1)), &2);
+    /// assert_eq!(m.get((1, 1)), &14);
+    /// ```
+    fn apply_col<F>(&mut self, col: usize, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T);
     /// Retrieves a immutable slice that represents the column.
     ///
     /// # Panics
@@ -157,7 +450,9 @@ pub trait ColumnPrioMatrix<'a, const R: usize, const C: usize, T> {
     /// # Panics
     ///
     /// If the column is out of bounds.
-    fn get_mut_column(&mut self, col: usize) -> &mut [T];
+    fn get_mut_column(&mut self, col: usize) -> &mut [T]
+    where
+        Self: StorageMut<R, C, T>;
     /// Retrieves a [`IntermittentSlice`].
     ///
     /// # Panics
@@ -169,16 +464,209 @@ pub trait ColumnPrioMatrix<'a, const R: usize, const C: usize, T> {
     /// # Panics
     ///
     /// If the row is out of bounds.
-    fn get_mut_row(&mut self, row: usize) -> IntermittentSliceMut<'_, R, C, T>;
+    fn get_mut_row(&mut self, row: usize) -> IntermittentSliceMut<'_, R, C, T>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Retrieves the columns a and b mutably at the same time, e.g. to add a multiple of one
+    /// column to another.
+    ///
+    /// # Panics
+    ///
+    /// If either column is out of bounds or a == b.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6];
+    /// let mut reftrix = Reftrix::<2, 3, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// let (c2, c0) = reftrix.get_mut_columns(2, 0);
+    /// c2.swap_with_slice(c0);
+    /// assert_eq!(data, [5, 6, 3, 4, 1, 2]);
+    /// ```
+    fn get_mut_columns(&mut self, a: usize, b: usize) -> (&mut [T], &mut [T])
+    where
+        Self: StorageMut<R, C, T>;
+    /// Retrieves the rows a and b mutably at the same time as two [`IntermittentSliceMut`].
+    ///
+    /// # Panics
+    ///
+    /// If either row is out of bounds or a == b.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6];
+    /// let mut reftrix = Reftrix::<2, 3, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// let (r0, r1) = reftrix.get_mut_rows(0, 1);
+    /// for (x, y) in r0.into_iter().zip(r1) {
+    ///     *x += *y;
+    /// }
+    /// assert_eq!(data, [3, 2, 7, 4, 11, 6]);
+    /// ```
+    fn get_mut_rows(
+        &mut self,
+        a: usize,
+        b: usize,
+    ) -> (
+        IntermittentSliceMut<'_, R, C, T>,
+        IntermittentSliceMut<'_, R, C, T>,
+    )
+    where
+        Self: StorageMut<R, C, T>;
+    /// Inserts a value at position (x, y) inside the matrix.
+    ///
+    /// Returns [`MatrixError::OutOfBounds`] instead of panicking if the location is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, ColumnPrioMatrix, MatrixError };
+    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
+    /// let mut reftrix = Reftrix::<4, 4, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(reftrix.try_insert((3, 0), 0), Ok(()));
+    /// assert_eq!(
+    ///     reftrix.try_insert((4, 0), 0),
+    ///     Err(MatrixError::OutOfBounds { row: 4, col: 0, rows: 4, cols: 4 })
+    /// );
+    /// assert_eq!(data[3], 0);
+    /// ```
+    fn try_insert(&mut self, location: (usize, usize), value: T) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Get a immutable reference to a value in the matrix at location (x, y) or None if the
+    /// location is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
+    /// let mut reftrix = Reftrix::<4, 4, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(reftrix.try_get((0, 2)), Some(&3));
+    /// assert_eq!(reftrix.try_get((0, 4)), None);
+    /// ```
+    fn try_get(&'a self, location: (usize, usize)) -> Option<&'a T>;
+    /// Get a mutable reference to a value in the matrix at location (x, y) or None if the
+    /// location is out of bounds.
+    fn try_get_mut(&'a mut self, location: (usize, usize)) -> Option<&'a mut T>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Get mutable references to the values at N locations at once or None if a location is
+    /// out of bounds or two locations are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix, Export };
+    /// let mut m = Stacktrix::<4, 2, 2, ColumnPrio, i32>::from_rows([[1, 2], [3, 4]]);
+    /// let [a, b] = m.get_disjoint_mut([(0, 1), (1, 0)]).unwrap();
+    /// (*a, *b) = (*a + *b, *a - *b);
+    /// assert_eq!(m.to_rows(), [[1, 5], [-1, 4]]);
+    /// assert!(m.get_disjoint_mut([(0, 0), (0, 0)]).is_none());
+    /// assert!(m.get_disjoint_mut([(0, 0), (0, 2)]).is_none());
+    /// ```
+    fn get_disjoint_mut<const N: usize>(
+        &mut self,
+        locations: [(usize, usize); N],
+    ) -> Option<[&mut T; N]>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Fills an entire column with the given data.
+    ///
+    /// Returns [`MatrixError::ColumnOutOfBounds`] if the column is out of bounds and
+    /// [`MatrixError::SizeMismatch`] if the data is not the size of a column.
+    fn try_fill_col(&mut self, col: usize, data: &[T]) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Fills an entire row with the given data.
+    ///
+    /// Returns [`MatrixError::RowOutOfBounds`] if the row is out of bounds and
+    /// [`MatrixError::SizeMismatch`] if the data is not the size of a row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, ColumnPrioMatrix, MatrixError };
+    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
+    /// let mut reftrix = Reftrix::<4, 4, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(
+    ///     reftrix.try_fill_row(1, &[7,7,7]),
+    ///     Err(MatrixError::SizeMismatch { expected: 4, got: 3 })
+    /// );
+    /// assert_eq!(reftrix.try_fill_row(1, &[7,7,7,7]), Ok(()));
+    /// assert_eq!(data[5], 7);
+    /// ```
+    fn try_fill_row(&mut self, row: usize, data: &[T]) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Retrieves a immutable slice that represents the column or None if the column is out of
+    /// bounds.
+    fn try_get_column(&self, col: usize) -> Option<&[T]>;
+    /// Retrieves a [`IntermittentSlice`] or None if the row is out of bounds.
+    fn try_get_row(&self, row: usize) -> Option<IntermittentSlice<'_, R, C, T>>;
     /// Returns an iterator over all rows [`IntermittentSlice`] inside the matrix.
     fn rows(&self) -> IterIntermittentSlices<'_, R, C, T>;
     /// Returns an iterator over all rows in a mutable manner [`IntermittentSliceMut`] inside the matrix.
-    fn rows_mut(&mut self) -> IterMutIntermittentSlices<'_, R, C, T>;
+    fn rows_mut(&mut self) -> IterMutIntermittentSlices<'_, R, C, T>
+    where
+        Self: StorageMut<R, C, T>;
     /// Returns an iterator over all collumns (slices) inside the matrix.
-    fn cols(&self) -> IterSlices<'_, R, C, T>;
+    fn cols(&self) -> IterSlices<'_, C, R, T>;
     /// Returns an iterator over all collumns in a mutable manner (mutable slices) inside the matrix.
-    fn cols_mut(&mut self) -> IterSlicesMut<'_, R, C, T>;
-    /// Applies a function on all elements of the matrix.
+    fn cols_mut(&mut self) -> IterSlicesMut<'_, C, R, T>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Returns a [`DiagonalSlice`] over the main diagonal, the elements (i, i) for i in
+    /// 0..min(R, C).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let m = Reftrix::<3, 3, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(m.diagonal().into_iter().sum::<u8>(), 15);
+    /// ```
+    fn diagonal(&self) -> DiagonalSlice<'_, T>;
+    /// Returns a [`DiagonalSliceMut`] over the main diagonal.
+    fn diagonal_mut(&mut self) -> DiagonalSliceMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Returns a [`DiagonalSlice`] over the anti diagonal, the elements (i, C - 1 - i) for i in
+    /// 0..min(R, C).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let m = Reftrix::<3, 3, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(m.anti_diagonal().into_iter().copied().collect::<Vec<_>>(), vec![7, 5, 3]);
+    /// ```
+    fn anti_diagonal(&self) -> DiagonalSlice<'_, T>;
+    /// Returns a [`DiagonalSliceMut`] over the anti diagonal.
+    fn anti_diagonal_mut(&mut self) -> DiagonalSliceMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Sets every element of the main diagonal to value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut data = vec![0; 6];
+    /// let mut m = Reftrix::<2, 3, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// m.fill_diagonal(1);
+    /// assert_eq!(&data[..], &[1, 0, 0, 1, 0, 0]);
+    /// ```
+    fn fill_diagonal(&mut self, value: T)
+    where
+        Self: StorageMut<R, C, T>;
+    /// Applies a function on all elements of the matrix, in the order they lie in memory.
+    ///
+    /// f may capture its environment, e.g. a lookup table or a scale factor.
     ///
     /// # Examples
     ///
@@ -186,11 +674,87 @@ pub trait ColumnPrioMatrix<'a, const R: usize, const C: usize, T> {
     /// # use mightrix::{ Reftrix, ColumnPrio, ColumnPrioMatrix };
     /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
     /// let mut reftrix = Reftrix::<4, 4, ColumnPrio, u8>::from_values(&mut data[..]);
-    /// reftrix.apply_all(|el| *el *= 2);
+    /// let factor = 2;
+    /// reftrix.apply_all(|el| *el *= factor);
     /// assert_eq!(&data[..], &[2,2,2,2,4,4,4,4,6,6,6,6,8,8,8,8]);
     /// ```
-    fn apply_all(&mut self, f: fn(_: &mut T));
+    fn apply_all<F>(&mut self, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T);
+    /// Applies a function on all elements of the matrix, walking the buffer in chunks of
+    /// chunk_len elements. With prefetch the next chunk is requested from memory while the
+    /// current one is processed, this takes effect with the `simd` feature on x86_64.
+    ///
+    /// # Panics
+    ///
+    /// If chunk_len is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut m = Stacktrix::<6, 2, 3, ColumnPrio, u32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// m.apply_all_chunked(4, true, |el| *el += 10);
+    /// assert_eq!(m, Stacktrix::<6, 2, 3, ColumnPrio, u32>::from_rows([[11, 12, 13], [14, 15, 16]]));
+    /// ```
+    fn apply_all_chunked<F>(&mut self, chunk_len: usize, prefetch: bool, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T);
+    /// Applies a fallible function on the elements of the matrix in the order they lie in
+    /// memory, stopping at the first error.
+    ///
+    /// The elements before the failing one keep their new values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut m = Stacktrix::<4, 2, 2, ColumnPrio, u8>::with_values([1, 2, 200, 4]);
+    /// let doubled = m.try_apply_all(|el| {
+    ///     *el = el.checked_mul(2).ok_or(*el)?;
+    ///     Ok(())
+    /// });
+    /// assert_eq!(doubled, Err(200));
+    /// assert_eq!(m.as_slice(), &[2, 4, 200, 4]);
+    /// ```
+    fn try_apply_all<E, F>(&mut self, f: F) -> Result<(), E>
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T) -> Result<(), E>;
+    /// Returns a [`Grid`] that formats every cell of the matrix with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut data = vec![1, 20, 3, 4, 5, 6];
+    /// let m = Reftrix::<2, 3, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(
+    ///     m.display_with(|el, f| write!(f, "{:x}", el)).to_string(),
+    ///     " 1 3 5\n14 4 6\n"
+    /// );
+    /// ```
+    fn display_with<F>(&self, cell: F) -> Grid<'_, R, C, ColumnPrio, T, F>
+    where
+        F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result;
+    /// Writes the matrix in the format of [`pretty_print`](ColumnPrioMatrix::pretty_print) to w.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6];
+    /// let m = Reftrix::<2, 3, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// let mut out = Vec::new();
+    /// m.write_pretty(&mut out).unwrap();
+    /// assert_eq!(out, b"01-03-05\n02-04-06\n");
+    /// ```
+    #[cfg(feature = "std")]
+    fn write_pretty<W: io::Write>(&self, w: &mut W) -> io::Result<()>;
     /// Prints out the matrix, this is only usefull for numeric types.
+    #[cfg(feature = "std")]
     fn pretty_print(&self);
 }
 
@@ -212,7 +776,9 @@ pub trait RowPrioMatrix<'a, const R: usize, const C: usize, T> {
     /// reftrix.insert((3, 1), 0);
     /// assert_eq!(data[13], 0);
     /// ```
-    fn insert(&mut self, location: (usize, usize), value: T);
+    fn insert(&mut self, location: (usize, usize), value: T)
+    where
+        Self: StorageMut<R, C, T>;
     /// Get a immutable reference to a value in the matrix at location (x, y)
     ///
     /// # Panics
@@ -233,7 +799,9 @@ pub trait RowPrioMatrix<'a, const R: usize, const C: usize, T> {
     /// # Panics
     ///
     /// If the location given is out of bounds in x or y the function panics.
-    fn get_mut(&mut self, location: (usize, usize)) -> &mut T;
+    fn get_mut(&mut self, location: (usize, usize)) -> &mut T
+    where
+        Self: StorageMut<R, C, T>;
     /// Fills an entire row with the given data.
     ///
     /// # Panics
@@ -251,7 +819,9 @@ pub trait RowPrioMatrix<'a, const R: usize, const C: usize, T> {
     /// reftrix.fill_row(1, &[7,7,7,7]);
     /// assert_eq!(&data[4..8], &[7,7,7,7]);
     /// ```
-    fn fill_row(&mut self, row: usize, data: &[T]);
+    fn fill_row(&mut self, row: usize, data: &[T])
+    where
+        Self: StorageMut<R, C, T>;
     /// Fills an entire column with the given data.
     ///
     /// # Panics
@@ -272,19 +842,133 @@ pub trait RowPrioMatrix<'a, const R: usize, const C: usize, T> {
     /// assert_eq!(data[9], 7);
     /// assert_eq!(data[13], 7);
     /// ```
-    fn fill_col(&'a mut self, col: usize, data: &[T]);
+    fn fill_col(&mut self, col: usize, data: &[T])
+    where
+        Self: StorageMut<R, C, T>;
+    /// Sets every element to value, for bytes this is a single memset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix };
+    /// let mut m = Stacktrix::<6, 2, 3, RowPrio, u8>::with_values([1, 2, 3, 4, 5, 6]);
+    /// m.fill(0xff);
+    /// assert_eq!(m.as_slice(), &[0xff; 6]);
+    /// ```
+    fn fill(&mut self, value: T)
+    where
+        Self: StorageMut<R, C, T>;
+    /// Sets every element to the next value of the generator, in the order the elements lie in
+    /// memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix };
+    /// let mut m = Stacktrix::<4, 2, 2, RowPrio, u32>::zeros();
+    /// let mut next = 0;
+    /// m.fill_with(|| {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// assert_eq!(m.as_slice(), &[1, 2, 3, 4]);
+    /// ```
+    fn fill_with<F>(&mut self, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut() -> T;
+    /// Sets every element to its default value, e.g. zero for numbers.
+    fn clear(&mut self)
+    where
+        Self: StorageMut<R, C, T>;
+    /// Applies a function on every element of the row row, in order.
+    ///
+    /// # Panics
+    ///
+    /// If the row is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix };
+    /// let mut m = Stacktrix::<4, 2, 2, RowPrio, u8>::from_rows([[1, 2], [3, 4]]);
+    /// let mut offset = 0;
+    /// m.apply_row(1, |el| {
+    ///     *el += offset;
+    ///     offset += 10;
+    /// });
+    /// assert_eq!(m.get((1, 0)), &3);
+    /// assert_eq!(m.get((1, 1)), &14);
+    /// ```
+    fn apply_row<F>(&mut self, row: usize, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T);
+    /// Applies a function on every element of the column col, in order.
+    ///
+    /// # Panics
+    ///
+    /// If the column is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix };
+    /// let mut m = Stacktrix::<4, 2, 2, RowPrio, u8>::from_rows([[1, 2], [3, 4]]);
+    /// let mut offset = 0;
+    /// m.apply_col(1, |el| {
+    ///     *el += offset;
+    ///     offset += 10;
+    /// });
+    /// assert_eq!(m.get((0, 1)), &2);
+    /// assert_eq!(m.get((1, 1)), &14);
+    /// ```
+    fn apply_col<F>(&mut self, col: usize, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T);
     /// Retrieves a [`IntermittentSlice`].
     ///
     /// # Panics
     ///
     /// If the Columns is out of bounds.
-    fn get_column(&self, col: usize) -> IntermittentSlice<'_, R, C, T>;
+    fn get_column(&self, col: usize) -> IntermittentSlice<'_, C, R, T>;
     /// Retrieves a [`IntermittentSliceMut`].
     ///
     /// # Panics
     ///
     /// If the Columns is out of bounds.
-    fn get_mut_column(&mut self, col: usize) -> IntermittentSliceMut<'_, R, C, T>;
+    fn get_mut_column(&mut self, col: usize) -> IntermittentSliceMut<'_, C, R, T>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Retrieves the columns a and b mutably at the same time as two [`IntermittentSliceMut`].
+    ///
+    /// # Panics
+    ///
+    /// If either column is out of bounds or a == b.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6];
+    /// let mut reftrix = Reftrix::<2, 3, RowPrio, u8>::from_values(&mut data[..]);
+    /// let (c0, c2) = reftrix.get_mut_columns(0, 2);
+    /// for (x, y) in c0.into_iter().zip(c2) {
+    ///     *x += *y;
+    /// }
+    /// assert_eq!(data, [4, 2, 3, 10, 5, 6]);
+    /// ```
+    fn get_mut_columns(
+        &mut self,
+        a: usize,
+        b: usize,
+    ) -> (
+        IntermittentSliceMut<'_, C, R, T>,
+        IntermittentSliceMut<'_, C, R, T>,
+    )
+    where
+        Self: StorageMut<R, C, T>;
     /// Retrieves a immutable slice that represents the row.
     ///
     /// # Panics
@@ -296,103 +980,782 @@ pub trait RowPrioMatrix<'a, const R: usize, const C: usize, T> {
     /// # Panics
     ///
     /// If the row is out of bounds.
-    fn get_mut_row(&mut self, row: usize) -> &mut [T];
-    /// Returns an iterator over all rows [`IntermittentSlice`] inside the matrix.
-    fn rows(&self) -> IterSlices<'_, R, C, T>;
-    /// Returns an iterator over all rows in a mutable manner [`IntermittentSliceMut`] inside the matrix.
-    fn rows_mut(&mut self) -> IterSlicesMut<'_, R, C, T>;
-    /// Returns an iterator over all collumns (slices) inside the matrix.
-    fn cols(&self) -> IterIntermittentSlices<'_, R, C, T>;
-    /// Returns an iterator over all collumns in a mutable manner (mutable slices) inside the matrix.
-    fn cols_mut(&mut self) -> IterMutIntermittentSlices<'_, R, C, T>;
-    /// Applies a function on all elements of the matrix.
+    fn get_mut_row(&mut self, row: usize) -> &mut [T]
+    where
+        Self: StorageMut<R, C, T>;
+    /// Retrieves the rows a and b mutably at the same time, e.g. to subtract a multiple of one
+    /// row from another during elimination.
+    ///
+    /// # Panics
+    ///
+    /// If either row is out of bounds or a == b.
     ///
     /// # Examples
     ///
     /// ```
     /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6];
+    /// let mut reftrix = Reftrix::<2, 3, RowPrio, u8>::from_values(&mut data[..]);
+    /// let (r1, r0) = reftrix.get_mut_rows(1, 0);
+    /// for (x, y) in r1.iter_mut().zip(r0.iter()) {
+    ///     *x -= *y;
+    /// }
+    /// assert_eq!(data, [1, 2, 3, 3, 3, 3]);
+    /// ```
+    fn get_mut_rows(&mut self, a: usize, b: usize) -> (&mut [T], &mut [T])
+    where
+        Self: StorageMut<R, C, T>;
+    /// Inserts a value at position (x, y) inside the matrix.
+    ///
+    /// Returns [`MatrixError::OutOfBounds`] instead of panicking if the location is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix, MatrixError };
     /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
     /// let mut reftrix = Reftrix::<4, 4, RowPrio, u8>::from_values(&mut data[..]);
-    /// reftrix.apply_all(|el| *el *= 2);
-    /// assert_eq!(&data[..], &[2,2,2,2,4,4,4,4,6,6,6,6,8,8,8,8]);
+    /// assert_eq!(reftrix.try_insert((3, 1), 0), Ok(()));
+    /// assert_eq!(
+    ///     reftrix.try_insert((3, 4), 0),
+    ///     Err(MatrixError::OutOfBounds { row: 3, col: 4, rows: 4, cols: 4 })
+    /// );
+    /// assert_eq!(data[13], 0);
     /// ```
-    fn apply_all(&mut self, f: fn(_: &mut T));
-    /// Prints out the matrix, this is only usefull for numeric types.
-    fn pretty_print(&self);
-}
-
-/// The IntermittentSlice struct represents a imutable matrix row  or col in [`ColumnPrio`] / [`RowPrio`] matrices.
-///
-/// Since the underlying data is not continuous all slice operations are unavailable to the IntermittentSlice
-/// struct. It can however be indexed and iterated over.
-/// Const A represents the amount of slices in the Matrix, const S represents the length of each
-/// slice.
-pub struct IntermittentSlice<'a, const A: usize, const S: usize, T> {
-    start: &'a T,
-}
-
-impl<'a, const A: usize, const S: usize, T> Index<usize> for IntermittentSlice<'a, A, S, T> {
-    type Output = T;
-
-    fn index(&self, index: usize) -> &Self::Output {
-        if index >= S {
-            panic!("Index {index} out of bounds {}", S);
-        }
-        unsafe { &*((self.start as *const T).add(index * A)) }
-    }
-}
-
-/// The IntermittentSliceMut struct represents a mutable matrix row or col in all [`ColumnPrio`] / [`RowPrio`] matrices.
-///
-/// Since the underlying data is not continuous all slice operations are unavailable to the IntermittentSliceMut
-/// struct. It can however be indexed and iterated over.
-/// Const A represents the amount of slices in the Matrix, const S represents the length of each
-/// slice.
-pub struct IntermittentSliceMut<'a, const A: usize, const S: usize, T> {
-    start: &'a mut T,
-}
-
-impl<'a, const A: usize, const S: usize, T> IntermittentSliceMut<'a, A, S, T> {
-    /// swap allows for a memswap in non continuous memory this is not possible in safe rust since
-    /// you need to have two mutable references.
+    fn try_insert(&mut self, location: (usize, usize), value: T) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Get a immutable reference to a value in the matrix at location (x, y) or None if the
+    /// location is out of bounds.
     ///
-    /// SAFETY:
-    /// Since the index operation guarantees that a and b are in bounds and are therefore valid
-    /// the swap operation is safe.
-    pub fn swap(&mut self, a: usize, b: usize) {
-        unsafe {
-            std::mem::swap(
-                &mut *(&mut self[a] as *mut T),
-                &mut *(&mut self[b] as *mut T),
-            );
-        }
-    }
-}
-
-impl<'a, const A: usize, const S: usize, T> Index<usize> for IntermittentSliceMut<'a, A, S, T> {
-    type Output = T;
-
-    fn index(&self, index: usize) -> &Self::Output {
-        if index >= S {
-            panic!("Index {index} out of bounds {}", S);
-        }
-        unsafe { &*((self.start as *const T).add(index * A)) }
-    }
-}
-
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix };
+    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
+    /// let mut reftrix = Reftrix::<4, 4, RowPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(reftrix.try_get((2, 0)), Some(&3));
+    /// assert_eq!(reftrix.try_get((4, 0)), None);
+    /// ```
+    fn try_get(&self, location: (usize, usize)) -> Option<&T>;
+    /// Get a mutable reference to a value in the matrix at location (x, y) or None if the
+    /// location is out of bounds.
+    fn try_get_mut(&mut self, location: (usize, usize)) -> Option<&mut T>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Get mutable references to the values at N locations at once or None if a location is
+    /// out of bounds or two locations are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix, Export };
+    /// let mut m = Stacktrix::<6, 2, 3, RowPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let [a, b, c] = m.get_disjoint_mut([(0, 0), (1, 2), (1, 1)]).unwrap();
+    /// core::mem::swap(a, b);
+    /// *c += 10;
+    /// assert_eq!(m.to_rows(), [[6, 2, 3], [4, 15, 1]]);
+    /// assert!(m.get_disjoint_mut([(2, 0)]).is_none());
+    /// ```
+    fn get_disjoint_mut<const N: usize>(
+        &mut self,
+        locations: [(usize, usize); N],
+    ) -> Option<[&mut T; N]>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Fills an entire row with the given data.
+    ///
+    /// Returns [`MatrixError::RowOutOfBounds`] if the row is out of bounds and
+    /// [`MatrixError::SizeMismatch`] if the data is not the size of a row.
+    fn try_fill_row(&mut self, row: usize, data: &[T]) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Fills an entire column with the given data.
+    ///
+    /// Returns [`MatrixError::ColumnOutOfBounds`] if the column is out of bounds and
+    /// [`MatrixError::SizeMismatch`] if the data is not the size of a column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix, MatrixError };
+    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
+    /// let mut reftrix = Reftrix::<4, 4, RowPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(
+    ///     reftrix.try_fill_col(4, &[7,7,7,7]),
+    ///     Err(MatrixError::ColumnOutOfBounds { col: 4, cols: 4 })
+    /// );
+    /// assert_eq!(reftrix.try_fill_col(1, &[7,7,7,7]), Ok(()));
+    /// assert_eq!(data[5], 7);
+    /// ```
+    fn try_fill_col(&mut self, col: usize, data: &[T]) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Retrieves a [`IntermittentSlice`] or None if the column is out of bounds.
+    fn try_get_column(&self, col: usize) -> Option<IntermittentSlice<'_, C, R, T>>;
+    /// Retrieves a immutable slice that represents the row or None if the row is out of bounds.
+    fn try_get_row(&self, row: usize) -> Option<&[T]>;
+    /// Returns an iterator over all rows (slices) inside the matrix.
+    fn rows(&self) -> IterSlices<'_, R, C, T>;
+    /// Returns an iterator over all rows in a mutable manner (mutable slices) inside the matrix.
+    fn rows_mut(&mut self) -> IterSlicesMut<'_, R, C, T>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Returns an iterator over all collumns [`IntermittentSlice`] inside the matrix.
+    fn cols(&self) -> IterIntermittentSlices<'_, C, R, T>;
+    /// Returns an iterator over all collumns in a mutable manner [`IntermittentSliceMut`] inside the matrix.
+    fn cols_mut(&mut self) -> IterMutIntermittentSlices<'_, C, R, T>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Returns a [`DiagonalSlice`] over the main diagonal, the elements (i, i) for i in
+    /// 0..min(R, C).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let m = Reftrix::<3, 3, RowPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(m.diagonal().into_iter().sum::<u8>(), 15);
+    /// ```
+    fn diagonal(&self) -> DiagonalSlice<'_, T>;
+    /// Returns a [`DiagonalSliceMut`] over the main diagonal.
+    fn diagonal_mut(&mut self) -> DiagonalSliceMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Returns a [`DiagonalSlice`] over the anti diagonal, the elements (i, C - 1 - i) for i in
+    /// 0..min(R, C).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let m = Reftrix::<3, 3, RowPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(m.anti_diagonal().into_iter().copied().collect::<Vec<_>>(), vec![3, 5, 7]);
+    /// ```
+    fn anti_diagonal(&self) -> DiagonalSlice<'_, T>;
+    /// Returns a [`DiagonalSliceMut`] over the anti diagonal.
+    fn anti_diagonal_mut(&mut self) -> DiagonalSliceMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Sets every element of the main diagonal to value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix };
+    /// let mut data = vec![0; 6];
+    /// let mut m = Reftrix::<2, 3, RowPrio, u8>::from_values(&mut data[..]);
+    /// m.fill_diagonal(1);
+    /// assert_eq!(&data[..], &[1, 0, 0, 0, 1, 0]);
+    /// ```
+    fn fill_diagonal(&mut self, value: T)
+    where
+        Self: StorageMut<R, C, T>;
+    /// Applies a function on all elements of the matrix, in the order they lie in memory.
+    ///
+    /// f may capture its environment, e.g. a lookup table or a scale factor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix };
+    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
+    /// let mut reftrix = Reftrix::<4, 4, RowPrio, u8>::from_values(&mut data[..]);
+    /// let factor = 2;
+    /// reftrix.apply_all(|el| *el *= factor);
+    /// assert_eq!(&data[..], &[2,2,2,2,4,4,4,4,6,6,6,6,8,8,8,8]);
+    /// ```
+    fn apply_all<F>(&mut self, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T);
+    /// Applies a function on all elements of the matrix, walking the buffer in chunks of
+    /// chunk_len elements. With prefetch the next chunk is requested from memory while the
+    /// current one is processed, this takes effect with the `simd` feature on x86_64.
+    ///
+    /// # Panics
+    ///
+    /// If chunk_len is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix };
+    /// let mut m = Stacktrix::<6, 2, 3, RowPrio, u32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// m.apply_all_chunked(4, true, |el| *el += 10);
+    /// assert_eq!(m, Stacktrix::<6, 2, 3, RowPrio, u32>::from_rows([[11, 12, 13], [14, 15, 16]]));
+    /// ```
+    fn apply_all_chunked<F>(&mut self, chunk_len: usize, prefetch: bool, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T);
+    /// Applies a fallible function on the elements of the matrix in the order they lie in
+    /// memory, stopping at the first error.
+    ///
+    /// The elements before the failing one keep their new values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix };
+    /// let mut m = Stacktrix::<4, 2, 2, RowPrio, u8>::with_values([1, 2, 200, 4]);
+    /// let doubled = m.try_apply_all(|el| {
+    ///     *el = el.checked_mul(2).ok_or(*el)?;
+    ///     Ok(())
+    /// });
+    /// assert_eq!(doubled, Err(200));
+    /// assert_eq!(m.as_slice(), &[2, 4, 200, 4]);
+    /// ```
+    fn try_apply_all<E, F>(&mut self, f: F) -> Result<(), E>
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T) -> Result<(), E>;
+    /// Returns a [`Grid`] that formats every cell of the matrix with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix };
+    /// let mut data = vec![1, 20, 3, 4, 5, 6];
+    /// let m = Reftrix::<2, 3, RowPrio, u8>::from_values(&mut data[..]);
+    /// assert_eq!(
+    ///     m.display_with(|el, f| write!(f, "{:x}", el)).to_string(),
+    ///     "1 14 3\n4  5 6\n"
+    /// );
+    /// ```
+    fn display_with<F>(&self, cell: F) -> Grid<'_, R, C, RowPrio, T, F>
+    where
+        F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result;
+    /// Writes the matrix in the format of [`pretty_print`](RowPrioMatrix::pretty_print) to w.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6];
+    /// let m = Reftrix::<2, 3, RowPrio, u8>::from_values(&mut data[..]);
+    /// let mut out = Vec::new();
+    /// m.write_pretty(&mut out).unwrap();
+    /// assert_eq!(out, b"01-02-03\n04-05-06\n");
+    /// ```
+    #[cfg(feature = "std")]
+    fn write_pretty<W: io::Write>(&self, w: &mut W) -> io::Result<()>;
+    /// Prints out the matrix, this is only usefull for numeric types.
+    #[cfg(feature = "std")]
+    fn pretty_print(&self);
+}
+
+/// The IntermittentSlice struct represents a imutable matrix row  or col in [`ColumnPrio`] / [`RowPrio`] matrices.
+///
+/// Since the underlying data is not continuous all slice operations are unavailable to the IntermittentSlice
+/// struct. It can however be indexed, iterated over and compared with slices and arrays.
+/// Const A represents the amount of slices in the Matrix, const S represents the length of each
+/// slice.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+/// let m = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from_rows([[1, 2, 3], [7, 7, 7]]);
+/// assert_eq!(m.get_row(1), [7, 7, 7]);
+/// assert_eq!(m.get_row(0), &[1, 2, 3][..]);
+/// assert_eq!(format!("{:?}", m.get_row(0)), "[1, 2, 3]");
+/// ```
+pub struct IntermittentSlice<'a, const A: usize, const S: usize, T> {
+    start: *const T,
+    _borrow: PhantomData<&'a T>,
+}
+
+// SAFETY: an IntermittentSlice is a shared borrow of its elements.
+unsafe impl<const A: usize, const S: usize, T: Sync> Send for IntermittentSlice<'_, A, S, T> {}
+unsafe impl<const A: usize, const S: usize, T: Sync> Sync for IntermittentSlice<'_, A, S, T> {}
+
+impl<'a, const A: usize, const S: usize, T> IntermittentSlice<'a, A, S, T> {
+    /// Borrows the lane of buffer that starts at offset.
+    ///
+    /// The pointer to the first element is derived from the whole buffer, so the elements after
+    /// it may be reached through it.
+    pub(crate) fn new(buffer: &'a [T], offset: usize) -> Self {
+        assert!(
+            S == 0 || offset + (S - 1) * A < buffer.len(),
+            "lane at {offset} out of bounds of the buffer"
+        );
+        Self {
+            start: buffer.as_ptr().wrapping_add(offset),
+            _borrow: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the slice.
+    pub fn len(&self) -> usize {
+        S
+    }
+
+    /// Returns true if the slice has no elements.
+    pub fn is_empty(&self) -> bool {
+        S == 0
+    }
+
+    /// Returns the element i of the slice or None if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let row = m.get_row(1);
+    /// assert_eq!((row.len(), row.get(2), row.get(3)), (3, Some(&6), None));
+    /// assert_eq!((row.first(), row.last()), (Some(&4), Some(&6)));
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        // SAFETY: new checked that the S elements A apart lie in the borrowed buffer.
+        (index < S).then(|| unsafe { &*self.start.add(index * A) })
+    }
+
+    /// Returns the first element or None if the slice is empty.
+    pub fn first(&self) -> Option<&'a T> {
+        self.get(0)
+    }
+
+    /// Returns the last element or None if the slice is empty.
+    pub fn last(&self) -> Option<&'a T> {
+        S.checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    /// Returns an iterator over the elements that leaves the slice usable, e.g. for algorithms
+    /// that pass over a row of a [`ColumnPrio`] matrix more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let row = m.get_row(1);
+    /// let max = row.iter().copied().max().unwrap();
+    /// let scaled: Vec<_> = (&row).into_iter().map(|el| el * 10 / max).collect();
+    /// assert_eq!(scaled, vec![6, 8, 10]);
+    /// ```
+    pub fn iter(&self) -> IntermittentSliceIntoItterator<'_, A, S, T> {
+        IntermittentSlice {
+            start: self.start,
+            _borrow: PhantomData,
+        }
+        .into_iter()
+    }
+
+    /// Copies the elements into a new contiguous Vec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(m.get_row(1).to_vec(), vec![4, 5, 6]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> alloc::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Copies the elements into dst, e.g. to hand a row of a [`ColumnPrio`] matrix to an API
+    /// that takes slices.
+    ///
+    /// # Panics
+    ///
+    /// If dst does not hold exactly S elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let mut row = [0; 3];
+    /// m.get_row(0).copy_to_slice(&mut row);
+    /// assert_eq!(row, [1, 2, 3]);
+    /// ```
+    pub fn copy_to_slice(&self, dst: &mut [T])
+    where
+        T: Copy,
+    {
+        assert_eq!(
+            dst.len(),
+            S,
+            "destination and source slices have different lengths"
+        );
+        for (dst, &el) in dst.iter_mut().zip(self) {
+            *dst = el;
+        }
+    }
+
+    /// Clones the elements into dst, reusing the resources of the elements of dst.
+    ///
+    /// # Panics
+    ///
+    /// If dst does not hold exactly S elements.
+    pub fn clone_into(&self, dst: &mut [T])
+    where
+        T: Clone,
+    {
+        assert_eq!(
+            dst.len(),
+            S,
+            "destination and source slices have different lengths"
+        );
+        for (dst, el) in dst.iter_mut().zip(self) {
+            dst.clone_from(el);
+        }
+    }
+}
+
+impl<'s, 'a, const A: usize, const S: usize, T> IntoIterator
+    for &'s IntermittentSlice<'a, A, S, T>
+{
+    type Item = &'s T;
+
+    type IntoIter = IntermittentSliceIntoItterator<'s, A, S, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, const A: usize, const S: usize, T> Index<usize> for IntermittentSlice<'a, A, S, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(el) => el,
+            None => panic!("Index {index} out of bounds {}", S),
+        }
+    }
+}
+
+/// The IntermittentSliceMut struct represents a mutable matrix row or col in all [`ColumnPrio`] / [`RowPrio`] matrices.
+///
+/// Since the underlying data is not continuous all slice operations are unavailable to the IntermittentSliceMut
+/// struct. It can however be indexed, iterated over and compared with slices and arrays.
+/// Const A represents the amount of slices in the Matrix, const S represents the length of each
+/// slice.
+pub struct IntermittentSliceMut<'a, const A: usize, const S: usize, T> {
+    start: *mut T,
+    _borrow: PhantomData<&'a mut T>,
+}
+
+// SAFETY: an IntermittentSliceMut is an exclusive borrow of its elements.
+unsafe impl<const A: usize, const S: usize, T: Send> Send for IntermittentSliceMut<'_, A, S, T> {}
+unsafe impl<const A: usize, const S: usize, T: Sync> Sync for IntermittentSliceMut<'_, A, S, T> {}
+
+impl<'a, const A: usize, const S: usize, T> IntermittentSliceMut<'a, A, S, T> {
+    /// Borrows the lane of buffer that starts at offset mutably.
+    pub(crate) fn new(buffer: &'a mut [T], offset: usize) -> Self {
+        assert!(
+            S == 0 || offset + (S - 1) * A < buffer.len(),
+            "lane at {offset} out of bounds of the buffer"
+        );
+        // SAFETY: the lane lies in the buffer, which is borrowed mutably for 'a.
+        unsafe { Self::from_raw(buffer.as_mut_ptr().wrapping_add(offset)) }
+    }
+
+    /// Borrows the lane of S elements A apart that begins at start.
+    ///
+    /// # Safety
+    ///
+    /// All S elements have to be valid and exclusively borrowed for 'a, and start has to carry
+    /// the provenance of all of them, e.g. by being derived from a pointer to the whole buffer.
+    pub(crate) unsafe fn from_raw(start: *mut T) -> Self {
+        Self {
+            start,
+            _borrow: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the slice.
+    pub fn len(&self) -> usize {
+        S
+    }
+
+    /// Returns true if the slice has no elements.
+    pub fn is_empty(&self) -> bool {
+        S == 0
+    }
+
+    /// Returns the element i of the slice or None if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        (index < S).then(|| &self[index])
+    }
+
+    /// Returns the element i of the slice mutably or None if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let mut row = m.get_mut_row(0);
+    /// if let Some(el) = row.get_mut(1) {
+    ///     *el = 0;
+    /// }
+    /// assert!(row.get_mut(3).is_none());
+    /// *row.last_mut().unwrap() += 10;
+    /// assert_eq!(m.get_row(0), [1, 0, 13]);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= S {
+            return None;
+        }
+        Some(&mut self[index])
+    }
+
+    /// Returns the first element or None if the slice is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns the first element mutably or None if the slice is empty.
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns the last element or None if the slice is empty.
+    pub fn last(&self) -> Option<&T> {
+        S.checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    /// Returns the last element mutably or None if the slice is empty.
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        S.checked_sub(1).and_then(|index| self.get_mut(index))
+    }
+
+    /// swap allows for a memswap in non continuous memory this is not possible in safe rust since
+    /// you need to have two mutable references.
+    ///
+    /// # Panics
+    ///
+    /// If a or b is out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        for index in [a, b] {
+            if index >= S {
+                panic!("Index {index} out of bounds {}", S);
+            }
+        }
+        // SAFETY: a and b are in bounds, ptr::swap allows both to be the same element.
+        unsafe {
+            core::ptr::swap(self.start.add(a * A), self.start.add(b * A));
+        }
+    }
+
+    /// Returns an iterator over the elements that leaves the slice usable.
+    pub fn iter(&self) -> IntermittentSliceIntoItterator<'_, A, S, T> {
+        self.as_slice().into_iter()
+    }
+
+    /// Sets every element to value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// m.get_mut_row(1).fill(0);
+    /// assert_eq!(m.get_row(1), [0, 0, 0]);
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for el in self {
+            el.clone_from(&value);
+        }
+    }
+
+    /// Copies the elements of src into the slice.
+    ///
+    /// # Panics
+    ///
+    /// If src does not hold exactly S elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// m.get_mut_row(0).copy_from_slice(&[7, 8, 9]);
+    /// assert_eq!(m.get_row(0), [7, 8, 9]);
+    /// ```
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        assert_eq!(
+            src.len(),
+            S,
+            "destination and source slices have different lengths"
+        );
+        for (el, &src) in self.iter_mut().zip(src) {
+            *el = src;
+        }
+    }
+
+    /// Copies the elements into a new contiguous Vec, see [`IntermittentSlice::to_vec`].
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> alloc::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        self.as_slice().to_vec()
+    }
+
+    /// Copies the elements into dst, see [`IntermittentSlice::copy_to_slice`].
+    ///
+    /// # Panics
+    ///
+    /// If dst does not hold exactly S elements.
+    pub fn copy_to_slice(&self, dst: &mut [T])
+    where
+        T: Copy,
+    {
+        self.as_slice().copy_to_slice(dst)
+    }
+
+    /// Clones the elements into dst, see [`IntermittentSlice::clone_into`].
+    ///
+    /// # Panics
+    ///
+    /// If dst does not hold exactly S elements.
+    pub fn clone_into(&self, dst: &mut [T])
+    where
+        T: Clone,
+    {
+        self.as_slice().clone_into(dst)
+    }
+
+    fn as_slice(&self) -> IntermittentSlice<'_, A, S, T> {
+        IntermittentSlice {
+            start: self.start,
+            _borrow: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the elements mutably that leaves the slice usable, e.g. to
+    /// update a row of a [`ColumnPrio`] matrix in several passes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let mut row = m.get_mut_row(0);
+    /// let sum: i32 = row.iter().sum();
+    /// for el in &mut row {
+    ///     *el *= sum;
+    /// }
+    /// row.iter_mut().for_each(|el| *el -= 1);
+    /// assert_eq!(m.get_row(0).iter().copied().collect::<Vec<_>>(), vec![5, 11, 17]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IntermittentSliceMutIntoItterator<'_, A, S, T> {
+        // SAFETY: the elements stay borrowed through self while the reborrow lives.
+        unsafe { IntermittentSliceMut::from_raw(self.start) }.into_iter()
+    }
+}
+
+impl<'a, const A: usize, const S: usize, T> Index<usize> for IntermittentSliceMut<'a, A, S, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        if index >= S {
+            panic!("Index {index} out of bounds {}", S);
+        }
+        // SAFETY: index is in bounds of the borrowed lane.
+        unsafe { &*self.start.add(index * A) }
+    }
+}
+
 impl<'a, const A: usize, const S: usize, T> IndexMut<usize> for IntermittentSliceMut<'a, A, S, T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         if index >= S {
             panic!("Index {index} out of bounds {}", S);
         }
-        unsafe { &mut *((self.start as *mut T).add(index * A)) }
+        // SAFETY: index is in bounds of the borrowed lane.
+        unsafe { &mut *self.start.add(index * A) }
+    }
+}
+
+impl<'s, 'a, const A: usize, const S: usize, T> IntoIterator
+    for &'s IntermittentSliceMut<'a, A, S, T>
+{
+    type Item = &'s T;
+
+    type IntoIter = IntermittentSliceIntoItterator<'s, A, S, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'s, 'a, const A: usize, const S: usize, T> IntoIterator
+    for &'s mut IntermittentSliceMut<'a, A, S, T>
+{
+    type Item = &'s mut T;
+
+    type IntoIter = IntermittentSliceMutIntoItterator<'s, A, S, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
 
+macro_rules! intermittent_slice_eq {
+    ($($slice:ident),*) => {
+        $(
+            impl<const A: usize, const S: usize, T: fmt::Debug> fmt::Debug for $slice<'_, A, S, T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_list().entries(self.iter()).finish()
+                }
+            }
+
+            impl<const A: usize, const S: usize, T, U> PartialEq<[U]> for $slice<'_, A, S, T>
+            where
+                T: PartialEq<U>,
+            {
+                fn eq(&self, other: &[U]) -> bool {
+                    other.len() == S && self.iter().zip(other).all(|(a, b)| a == b)
+                }
+            }
+
+            impl<const A: usize, const S: usize, T, U> PartialEq<&[U]> for $slice<'_, A, S, T>
+            where
+                T: PartialEq<U>,
+            {
+                fn eq(&self, other: &&[U]) -> bool {
+                    *self == **other
+                }
+            }
+
+            impl<const A: usize, const S: usize, const N: usize, T, U> PartialEq<[U; N]>
+                for $slice<'_, A, S, T>
+            where
+                T: PartialEq<U>,
+            {
+                fn eq(&self, other: &[U; N]) -> bool {
+                    *self == other[..]
+                }
+            }
+        )*
+    };
+}
+
+intermittent_slice_eq!(IntermittentSlice, IntermittentSliceMut);
+
 #[doc(hidden)]
 pub struct IntermittentSliceMutIntoItterator<'a, const R: usize, const S: usize, T> {
     row: IntermittentSliceMut<'a, R, S, T>,
     index: usize,
+    end: usize,
 }
 
 impl<'a, const A: usize, const S: usize, T> IntoIterator for IntermittentSliceMut<'a, A, S, T> {
@@ -404,6 +1767,7 @@ impl<'a, const A: usize, const S: usize, T> IntoIterator for IntermittentSliceMu
         IntermittentSliceMutIntoItterator {
             row: self,
             index: 0,
+            end: S,
         }
     }
 }
@@ -414,21 +1778,49 @@ impl<'a, const A: usize, const S: usize, T> Iterator
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= S {
+        if self.index >= self.end {
             return None;
         }
-        unsafe {
-            let next = &mut *((self.row.start as *mut T).add(self.index * A));
-            self.index += 1;
-            Some(next)
+        // SAFETY: index is in bounds of the lane and every element is handed out once.
+        let next = unsafe { &mut *self.row.start.add(self.index * A) };
+        self.index += 1;
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a, const A: usize, const S: usize, T> DoubleEndedIterator
+    for IntermittentSliceMutIntoItterator<'a, A, S, T>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
         }
+        self.end -= 1;
+        // SAFETY: end stays within the slice and every element is handed out once.
+        unsafe { Some(&mut *self.row.start.add(self.end * A)) }
     }
 }
 
+impl<const A: usize, const S: usize, T> ExactSizeIterator
+    for IntermittentSliceMutIntoItterator<'_, A, S, T>
+{
+}
+
+impl<const A: usize, const S: usize, T> FusedIterator
+    for IntermittentSliceMutIntoItterator<'_, A, S, T>
+{
+}
+
 #[doc(hidden)]
 pub struct IntermittentSliceIntoItterator<'a, const A: usize, const S: usize, T> {
     row: IntermittentSlice<'a, A, S, T>,
     index: usize,
+    end: usize,
 }
 
 impl<'a, const A: usize, const S: usize, T> IntoIterator for IntermittentSlice<'a, A, S, T> {
@@ -440,6 +1832,7 @@ impl<'a, const A: usize, const S: usize, T> IntoIterator for IntermittentSlice<'
         IntermittentSliceIntoItterator {
             row: self,
             index: 0,
+            end: S,
         }
     }
 }
@@ -450,21 +1843,49 @@ impl<'a, const A: usize, const S: usize, T> Iterator
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= S {
+        if self.index >= self.end {
             return None;
         }
-        unsafe {
-            let next = &*((self.row.start as *const T).add(self.index * A));
-            self.index += 1;
-            Some(next)
+        // SAFETY: index is in bounds of the lane.
+        let next = unsafe { &*self.row.start.add(self.index * A) };
+        self.index += 1;
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a, const A: usize, const S: usize, T> DoubleEndedIterator
+    for IntermittentSliceIntoItterator<'a, A, S, T>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
         }
+        self.end -= 1;
+        // SAFETY: end stays within the slice.
+        unsafe { Some(&*self.row.start.add(self.end * A)) }
     }
 }
 
+impl<const A: usize, const S: usize, T> ExactSizeIterator
+    for IntermittentSliceIntoItterator<'_, A, S, T>
+{
+}
+
+impl<const A: usize, const S: usize, T> FusedIterator
+    for IntermittentSliceIntoItterator<'_, A, S, T>
+{
+}
+
 /// IterIntermittentSlice represents an iterator over all rows / cols in a [`ColumnPrio`] / [`RowPrio`]
 /// Matrix.
 pub struct IterIntermittentSlices<'a, const R: usize, const S: usize, T> {
     slice_index: usize,
+    slice_end: usize,
     matrix_buffer: &'a [T],
 }
 
@@ -472,44 +1893,123 @@ impl<'a, const A: usize, const S: usize, T> Iterator for IterIntermittentSlices<
     type Item = IntermittentSlice<'a, A, S, T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.slice_index >= A {
+        if self.slice_index >= self.slice_end {
             return None;
         };
-        let r = IntermittentSlice {
-            start: &self.matrix_buffer[self.slice_index],
-        };
+        let r = IntermittentSlice::new(self.matrix_buffer, self.slice_index);
         self.slice_index += 1;
         Some(r)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice_end - self.slice_index;
+        (len, Some(len))
+    }
+}
+
+impl<const A: usize, const S: usize, T> DoubleEndedIterator
+    for IterIntermittentSlices<'_, A, S, T>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice_index >= self.slice_end {
+            return None;
+        };
+        self.slice_end -= 1;
+        Some(IntermittentSlice::new(self.matrix_buffer, self.slice_end))
+    }
 }
 
+impl<const A: usize, const S: usize, T> ExactSizeIterator for IterIntermittentSlices<'_, A, S, T> {}
+
+impl<const A: usize, const S: usize, T> FusedIterator for IterIntermittentSlices<'_, A, S, T> {}
+
 /// IterIntermittentSliceMut represents an mutable iterator over all rows / cols in a [`ColumnPrio`] / [`RowPrio`]
 /// Matrix.
 pub struct IterMutIntermittentSlices<'a, const A: usize, const S: usize, T> {
+    start: *mut T,
     slice_index: usize,
-    matrix_buffer: &'a mut [T],
+    slice_end: usize,
+    _borrow: PhantomData<&'a mut [T]>,
 }
 
-impl<'a, const A: usize, const S: usize, T> Iterator for IterMutIntermittentSlices<'a, A, S, T>
-where
-    Self: 'a,
+// SAFETY: the iterator is an exclusive borrow of the matrix buffer.
+unsafe impl<const A: usize, const S: usize, T: Send> Send
+    for IterMutIntermittentSlices<'_, A, S, T>
+{
+}
+unsafe impl<const A: usize, const S: usize, T: Sync> Sync
+    for IterMutIntermittentSlices<'_, A, S, T>
 {
+}
+
+impl<'a, const A: usize, const S: usize, T> IterMutIntermittentSlices<'a, A, S, T> {
+    /// Iterates over the A lanes of length S that interleave in buffer.
+    pub(crate) fn new(buffer: &'a mut [T]) -> Self {
+        assert_eq!(
+            buffer.len(),
+            A * S,
+            "buffer does not hold {A} lanes of length {S}"
+        );
+        Self {
+            start: buffer.as_mut_ptr(),
+            slice_index: 0,
+            slice_end: A,
+            _borrow: PhantomData,
+        }
+    }
+
+    /// Hands out the lane that starts at index.
+    ///
+    /// # Safety
+    ///
+    /// index has to be below A and each lane may only be handed out once.
+    unsafe fn lane(&self, index: usize) -> IntermittentSliceMut<'a, A, S, T> {
+        // SAFETY: lanes never share an element, and the pointer to each lane is derived from the
+        // pointer to the whole buffer, which stays borrowed for 'a. Lanes of length 0 start past
+        // the empty buffer, so the offset must not assume it stays in bounds.
+        unsafe { IntermittentSliceMut::from_raw(self.start.wrapping_add(index)) }
+    }
+}
+
+impl<'a, const A: usize, const S: usize, T> Iterator for IterMutIntermittentSlices<'a, A, S, T> {
     type Item = IntermittentSliceMut<'a, A, S, T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.slice_index >= A {
+        if self.slice_index >= self.slice_end {
             return None;
         };
-        // SAFETY:
-        // The IntermittentSliceMut point to the same array in memory but never touch the same elements.
-        let row = IntermittentSliceMut {
-            start: unsafe { std::mem::transmute(&mut self.matrix_buffer[self.slice_index]) },
-        };
+        // SAFETY: slice_index is below slice_end <= A and moves past the lane afterwards.
+        let row = unsafe { self.lane(self.slice_index) };
         self.slice_index += 1;
         Some(row)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice_end - self.slice_index;
+        (len, Some(len))
+    }
+}
+
+impl<const A: usize, const S: usize, T> DoubleEndedIterator
+    for IterMutIntermittentSlices<'_, A, S, T>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.slice_index >= self.slice_end {
+            return None;
+        };
+        self.slice_end -= 1;
+        // SAFETY: slice_end is at least slice_index and was moved past the lane.
+        Some(unsafe { self.lane(self.slice_end) })
+    }
+}
+
+impl<const A: usize, const S: usize, T> ExactSizeIterator
+    for IterMutIntermittentSlices<'_, A, S, T>
+{
 }
 
+impl<const A: usize, const S: usize, T> FusedIterator for IterMutIntermittentSlices<'_, A, S, T> {}
+
 /// IterRows represents an iterator over all rows of a Matrix.
 pub struct IterSlices<'a, const R: usize, const S: usize, T> {
     matrix_buffer: &'a [T],
@@ -526,8 +2026,28 @@ impl<'a, const R: usize, const S: usize, T> Iterator for IterSlices<'a, R, S, T>
         self.matrix_buffer = rest;
         Some(r)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.matrix_buffer.len().checked_div(S).unwrap_or(0);
+        (len, Some(len))
+    }
 }
 
+impl<const R: usize, const S: usize, T> DoubleEndedIterator for IterSlices<'_, R, S, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.matrix_buffer.is_empty() {
+            return None;
+        };
+        let (rest, r) = self.matrix_buffer.split_at(self.matrix_buffer.len() - S);
+        self.matrix_buffer = rest;
+        Some(r)
+    }
+}
+
+impl<const R: usize, const S: usize, T> ExactSizeIterator for IterSlices<'_, R, S, T> {}
+
+impl<const R: usize, const S: usize, T> FusedIterator for IterSlices<'_, R, S, T> {}
+
 /// IterRows represents an iterator over all rows of a Matrix.
 pub struct IterSlicesMut<'a, const R: usize, const S: usize, T> {
     matrix_buffer: &'a mut [T],
@@ -537,17 +2057,33 @@ impl<'a, const R: usize, const S: usize, T> Iterator for IterSlicesMut<'a, R, S,
     type Item = &'a mut [T];
 
     fn next(&mut self) -> Option<Self::Item> {
-        unsafe {
-            if self.matrix_buffer.is_empty() {
-                return None;
-            };
-            // SAFETY:
-            // I think this should be okay since the lifetime is tied to the original
-            // matrix_buffer.
-            let (r, rest): (&mut [T], &mut [T]) =
-                std::mem::transmute(self.matrix_buffer.split_at_mut(S));
-            self.matrix_buffer = rest;
-            Some(std::mem::transmute(r))
-        }
+        let buffer = core::mem::take(&mut self.matrix_buffer);
+        if buffer.is_empty() {
+            return None;
+        };
+        let (r, rest) = buffer.split_at_mut(S);
+        self.matrix_buffer = rest;
+        Some(r)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.matrix_buffer.len().checked_div(S).unwrap_or(0);
+        (len, Some(len))
     }
 }
+
+impl<const R: usize, const S: usize, T> DoubleEndedIterator for IterSlicesMut<'_, R, S, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let buffer = core::mem::take(&mut self.matrix_buffer);
+        if buffer.is_empty() {
+            return None;
+        };
+        let (rest, r) = buffer.split_at_mut(buffer.len() - S);
+        self.matrix_buffer = rest;
+        Some(r)
+    }
+}
+
+impl<const R: usize, const S: usize, T> ExactSizeIterator for IterSlicesMut<'_, R, S, T> {}
+
+impl<const R: usize, const S: usize, T> FusedIterator for IterSlicesMut<'_, R, S, T> {}
@@ -0,0 +1,90 @@
+/// Constructs a [`Stacktrix`](crate::Stacktrix) from its rows, inferring S, R and C.
+///
+/// The memory priority is given first and has to be written as `ColumnPrio` or `RowPrio`, the
+/// elements are written in logical order and stored in the order of the memory priority. All
+/// rows need the same length. The macro can be used in const contexts.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ stacktrix, ColumnPrio, ColumnPrioMatrix, RowPrio, Stacktrix };
+/// const M: Stacktrix<6, 2, 3, ColumnPrio, u8> = stacktrix![ColumnPrio; [1, 2, 3], [4, 5, 6]];
+/// assert_eq!(M.get_column(0), &[1, 4]);
+/// assert_eq!(M, stacktrix![RowPrio; [1, 2, 3], [4, 5, 6]]);
+/// ```
+#[macro_export]
+macro_rules! stacktrix {
+    (ColumnPrio; $([$($x:expr),* $(,)?]),+ $(,)?) => {
+        $crate::Stacktrix::<
+            { $crate::stacktrix!(@count $([$($x),*])+) * $crate::stacktrix!(@cols $([$($x),*])+) },
+            { $crate::stacktrix!(@count $([$($x),*])+) },
+            { $crate::stacktrix!(@cols $([$($x),*])+) },
+            $crate::ColumnPrio,
+            _,
+        >::with_values($crate::stacktrix::rows_to_columns::<
+            { $crate::stacktrix!(@count $([$($x),*])+) * $crate::stacktrix!(@cols $([$($x),*])+) },
+            { $crate::stacktrix!(@count $([$($x),*])+) },
+            { $crate::stacktrix!(@cols $([$($x),*])+) },
+            _,
+        >([$($($x),*),+]))
+    };
+    (RowPrio; $([$($x:expr),* $(,)?]),+ $(,)?) => {
+        $crate::Stacktrix::<
+            { $crate::stacktrix!(@count $([$($x),*])+) * $crate::stacktrix!(@cols $([$($x),*])+) },
+            { $crate::stacktrix!(@count $([$($x),*])+) },
+            { $crate::stacktrix!(@cols $([$($x),*])+) },
+            $crate::RowPrio,
+            _,
+        >::with_values([$($($x),*),+])
+    };
+    (@count $($t:tt)*) => {
+        <[()]>::len(&[$($crate::stacktrix!(@unit $t)),*])
+    };
+    (@cols [$($x:expr),*] $($rest:tt)*) => {
+        $crate::stacktrix!(@count $($x)*)
+    };
+    (@unit $t:tt) => {
+        ()
+    };
+}
+
+/// Names the type of a [`Stacktrix`](crate::Stacktrix) with R rows and C columns, S is computed
+/// as R * C.
+///
+/// R and C have to be known, i.e. literals or constants, generic parameters can not be used in
+/// the computation of S on stable.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ stacktrix_alias, ColumnPrio, Stacktrix };
+/// type State = stacktrix_alias![4, 4, ColumnPrio, u8];
+/// let state = State::zeros();
+/// let same: Stacktrix<16, 4, 4, ColumnPrio, u8> = state;
+/// ```
+#[macro_export]
+macro_rules! stacktrix_alias {
+    ($r:expr, $c:expr, $p:ty, $t:ty $(,)?) => {
+        $crate::Stacktrix<{ $r * $c }, { $r }, { $c }, $p, $t>
+    };
+}
+
+/// Selects the rows and the columns of a slice for [`View::slice`](crate::View::slice), written
+/// as `s![rows, cols]`.
+///
+/// Both accept any range, e.g. `..`, `a..b` or `a..=b`, or a single index that selects one row or
+/// column.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ s, Stacktrix, RowPrio, View };
+/// let m = Stacktrix::<6, 2, 3, RowPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+/// assert_eq!(m.slice(s![.., 1..]).iter().copied().collect::<Vec<_>>(), vec![2, 3, 5, 6]);
+/// ```
+#[macro_export]
+macro_rules! s {
+    ($rows:expr, $cols:expr $(,)?) => {
+        $crate::SliceInfo::new($rows, $cols)
+    };
+}
@@ -0,0 +1,178 @@
+use crate::{storage::StorageMut, MemoryPriority};
+
+/// The comparators of the size optimal sorting networks for 2 to 8 elements.
+const OPTIMAL: [&[(usize, usize)]; 7] = [
+    &[(0, 1)],
+    &[(0, 2), (0, 1), (1, 2)],
+    &[(0, 1), (2, 3), (0, 2), (1, 3), (1, 2)],
+    &[
+        (0, 3),
+        (1, 4),
+        (0, 2),
+        (1, 3),
+        (0, 1),
+        (2, 4),
+        (1, 2),
+        (3, 4),
+        (2, 3),
+    ],
+    &[
+        (0, 5),
+        (1, 3),
+        (2, 4),
+        (1, 2),
+        (3, 4),
+        (0, 3),
+        (2, 5),
+        (0, 1),
+        (2, 3),
+        (4, 5),
+        (1, 2),
+        (3, 4),
+    ],
+    &[
+        (0, 6),
+        (2, 3),
+        (4, 5),
+        (0, 2),
+        (1, 4),
+        (3, 6),
+        (0, 1),
+        (2, 5),
+        (3, 4),
+        (1, 2),
+        (4, 6),
+        (2, 3),
+        (4, 5),
+        (1, 2),
+        (3, 4),
+        (5, 6),
+    ],
+    &[
+        (0, 2),
+        (1, 3),
+        (4, 6),
+        (5, 7),
+        (0, 4),
+        (1, 5),
+        (2, 6),
+        (3, 7),
+        (0, 1),
+        (2, 3),
+        (4, 5),
+        (6, 7),
+        (2, 4),
+        (3, 5),
+        (1, 4),
+        (3, 6),
+        (1, 2),
+        (3, 4),
+        (5, 6),
+    ],
+];
+
+/// Returns the comparators (a, b) with a < b of a sorting network for n elements: the size
+/// optimal network for up to 8 elements, Batcher's odd even merge sort for more.
+pub(crate) fn comparators(n: usize) -> impl Iterator<Item = (usize, usize)> {
+    let optimal = match n {
+        2..=8 => OPTIMAL[n - 2],
+        _ => &[],
+    };
+    let batcher = (n > 8)
+        .then(|| odd_even_merge_sort(n))
+        .into_iter()
+        .flatten();
+    optimal.iter().copied().chain(batcher)
+}
+
+fn odd_even_merge_sort(n: usize) -> impl Iterator<Item = (usize, usize)> {
+    let passes = core::iter::successors(Some(1usize), move |&p| (p * 2 < n).then_some(p * 2));
+    passes.flat_map(move |p| {
+        let steps = core::iter::successors(Some(p), |&k| (k > 1).then_some(k / 2));
+        steps.flat_map(move |k| {
+            (k % p..n.saturating_sub(k))
+                .step_by(2 * k)
+                .flat_map(move |j| (0..k.min(n - j - k)).map(move |i| (i + j, i + j + k)))
+                .filter(move |&(a, b)| a / (2 * p) == b / (2 * p))
+        })
+    })
+}
+
+/// Orders buffer\[a\] and buffer\[b\] ascending by selecting instead of branching.
+pub(crate) fn compare_exchange<T: PartialOrd + Copy>(buffer: &mut [T], a: usize, b: usize) {
+    let (x, y) = (buffer[a], buffer[b]);
+    let swap = y < x;
+    buffer[a] = if swap { y } else { x };
+    buffer[b] = if swap { x } else { y };
+}
+
+/// SortingNetwork sorts every column or row of a matrix with a fixed sequence of compare and
+/// exchange steps that does not depend on the data, e.g. for median filters over small lanes.
+///
+/// Elements that are not comparable, like NaN, are never moved by a single step, the result is
+/// unspecified if a lane holds such elements.
+pub trait SortingNetwork<const R: usize, const C: usize, T> {
+    /// Sorts every column ascending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, SortingNetwork, Export };
+    /// let mut m = Stacktrix::<6, 3, 2, RowPrio, i32>::from_rows([[3, 0], [1, 5], [2, -5]]);
+    /// m.sort_cols_network();
+    /// assert_eq!(m.to_rows(), [[1, -5], [2, 0], [3, 5]]);
+    /// ```
+    fn sort_cols_network(&mut self)
+    where
+        T: PartialOrd + Copy;
+    /// Sorts every row ascending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, SortingNetwork, Export };
+    /// let mut m = Stacktrix::<6, 2, 3, ColumnPrio, f32>::from_rows([[3.0, 1.0, 2.0], [0.5, 0.0, -1.0]]);
+    /// m.sort_rows_network();
+    /// assert_eq!(m.to_rows(), [[1.0, 2.0, 3.0], [-1.0, 0.0, 0.5]]);
+    /// ```
+    fn sort_rows_network(&mut self)
+    where
+        T: PartialOrd + Copy;
+}
+
+impl<M, const R: usize, const C: usize, T> SortingNetwork<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+{
+    fn sort_cols_network(&mut self)
+    where
+        T: PartialOrd + Copy,
+    {
+        let buffer = self.buffer_mut();
+        for (a, b) in comparators(R) {
+            for col in 0..C {
+                compare_exchange(
+                    buffer,
+                    M::Priority::offset((a, col), R, C),
+                    M::Priority::offset((b, col), R, C),
+                );
+            }
+        }
+    }
+
+    fn sort_rows_network(&mut self)
+    where
+        T: PartialOrd + Copy,
+    {
+        let buffer = self.buffer_mut();
+        for (a, b) in comparators(C) {
+            for row in 0..R {
+                compare_exchange(
+                    buffer,
+                    M::Priority::offset((row, a), R, C),
+                    M::Priority::offset((row, b), R, C),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Reading and writing NumPy `.npy` files, enabled by the `npy` feature.
+//!
+//! A matrix is written as a two dimensional array in its memory order, `fortran_order` is set
+//! for [`ColumnPrio`](crate::ColumnPrio) matrices. Arrays in the other order are reordered while
+//! reading, so `np.save` and `np.load` exchange matrices of either memory priority.
+use crate::{
+    storage::{location, same_layout, Storage, StorageMut},
+    ColumnPrio, MemoryPriority, Stacktrix,
+};
+use std::io::{self, Read, Write};
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+/// The longest header that is read, the same limit NumPy applies.
+const MAX_HEADER_LEN: usize = 10_000;
+
+/// NpyElement is an element type with a NumPy dtype.
+pub trait NpyElement: Copy + Default {
+    /// The dtype without the byte order, e.g. `f8`.
+    const DTYPE: &'static str;
+    /// Writes the element little endian.
+    fn write_le<W: Write>(self, w: &mut W) -> io::Result<()>;
+    /// Reads the element from its bytes, which are little endian if little is true.
+    fn from_bytes(bytes: &[u8], little: bool) -> Self;
+}
+
+macro_rules! npy_element {
+    ($($t:ty => $dtype:literal),*) => {
+        $(
+            impl NpyElement for $t {
+                const DTYPE: &'static str = $dtype;
+
+                fn write_le<W: Write>(self, w: &mut W) -> io::Result<()> {
+                    w.write_all(&self.to_le_bytes())
+                }
+
+                fn from_bytes(bytes: &[u8], little: bool) -> Self {
+                    let bytes = bytes.try_into().expect("one element worth of bytes");
+                    if little {
+                        <$t>::from_le_bytes(bytes)
+                    } else {
+                        <$t>::from_be_bytes(bytes)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+npy_element!(
+    u8 => "u1", u16 => "u2", u32 => "u4", u64 => "u8",
+    i8 => "i1", i16 => "i2", i32 => "i4", i64 => "i8",
+    f32 => "f4", f64 => "f8"
+);
+
+impl NpyElement for bool {
+    const DTYPE: &'static str = "b1";
+
+    fn write_le<W: Write>(self, w: &mut W) -> io::Result<()> {
+        w.write_all(&[self as u8])
+    }
+
+    fn from_bytes(bytes: &[u8], _little: bool) -> Self {
+        bytes[0] != 0
+    }
+}
+
+/// Npy reads and writes matrices as NumPy `.npy` files.
+pub trait Npy<const R: usize, const C: usize, T> {
+    /// Writes the matrix as a `.npy` file of version 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Npy };
+    /// let m = Stacktrix::<4, 2, 2, ColumnPrio, u8>::from_rows([[1, 2], [3, 4]]);
+    /// let mut file = Vec::new();
+    /// m.write_npy(&mut file).unwrap();
+    /// assert_eq!(file.len(), 128 + 4);
+    /// assert!(file.starts_with(b"\x93NUMPY\x01\x00"));
+    /// assert_eq!(&file[128..], &[1, 3, 2, 4]);
+    /// ```
+    fn write_npy<W: Write>(&self, w: &mut W) -> io::Result<()>;
+    /// Overwrites the matrix with a `.npy` file of version 1, 2 or 3 that holds an R x C
+    /// array of T, in either order and byte order.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] if the file is malformed or holds another dtype
+    /// or shape, the message names both shapes. The matrix may be partially overwritten if the
+    /// data ends early.
+    fn read_npy_into<Rd: Read>(&mut self, r: &mut Rd) -> io::Result<()>
+    where
+        Self: StorageMut<R, C, T>;
+}
+
+impl<M, const R: usize, const C: usize, T> Npy<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+    T: NpyElement,
+{
+    fn write_npy<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let byte_order = if core::mem::size_of::<T>() == 1 {
+            '|'
+        } else {
+            '<'
+        };
+        let fortran_order = if same_layout::<M::Priority, ColumnPrio>(R, C) {
+            "True"
+        } else {
+            "False"
+        };
+        let mut header = format!(
+            "{{'descr': '{byte_order}{}', 'fortran_order': {fortran_order}, 'shape': ({R}, {C}), }}",
+            T::DTYPE
+        );
+        // The data starts at a multiple of 64 bytes, the header ends with a newline.
+        let unpadded = MAGIC.len() + 4 + header.len() + 1;
+        header.extend(core::iter::repeat_n(
+            ' ',
+            unpadded.next_multiple_of(64) - unpadded,
+        ));
+        header.push('\n');
+        w.write_all(MAGIC)?;
+        w.write_all(&[1, 0])?;
+        w.write_all(&(header.len() as u16).to_le_bytes())?;
+        w.write_all(header.as_bytes())?;
+        for &el in self.buffer() {
+            el.write_le(w)?;
+        }
+        Ok(())
+    }
+
+    fn read_npy_into<Rd: Read>(&mut self, r: &mut Rd) -> io::Result<()>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        let header = read_header(r)?;
+        let descr = quoted(field(&header, "descr")?)?;
+        let (little, dtype) = match descr.split_at_checked(1) {
+            Some(("<" | "|", dtype)) => (true, dtype),
+            Some((">", dtype)) => (false, dtype),
+            Some(("=", dtype)) => (cfg!(target_endian = "little"), dtype),
+            _ => return Err(invalid(format!("unknown byte order in descr '{descr}'"))),
+        };
+        if dtype != T::DTYPE {
+            return Err(invalid(format!(
+                "expected dtype {} but the file holds {dtype}",
+                T::DTYPE
+            )));
+        }
+        let fortran_order = match field(&header, "fortran_order")? {
+            order if order.starts_with("True") => true,
+            order if order.starts_with("False") => false,
+            _ => return Err(invalid("fortran_order is neither True nor False".into())),
+        };
+        let shape = shape(field(&header, "shape")?)?;
+        if shape != [R, C] {
+            return Err(invalid(format!(
+                "expected shape ({R}, {C}) but the file holds {shape:?}"
+            )));
+        }
+        let buffer = self.buffer_mut();
+        let mut bytes = vec![0; core::mem::size_of::<T>()];
+        for index in 0..R * C {
+            r.read_exact(&mut bytes)?;
+            let location = if fortran_order {
+                location::<ColumnPrio>(index, R, C)
+            } else {
+                (index / C, index % C)
+            };
+            buffer[M::Priority::offset(location, R, C)] = T::from_bytes(&bytes, little);
+        }
+        Ok(())
+    }
+}
+
+impl<const S: usize, const R: usize, const C: usize, P, T> Stacktrix<S, R, C, P, T>
+where
+    P: MemoryPriority,
+    T: NpyElement,
+{
+    /// Reads a Stacktrix from a `.npy` file, see [`Npy::read_npy_into`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, RowPrio, Export, Npy };
+    /// let m = Stacktrix::<6, 2, 3, ColumnPrio, f64>::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let mut file = Vec::new();
+    /// m.write_npy(&mut file).unwrap();
+    /// let read = Stacktrix::<6, 2, 3, RowPrio, f64>::read_npy(&mut &file[..]).unwrap();
+    /// assert_eq!(read.to_rows(), m.to_rows());
+    /// assert!(Stacktrix::<6, 3, 2, RowPrio, f64>::read_npy(&mut &file[..]).is_err());
+    /// ```
+    pub fn read_npy<Rd: Read>(r: &mut Rd) -> io::Result<Self> {
+        let mut matrix = Self::from_fn(|_, _| T::default());
+        matrix.read_npy_into(r)?;
+        Ok(matrix)
+    }
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Reads the magic string, the version and the header dictionary.
+fn read_header<Rd: Read>(r: &mut Rd) -> io::Result<String> {
+    let mut preamble = [0; 8];
+    r.read_exact(&mut preamble)?;
+    if &preamble[..6] != MAGIC {
+        return Err(invalid("not a .npy file".into()));
+    }
+    let len = match preamble[6] {
+        1 => {
+            let mut len = [0; 2];
+            r.read_exact(&mut len)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0; 4];
+            r.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        major => return Err(invalid(format!("unsupported .npy version {major}"))),
+    };
+    if len > MAX_HEADER_LEN {
+        return Err(invalid(format!(
+            "the header of {len} bytes is longer than {MAX_HEADER_LEN} bytes"
+        )));
+    }
+    let mut header = vec![0; len];
+    r.read_exact(&mut header)?;
+    String::from_utf8(header).map_err(|_| invalid("the header is not UTF-8".into()))
+}
+
+/// Returns the text following the key in the header dictionary.
+fn field<'h>(header: &'h str, key: &str) -> io::Result<&'h str> {
+    let pattern = format!("'{key}':");
+    header
+        .find(&pattern)
+        .map(|start| header[start + pattern.len()..].trim_start())
+        .ok_or_else(|| invalid(format!("the header lacks {key}")))
+}
+
+/// Returns the content of the single quoted string text starts with.
+fn quoted(text: &str) -> io::Result<&str> {
+    text.strip_prefix('\'')
+        .and_then(|text| text.split_once('\''))
+        .map(|(content, _)| content)
+        .ok_or_else(|| invalid("descr is not a string".into()))
+}
+
+/// Parses the shape tuple text starts with.
+fn shape(text: &str) -> io::Result<Vec<usize>> {
+    text.strip_prefix('(')
+        .and_then(|text| text.split_once(')'))
+        .ok_or_else(|| invalid("shape is not a tuple".into()))?
+        .0
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| {
+            dim.parse()
+                .map_err(|_| invalid(format!("invalid dimension {dim}")))
+        })
+        .collect()
+}
@@ -0,0 +1,131 @@
+use crate::{
+    storage::{assert_row, StorageMut},
+    MemoryPriority,
+};
+use core::ops::{Add, Div, Mul, Sub};
+
+/// OrthogonalTransform applies elementary orthogonal transformations in place, the building
+/// blocks of QR decompositions and eigenvalue algorithms.
+///
+/// The loops follow the memory priority of the matrix, so that the buffer is walked
+/// continuously where possible.
+pub trait OrthogonalTransform<const R: usize, const C: usize, T> {
+    /// Multiplies the matrix from the left with the householder reflection
+    /// H = I - 2 * v * v^T / (v^T * v).
+    ///
+    /// v acts on the last v.len() rows, for the step k of a QR decomposition v has R - k
+    /// elements and from_col = k. Only the columns from from_col on are updated, the columns
+    /// before are expected to be zero in these rows already. A zero v leaves the matrix
+    /// unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If v has more than R elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, OrthogonalTransform };
+    /// let mut data = vec![3.0, 4.0, 1.0, 2.0];
+    /// let mut m = Reftrix::<2, 2, ColumnPrio, f64>::from_values(&mut data[..]);
+    /// // v = x - |x| * e1 maps the first column x = (3, 4) onto (5, 0).
+    /// m.apply_householder_left(&[-2.0, 4.0], 0);
+    /// let expected = [5.0, 0.0, 2.2, -0.4];
+    /// assert!(data.iter().zip(expected).all(|(a, b)| (a - b).abs() < 1e-12));
+    /// ```
+    fn apply_householder_left(&mut self, v: &[T], from_col: usize);
+    /// Rotates the rows i and j, i.e. multiplies the matrix from the left with the givens
+    /// rotation that has c at (i, i) and (j, j), s at (i, j) and -s at (j, i):
+    ///
+    /// row i = c * row i + s * row j
+    ///
+    /// row j = c * row j - s * row i
+    ///
+    /// # Panics
+    ///
+    /// If i or j is out of bounds or i == j.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, OrthogonalTransform };
+    /// let mut data = vec![3.0, 1.0, 4.0, 2.0];
+    /// let mut m = Reftrix::<2, 2, RowPrio, f64>::from_values(&mut data[..]);
+    /// // c = 3 / 5 and s = 4 / 5 zero the element (1, 0).
+    /// m.apply_givens(0, 1, 0.6, 0.8);
+    /// let expected = [5.0, 2.2, 0.0, 0.4];
+    /// assert!(data.iter().zip(expected).all(|(a, b)| (a - b).abs() < 1e-12));
+    /// ```
+    fn apply_givens(&mut self, i: usize, j: usize, c: T, s: T);
+}
+
+impl<M, const R: usize, const C: usize, T> OrthogonalTransform<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+    T: Copy
+        + PartialEq
+        + From<u8>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    fn apply_householder_left(&mut self, v: &[T], from_col: usize) {
+        assert!(
+            v.len() <= R,
+            "Householder vector of length {} exceeds the {} rows",
+            v.len(),
+            R
+        );
+        let zero = T::from(0);
+        let norm = v.iter().fold(zero, |acc, &x| acc + x * x);
+        if norm == zero {
+            return;
+        }
+        let tau = T::from(2) / norm;
+        let first = R - v.len();
+        let buffer = self.buffer_mut();
+        if M::Priority::row_stride(R, C) == 1 {
+            // Columns are continuous, every column is updated on its own.
+            for col in from_col..C {
+                let start = M::Priority::offset((first, col), R, C);
+                let column = &mut buffer[start..start + v.len()];
+                let w = column.iter().zip(v).fold(zero, |acc, (&a, &x)| acc + a * x) * tau;
+                for (a, &x) in column.iter_mut().zip(v) {
+                    *a = *a - w * x;
+                }
+            }
+        } else {
+            // Rows are continuous, v^T * A is accumulated row by row first.
+            let mut w = [zero; C];
+            for (i, &x) in v.iter().enumerate() {
+                for (col, w) in w.iter_mut().enumerate().skip(from_col) {
+                    *w = *w + x * buffer[M::Priority::offset((first + i, col), R, C)];
+                }
+            }
+            for (i, &x) in v.iter().enumerate() {
+                let x = x * tau;
+                for (col, &w) in w.iter().enumerate().skip(from_col) {
+                    let a = &mut buffer[M::Priority::offset((first + i, col), R, C)];
+                    *a = *a - x * w;
+                }
+            }
+        }
+    }
+
+    fn apply_givens(&mut self, i: usize, j: usize, c: T, s: T) {
+        assert_row::<R>(i);
+        assert_row::<R>(j);
+        assert!(i != j, "Givens rotation of row {} with itself", i);
+        let buffer = self.buffer_mut();
+        for col in 0..C {
+            let (oi, oj) = (
+                M::Priority::offset((i, col), R, C),
+                M::Priority::offset((j, col), R, C),
+            );
+            let (a, b) = (buffer[oi], buffer[oj]);
+            buffer[oi] = c * a + s * b;
+            buffer[oj] = c * b - s * a;
+        }
+    }
+}
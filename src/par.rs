@@ -0,0 +1,89 @@
+use crate::{storage::Storage, MemoryPriority};
+
+/// ParColMap maps a closure over all columns of a matrix in parallel.
+///
+/// With the `rayon` feature the columns are distributed over the rayon thread pool, otherwise
+/// they are split into one continuous range per available core, each mapped on a scoped thread.
+pub trait ParColMap<const R: usize, const C: usize, T> {
+    /// Maps f over all columns and collects the results in column order.
+    ///
+    /// Continuous columns of a [`ColumnPrio`](crate::ColumnPrio) matrix are passed to f in
+    /// place, strided columns are copied first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, ParColMap };
+    /// let m = Stacktrix::<6, 2, 3, RowPrio, u32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(m.par_col_map(|col| col.iter().sum::<u32>()), vec![5, 7, 9]);
+    /// ```
+    fn par_col_map<U, F>(&self, f: F) -> Vec<U>
+    where
+        U: Send,
+        F: Fn(&[T; R]) -> U + Sync;
+}
+
+impl<M, const R: usize, const C: usize, T> ParColMap<R, C, T> for M
+where
+    M: Storage<R, C, T> + Sync,
+    T: Copy + Sync,
+{
+    fn par_col_map<U, F>(&self, f: F) -> Vec<U>
+    where
+        U: Send,
+        F: Fn(&[T; R]) -> U + Sync,
+    {
+        let buffer = self.buffer();
+        let map = |col| map_column::<M, R, C, T, U, F>(buffer, col, &f);
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            (0..C).into_par_iter().map(map).collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            let threads = std::thread::available_parallelism()
+                .map_or(1, |n| n.get())
+                .min(C);
+            if threads <= 1 {
+                return (0..C).map(map).collect();
+            }
+            let per_thread = C.div_ceil(threads);
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = (0..C)
+                    .step_by(per_thread)
+                    .map(|start| {
+                        let map = &map;
+                        scope.spawn(move || {
+                            (start..C.min(start + per_thread))
+                                .map(map)
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|handle| handle.join().expect("column mapping thread panicked"))
+                    .collect()
+            })
+        }
+    }
+}
+
+/// Calls f with column col of the R x C matrix in buffer.
+fn map_column<M, const R: usize, const C: usize, T, U, F>(buffer: &[T], col: usize, f: &F) -> U
+where
+    M: Storage<R, C, T>,
+    T: Copy,
+    F: Fn(&[T; R]) -> U,
+{
+    if M::Priority::row_stride(R, C) == 1 {
+        let start = M::Priority::offset((0, col), R, C);
+        let column = buffer[start..start + R]
+            .try_into()
+            .expect("a continuous column holds R elements");
+        return f(column);
+    }
+    let column = core::array::from_fn(|row| buffer[M::Priority::offset((row, col), R, C)]);
+    f(&column)
+}
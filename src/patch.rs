@@ -0,0 +1,162 @@
+use crate::{
+    storage::{Storage, StorageMut},
+    MatrixError, MemoryPriority, Position,
+};
+use alloc::vec::Vec;
+use core::mem::size_of;
+
+/// MatrixPatch holds the changes between two matrices of the same shape, see
+/// [`Patch::diff_patch`].
+///
+/// Locations and data are logical, so a patch computed on one memory priority applies to the
+/// other as well.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MatrixPatch<T> {
+    /// The changed cells with their new value.
+    Cells {
+        /// The amount of rows of the patched matrix.
+        rows: usize,
+        /// The amount of columns of the patched matrix.
+        cols: usize,
+        /// The location and the new value of every changed cell, in row major order.
+        cells: Vec<(Position, T)>,
+    },
+    /// Every element, used when listing the changed cells would not be smaller.
+    Full {
+        /// The amount of rows of the patched matrix.
+        rows: usize,
+        /// The amount of columns of the patched matrix.
+        cols: usize,
+        /// All elements in row major order.
+        data: Vec<T>,
+    },
+}
+
+impl<T> MatrixPatch<T> {
+    /// Returns true if the patch changes nothing.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            MatrixPatch::Cells { cells, .. } => cells.is_empty(),
+            MatrixPatch::Full { .. } => false,
+        }
+    }
+}
+
+/// Patch computes and applies the changes between two matrices, e.g. to synchronize the state
+/// of a matrix with another process.
+pub trait Patch<const R: usize, const C: usize, T> {
+    /// Computes the patch that turns old into this matrix.
+    ///
+    /// The patch lists the changed cells, unless that takes at least as much memory as all
+    /// elements, then it holds a full copy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, RowPrio, MatrixPatch, Patch };
+    /// let old = Stacktrix::<6, 2, 3, RowPrio, u64>::zeros();
+    /// let mut new = Stacktrix::<6, 2, 3, ColumnPrio, u64>::zeros();
+    /// new.as_mut_slice()[3] = 7;
+    /// let patch = new.diff_patch(&old);
+    /// assert_eq!(patch, MatrixPatch::Cells { rows: 2, cols: 3, cells: vec![((1, 1), 7)] });
+    ///
+    /// let mut synced = Stacktrix::<6, 2, 3, RowPrio, u64>::zeros();
+    /// synced.apply_patch(&patch).unwrap();
+    /// assert_eq!(synced, new);
+    /// ```
+    fn diff_patch<O>(&self, old: &O) -> MatrixPatch<T>
+    where
+        O: Storage<R, C, T>;
+    /// Applies a patch computed by [`Patch::diff_patch`].
+    ///
+    /// Returns [`MatrixError::InvalidShape`] if the patch was computed for matrices of another
+    /// shape, and [`MatrixError::OutOfBounds`] if a cell or [`MatrixError::SizeMismatch`] if
+    /// the data of a hand made patch does not fit. The matrix is unchanged in that case.
+    fn apply_patch(&mut self, patch: &MatrixPatch<T>) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>;
+}
+
+impl<M, const R: usize, const C: usize, T> Patch<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+    T: Copy + PartialEq,
+{
+    fn diff_patch<O>(&self, old: &O) -> MatrixPatch<T>
+    where
+        O: Storage<R, C, T>,
+    {
+        let (new, old) = (self.buffer(), old.buffer());
+        let mut cells = Vec::new();
+        for row in 0..R {
+            for col in 0..C {
+                let value = new[M::Priority::offset((row, col), R, C)];
+                if value != old[O::Priority::offset((row, col), R, C)] {
+                    cells.push(((row, col), value));
+                }
+            }
+        }
+        if cells.len() * size_of::<(Position, T)>() < R * C * size_of::<T>() {
+            return MatrixPatch::Cells {
+                rows: R,
+                cols: C,
+                cells,
+            };
+        }
+        let mut data = Vec::with_capacity(R * C);
+        for row in 0..R {
+            for col in 0..C {
+                data.push(new[M::Priority::offset((row, col), R, C)]);
+            }
+        }
+        MatrixPatch::Full {
+            rows: R,
+            cols: C,
+            data,
+        }
+    }
+
+    fn apply_patch(&mut self, patch: &MatrixPatch<T>) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        let (MatrixPatch::Cells { rows, cols, .. } | MatrixPatch::Full { rows, cols, .. }) = *patch;
+        if (rows, cols) != (R, C) {
+            return Err(MatrixError::InvalidShape {
+                size: R * C,
+                rows,
+                cols,
+            });
+        }
+        let buffer = self.buffer_mut();
+        match patch {
+            MatrixPatch::Cells { cells, .. } => {
+                if let Some(&((row, col), _)) = cells.iter().find(|((r, c), _)| *r >= R || *c >= C)
+                {
+                    return Err(MatrixError::OutOfBounds {
+                        row,
+                        col,
+                        rows: R,
+                        cols: C,
+                    });
+                }
+                for &(location, value) in cells {
+                    buffer[M::Priority::offset(location, R, C)] = value;
+                }
+            }
+            MatrixPatch::Full { data, .. } => {
+                if data.len() != R * C {
+                    return Err(MatrixError::SizeMismatch {
+                        expected: R * C,
+                        got: data.len(),
+                    });
+                }
+                for (index, &value) in data.iter().enumerate() {
+                    buffer[M::Priority::offset((index / C, index % C), R, C)] = value;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    storage::StorageMut, Boolean, MatrixError, MemoryPriority, MinPlus, Progress, Semiring,
+};
+use core::ops::ControlFlow;
+
+/// Paths computes the closure of square adjacency matrices over all paths in their own memory.
+pub trait Paths<const R: usize, const C: usize, T> {
+    /// Replaces the element (i, j) with the semiring sum over the products along all paths from
+    /// i to j, using the Floyd-Warshall algorithm.
+    ///
+    /// The update d(i, j) = add(d(i, j), mul(d(i, k), d(k, j))) is done in place, which requires
+    /// an idempotent add such as the minimum of [`MinPlus`] or the or of [`Boolean`].
+    ///
+    /// Returns [`MatrixError::NotSquare`] if R != C.
+    fn semiring_closure_in_place<S>(&mut self, semiring: S) -> Result<(), MatrixError>
+    where
+        S: Semiring<T>;
+    /// Computes the closure like [`Paths::semiring_closure_in_place`], reporting the
+    /// intermediate nodes that are done to progress.
+    ///
+    /// Returns [`MatrixError::Cancelled`] if progress cancelled the closure, the matrix then
+    /// holds the closure over the paths through the nodes reported so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix, Boolean, MatrixError, Paths };
+    /// let mut reach = Stacktrix::<16, 4, 4, RowPrio, bool>::from_fn(|i, j| j == i + 1);
+    /// let cancelled = reach.semiring_closure_with_progress(Boolean, |progress| {
+    ///     match progress.done {
+    ///         2 => ControlFlow::Break(()),
+    ///         _ => ControlFlow::Continue(()),
+    ///     }
+    /// });
+    /// assert_eq!(cancelled, Err(MatrixError::Cancelled));
+    /// // Only paths through the nodes 0 and 1 are known.
+    /// assert!(*reach.get((0, 2)) && !*reach.get((0, 3)));
+    /// ```
+    fn semiring_closure_with_progress<S, F>(
+        &mut self,
+        semiring: S,
+        progress: F,
+    ) -> Result<(), MatrixError>
+    where
+        S: Semiring<T>,
+        F: FnMut(Progress) -> ControlFlow<()>;
+    /// Replaces the edge weights with the lengths of the shortest paths between all pairs of
+    /// nodes, unreachable pairs stay at [`Tropical::INFINITY`](crate::Tropical::INFINITY).
+    ///
+    /// The diagonal is expected to be zero. A negative cycle shows as a negative element on the
+    /// diagonal afterwards, the other distances are meaningless in that case.
+    ///
+    /// Returns [`MatrixError::NotSquare`] if R != C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix, Paths };
+    /// const INF: f64 = f64::INFINITY;
+    /// let mut d = Stacktrix::<9, 3, 3, RowPrio, f64>::from_rows([
+    ///     [0.0, 4.0, 1.0],
+    ///     [INF, 0.0, INF],
+    ///     [INF, 2.0, 0.0],
+    /// ]);
+    /// d.all_pairs_shortest_paths().unwrap();
+    /// assert_eq!(d.get_row(0), &[0.0, 3.0, 1.0]);
+    /// assert_eq!(d.get_row(1), &[INF, 0.0, INF]);
+    /// ```
+    fn all_pairs_shortest_paths(&mut self) -> Result<(), MatrixError>
+    where
+        MinPlus: Semiring<T>;
+    /// Replaces the adjacency matrix with its transitive closure, (i, j) is true if there is a
+    /// path of at least one edge from i to j.
+    ///
+    /// Returns [`MatrixError::NotSquare`] if R != C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Paths };
+    /// let mut reach = Stacktrix::<9, 3, 3, ColumnPrio, bool>::from_fn(|i, j| j == i + 1);
+    /// reach.transitive_closure().unwrap();
+    /// assert_eq!(
+    ///     reach,
+    ///     Stacktrix::<9, 3, 3, ColumnPrio, bool>::from_fn(|i, j| j > i)
+    /// );
+    /// ```
+    fn transitive_closure(&mut self) -> Result<(), MatrixError>
+    where
+        Boolean: Semiring<T>;
+}
+
+impl<M, const R: usize, const C: usize, T> Paths<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+    T: Copy,
+{
+    fn semiring_closure_in_place<S>(&mut self, semiring: S) -> Result<(), MatrixError>
+    where
+        S: Semiring<T>,
+    {
+        self.semiring_closure_with_progress(semiring, |_| ControlFlow::Continue(()))
+    }
+
+    fn semiring_closure_with_progress<S, F>(
+        &mut self,
+        semiring: S,
+        mut progress: F,
+    ) -> Result<(), MatrixError>
+    where
+        S: Semiring<T>,
+        F: FnMut(Progress) -> ControlFlow<()>,
+    {
+        if R != C {
+            return Err(MatrixError::NotSquare { rows: R, cols: C });
+        }
+        let at = |row, col| M::Priority::offset((row, col), R, C);
+        let d = self.buffer_mut();
+        for k in 0..R {
+            for i in 0..R {
+                let via = d[at(i, k)];
+                for j in 0..R {
+                    d[at(i, j)] = semiring.add(d[at(i, j)], semiring.mul(via, d[at(k, j)]));
+                }
+            }
+            if progress(Progress::of(k + 1, R)).is_break() {
+                return Err(MatrixError::Cancelled);
+            }
+        }
+        Ok(())
+    }
+
+    fn all_pairs_shortest_paths(&mut self) -> Result<(), MatrixError>
+    where
+        MinPlus: Semiring<T>,
+    {
+        self.semiring_closure_in_place(MinPlus)
+    }
+
+    fn transitive_closure(&mut self) -> Result<(), MatrixError>
+    where
+        Boolean: Semiring<T>,
+    {
+        self.semiring_closure_in_place(Boolean)
+    }
+}
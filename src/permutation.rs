@@ -0,0 +1,454 @@
+use crate::{
+    storage::{Storage, StorageMut},
+    MemoryPriority, Stacktrix,
+};
+use core::hash::{Hash, Hasher};
+
+/// Permutation is a permutation of N indices stored as an index array.
+///
+/// The permutation p corresponds to the N x N permutation matrix P that has a 1 at (i, p\[i\])
+/// for every i. Applying it to the rows of a matrix A computes P * A, the row i of the result
+/// is the row p\[i\] of A. In contrast to a dense permutation matrix only N indices are stored
+/// and applying it swaps whole rows / columns in place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Permutation<const N: usize> {
+    indices: [usize; N],
+}
+
+impl<const N: usize> Permutation<N> {
+    /// Constructs the identity permutation.
+    pub fn identity() -> Self {
+        Self {
+            indices: core::array::from_fn(|i| i),
+        }
+    }
+
+    /// Constructs a permutation from an index array, the row i of a permuted matrix is the
+    /// row indices\[i\] of the original.
+    ///
+    /// Returns None if indices is not a permutation of 0..N.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::Permutation;
+    /// assert!(Permutation::from_indices([2, 0, 1]).is_some());
+    /// assert!(Permutation::from_indices([2, 0, 2]).is_none());
+    /// assert!(Permutation::from_indices([3, 0, 1]).is_none());
+    /// ```
+    pub fn from_indices(indices: [usize; N]) -> Option<Self> {
+        let mut seen = [false; N];
+        for &i in indices.iter() {
+            if i >= N || seen[i] {
+                return None;
+            }
+            seen[i] = true;
+        }
+        Some(Self { indices })
+    }
+
+    /// Returns the index array of the permutation.
+    pub fn indices(&self) -> &[usize; N] {
+        &self.indices
+    }
+
+    /// Swaps the targets of a and b, e.g. to record a pivot row swap.
+    ///
+    /// # Panics
+    ///
+    /// If a or b is out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.indices.swap(a, b);
+    }
+
+    /// Returns the permutation that is equivalent to applying self and then other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Permutation, Stacktrix, RowPrio, RowPrioMatrix };
+    /// let p = Permutation::from_indices([1, 2, 0]).unwrap();
+    /// let q = Permutation::from_indices([1, 0, 2]).unwrap();
+    /// let mut a = Stacktrix::<3, 3, 1, RowPrio, u8>::with_values([0, 1, 2]);
+    /// let mut b = Stacktrix::<3, 3, 1, RowPrio, u8>::with_values([0, 1, 2]);
+    /// p.apply_to_rows(&mut a);
+    /// q.apply_to_rows(&mut a);
+    /// p.compose(&q).apply_to_rows(&mut b);
+    /// assert_eq!(a.get_column(0).into_iter().collect::<Vec<_>>(), vec![&2, &1, &0]);
+    /// assert_eq!(b.get_column(0).into_iter().collect::<Vec<_>>(), vec![&2, &1, &0]);
+    /// ```
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            indices: core::array::from_fn(|i| self.indices[other.indices[i]]),
+        }
+    }
+
+    /// Returns the inverse permutation, which corresponds to the transposed permutation matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::Permutation;
+    /// let p = Permutation::from_indices([2, 0, 3, 1]).unwrap();
+    /// assert_eq!(p.compose(&p.inverse()), Permutation::identity());
+    /// ```
+    pub fn inverse(&self) -> Self {
+        let mut indices = [0; N];
+        for (i, &p) in self.indices.iter().enumerate() {
+            indices[p] = i;
+        }
+        Self { indices }
+    }
+
+    /// Permutes the rows of the matrix in place, afterwards row i holds the previous row
+    /// p\[i\].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Permutation, Reftrix, ColumnPrio };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6];
+    /// let mut m = Reftrix::<3, 2, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// Permutation::from_indices([2, 0, 1]).unwrap().apply_to_rows(&mut m);
+    /// assert_eq!(&data[..], &[3, 1, 2, 6, 4, 5]);
+    /// ```
+    pub fn apply_to_rows<M, const C: usize, T>(&self, matrix: &mut M)
+    where
+        M: StorageMut<N, C, T>,
+    {
+        let buffer = matrix.buffer_mut();
+        self.apply_cycles(|a, b| {
+            for col in 0..C {
+                buffer.swap(
+                    M::Priority::offset((a, col), N, C),
+                    M::Priority::offset((b, col), N, C),
+                );
+            }
+        });
+    }
+
+    /// Permutes the columns of the matrix in place, afterwards column i holds the previous
+    /// column p\[i\].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Permutation, Reftrix, RowPrio };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6];
+    /// let mut m = Reftrix::<2, 3, RowPrio, u8>::from_values(&mut data[..]);
+    /// Permutation::from_indices([2, 0, 1]).unwrap().apply_to_cols(&mut m);
+    /// assert_eq!(&data[..], &[3, 1, 2, 6, 4, 5]);
+    /// ```
+    pub fn apply_to_cols<M, const R: usize, T>(&self, matrix: &mut M)
+    where
+        M: StorageMut<R, N, T>,
+    {
+        let buffer = matrix.buffer_mut();
+        self.apply_cycles(|a, b| {
+            for row in 0..R {
+                buffer.swap(
+                    M::Priority::offset((row, a), R, N),
+                    M::Priority::offset((row, b), R, N),
+                );
+            }
+        });
+    }
+
+    /// Walks all cycles of the permutation and performs the swaps that move the lane
+    /// p\[i\] to i.
+    fn apply_cycles(&self, mut swap: impl FnMut(usize, usize)) {
+        let mut done = [false; N];
+        for start in 0..N {
+            if done[start] {
+                continue;
+            }
+            done[start] = true;
+            let mut current = start;
+            while self.indices[current] != start {
+                let next = self.indices[current];
+                swap(current, next);
+                done[next] = true;
+                current = next;
+            }
+        }
+    }
+
+    /// Constructs the dense N x N permutation matrix, S has to equal N * N.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Permutation, RowPrio, RowPrioMatrix };
+    /// let p = Permutation::from_indices([1, 2, 0]).unwrap();
+    /// let m = p.to_matrix::<9, RowPrio, u8>();
+    /// assert_eq!(m.get_row(0), &[0, 1, 0]);
+    /// assert_eq!(m.get_row(2), &[1, 0, 0]);
+    /// ```
+    pub fn to_matrix<const S: usize, P, T>(&self) -> Stacktrix<S, N, N, P, T>
+    where
+        P: MemoryPriority,
+        T: Copy + From<u8>,
+    {
+        let mut m = Stacktrix::with_values([T::from(0); S]);
+        let buffer = m.buffer_mut();
+        for (row, &col) in self.indices.iter().enumerate() {
+            buffer[P::offset((row, col), N, N)] = T::from(1);
+        }
+        m
+    }
+
+    /// Reads a permutation from a dense N x N permutation matrix.
+    ///
+    /// Returns None if the matrix is not a permutation matrix, i.e. not every row and every
+    /// column contains exactly one 1 and zeros otherwise.
+    pub fn from_matrix<M, T>(matrix: &M) -> Option<Self>
+    where
+        M: Storage<N, N, T>,
+        T: From<u8> + PartialEq,
+    {
+        let (zero, one) = (T::from(0), T::from(1));
+        let buffer = matrix.buffer();
+        let mut indices = [N; N];
+        for (row, index) in indices.iter_mut().enumerate() {
+            for col in 0..N {
+                let el = &buffer[M::Priority::offset((row, col), N, N)];
+                if *el == one && *index == N {
+                    *index = col;
+                } else if *el != zero {
+                    return None;
+                }
+            }
+        }
+        Self::from_indices(indices)
+    }
+}
+
+impl<const N: usize> Default for Permutation<N> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// LanePermutation compares matrices up to the order of their rows or columns, e.g. the output
+/// of an algorithm that may legitimately reorder rows.
+///
+/// Every lane is hashed, only lanes with equal hashes are compared element wise. The matrices
+/// may have different memory priorities.
+pub trait LanePermutation<const R: usize, const C: usize, T> {
+    /// Returns the permutation that turns the rows of other into the rows of self, i.e. row i
+    /// of self equals row p\[i\] of other, or None if the matrices do not hold the same rows
+    /// with the same multiplicities. Equal rows are matched in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, RowPrio, LanePermutation };
+    /// let a = Stacktrix::<6, 3, 2, RowPrio, u8>::from_rows([[1, 2], [3, 4], [1, 2]]);
+    /// let mut b = Stacktrix::<6, 3, 2, ColumnPrio, u8>::from_rows([[3, 4], [1, 2], [1, 2]]);
+    /// let p = a.row_permutation_of(&b).unwrap();
+    /// assert_eq!(p.indices(), &[1, 0, 2]);
+    /// p.apply_to_rows(&mut b);
+    /// assert_eq!(a, b);
+    /// ```
+    fn row_permutation_of<O>(&self, other: &O) -> Option<Permutation<R>>
+    where
+        O: Storage<R, C, T>;
+    /// Returns true if self and other hold the same rows with the same multiplicities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, LanePermutation };
+    /// let a = Stacktrix::<4, 2, 2, RowPrio, u8>::from_rows([[1, 2], [1, 2]]);
+    /// let b = Stacktrix::<4, 2, 2, RowPrio, u8>::from_rows([[1, 2], [2, 1]]);
+    /// assert!(a.is_row_permutation_of(&a));
+    /// assert!(!a.is_row_permutation_of(&b));
+    /// ```
+    fn is_row_permutation_of<O>(&self, other: &O) -> bool
+    where
+        O: Storage<R, C, T>,
+    {
+        self.row_permutation_of(other).is_some()
+    }
+    /// Returns the permutation that turns the columns of other into the columns of self, i.e.
+    /// column i of self equals column p\[i\] of other, or None if the matrices do not hold the
+    /// same columns with the same multiplicities.
+    fn col_permutation_of<O>(&self, other: &O) -> Option<Permutation<C>>
+    where
+        O: Storage<R, C, T>;
+    /// Returns true if self and other hold the same columns with the same multiplicities.
+    fn is_col_permutation_of<O>(&self, other: &O) -> bool
+    where
+        O: Storage<R, C, T>,
+    {
+        self.col_permutation_of(other).is_some()
+    }
+}
+
+impl<M, const R: usize, const C: usize, T> LanePermutation<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+    T: Hash + Eq,
+{
+    fn row_permutation_of<O>(&self, other: &O) -> Option<Permutation<R>>
+    where
+        O: Storage<R, C, T>,
+    {
+        let (a, b) = (self.buffer(), other.buffer());
+        let at_a = |row, col| &a[M::Priority::offset((row, col), R, C)];
+        let at_b = |row, col| &b[O::Priority::offset((row, col), R, C)];
+        match_lanes(
+            core::array::from_fn(|row| lane_hash((0..C).map(|col| at_a(row, col)))),
+            core::array::from_fn(|row| lane_hash((0..C).map(|col| at_b(row, col)))),
+            |i, j| (0..C).all(|col| at_a(i, col) == at_b(j, col)),
+        )
+    }
+
+    fn col_permutation_of<O>(&self, other: &O) -> Option<Permutation<C>>
+    where
+        O: Storage<R, C, T>,
+    {
+        let (a, b) = (self.buffer(), other.buffer());
+        let at_a = |row, col| &a[M::Priority::offset((row, col), R, C)];
+        let at_b = |row, col| &b[O::Priority::offset((row, col), R, C)];
+        match_lanes(
+            core::array::from_fn(|col| lane_hash((0..R).map(|row| at_a(row, col)))),
+            core::array::from_fn(|col| lane_hash((0..R).map(|row| at_b(row, col)))),
+            |i, j| (0..R).all(|row| at_a(row, i) == at_b(row, j)),
+        )
+    }
+}
+
+/// Canonicalize reorders the rows and columns of a matrix into a deterministic order, e.g. to
+/// hash or compare results that are only defined up to the order of their rows and columns.
+pub trait Canonicalize<const R: usize, const C: usize, T> {
+    /// Sorts the rows and the columns in place and returns the permutations that were applied,
+    /// row i of the result is the previous row p\[i\], column j the previous column q\[j\].
+    ///
+    /// Lanes are ordered by their sorted elements first, which does not depend on the order of
+    /// the other lanes. Ties are broken lexicographically, alternating between rows and columns
+    /// until neither order changes or R + C rounds have passed, equal lanes keep their order.
+    /// Equal canonical forms imply that the matrices are permutations of each other, the
+    /// converse holds for most but not all matrices, e.g. not for every pair of isomorphic
+    /// adjacency matrices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, RowPrio, Canonicalize, Export };
+    /// let rows = [[5, 1], [2, 0], [3, 4]];
+    /// let mut a = Stacktrix::<6, 3, 2, RowPrio, u8>::from_rows(rows);
+    /// let mut b = Stacktrix::<6, 3, 2, ColumnPrio, u8>::from_rows([[4, 3], [1, 5], [0, 2]]);
+    /// let (p, q) = a.canonicalize();
+    /// b.canonicalize();
+    /// assert_eq!(a.to_rows(), [[0, 2], [1, 5], [4, 3]]);
+    /// assert_eq!(a, b);
+    /// let mut restored = Stacktrix::<6, 3, 2, RowPrio, u8>::from_rows(rows);
+    /// p.apply_to_rows(&mut restored);
+    /// q.apply_to_cols(&mut restored);
+    /// assert_eq!(restored, a);
+    /// ```
+    fn canonicalize(&mut self) -> (Permutation<R>, Permutation<C>)
+    where
+        T: Ord + Copy;
+}
+
+impl<M, const R: usize, const C: usize, T> Canonicalize<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+{
+    fn canonicalize(&mut self) -> (Permutation<R>, Permutation<C>)
+    where
+        T: Ord + Copy,
+    {
+        let buffer = self.buffer();
+        let at = |row, col| buffer[M::Priority::offset((row, col), R, C)];
+        let row_keys: [[T; C]; R] = core::array::from_fn(|row| {
+            let mut key = core::array::from_fn(|col| at(row, col));
+            key.sort_unstable();
+            key
+        });
+        let col_keys: [[T; R]; C] = core::array::from_fn(|col| {
+            let mut key = core::array::from_fn(|row| at(row, col));
+            key.sort_unstable();
+            key
+        });
+        let mut rows: [usize; R] = core::array::from_fn(|row| row);
+        let mut cols: [usize; C] = core::array::from_fn(|col| col);
+        for _ in 0..R + C {
+            let (previous_rows, previous_cols) = (rows, cols);
+            rows.sort_unstable_by(|&a, &b| {
+                row_keys[a]
+                    .cmp(&row_keys[b])
+                    .then_with(|| {
+                        let lane = |row| cols.iter().map(move |&col| at(row, col));
+                        lane(a).cmp(lane(b))
+                    })
+                    .then(a.cmp(&b))
+            });
+            cols.sort_unstable_by(|&a, &b| {
+                col_keys[a]
+                    .cmp(&col_keys[b])
+                    .then_with(|| {
+                        let lane = |col| rows.iter().map(move |&row| at(row, col));
+                        lane(a).cmp(lane(b))
+                    })
+                    .then(a.cmp(&b))
+            });
+            if rows == previous_rows && cols == previous_cols {
+                break;
+            }
+        }
+        let (rows, cols) = (Permutation { indices: rows }, Permutation { indices: cols });
+        rows.apply_to_rows(self);
+        cols.apply_to_cols(self);
+        (rows, cols)
+    }
+}
+
+/// Matches every lane i of a with an equal, unmatched lane j of b, candidates are looked up by
+/// their hash.
+fn match_lanes<const N: usize>(
+    hash_a: [u64; N],
+    hash_b: [u64; N],
+    eq: impl Fn(usize, usize) -> bool,
+) -> Option<Permutation<N>> {
+    let mut order: [usize; N] = core::array::from_fn(|j| j);
+    order.sort_unstable_by_key(|&j| (hash_b[j], j));
+    let mut matched = [false; N];
+    let mut indices = [0; N];
+    for (i, index) in indices.iter_mut().enumerate() {
+        let start = order.partition_point(|&j| hash_b[j] < hash_a[i]);
+        let j = order[start..]
+            .iter()
+            .copied()
+            .take_while(|&j| hash_b[j] == hash_a[i])
+            .find(|&j| !matched[j] && eq(i, j))?;
+        matched[j] = true;
+        *index = j;
+    }
+    Some(Permutation { indices })
+}
+
+/// Hashes the elements of a lane with FNV-1a, which needs neither std nor a random seed.
+fn lane_hash<'a, T: Hash + 'a>(lane: impl Iterator<Item = &'a T>) -> u64 {
+    let mut hasher = Fnv(0xcbf2_9ce4_8422_2325);
+    for el in lane {
+        el.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+struct Fnv(u64);
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
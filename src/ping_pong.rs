@@ -0,0 +1,87 @@
+/// PingPong owns two matrices of the same type, one holding the current state and one the next,
+/// as used by stencils, cellular automata and other iterative algorithms.
+///
+/// Swapping only flips which matrix is the current one, no elements are moved.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ PingPong, Stacktrix, RowPrio, RowPrioMatrix };
+/// // Every cell becomes the sum of its left and right neighbour, wrapping around.
+/// let start = Stacktrix::<4, 1, 4, RowPrio, u32>::with_values([1, 0, 0, 0]);
+/// let mut state = PingPong::new(start, Stacktrix::zeros());
+/// for _ in 0..2 {
+///     state.step(|src, dst| {
+///         let src = src.get_row(0);
+///         for (col, el) in dst.get_mut_row(0).iter_mut().enumerate() {
+///             *el = src[(col + 3) % 4] + src[(col + 1) % 4];
+///         }
+///     });
+/// }
+/// assert_eq!(state.current().get_row(0), &[2, 0, 2, 0]);
+/// ```
+pub struct PingPong<M> {
+    buffers: [M; 2],
+    current: usize,
+}
+
+impl<M> PingPong<M> {
+    /// Constructs a PingPong from the current state and the matrix the next state is written
+    /// to, the initial content of next does not matter to [`PingPong::step`].
+    pub fn new(current: M, next: M) -> Self {
+        Self {
+            buffers: [current, next],
+            current: 0,
+        }
+    }
+
+    /// Returns the current state.
+    pub fn current(&self) -> &M {
+        &self.buffers[self.current]
+    }
+
+    /// Returns the current state mutably.
+    pub fn current_mut(&mut self) -> &mut M {
+        &mut self.buffers[self.current]
+    }
+
+    /// Returns the matrix the next state is written to.
+    pub fn next_mut(&mut self) -> &mut M {
+        &mut self.buffers[1 - self.current]
+    }
+
+    /// Returns the current state together with the matrix the next state is written to.
+    pub fn split(&mut self) -> (&M, &mut M) {
+        let [first, second] = &mut self.buffers;
+        if self.current == 0 {
+            (first, second)
+        } else {
+            (second, first)
+        }
+    }
+
+    /// Makes the next state the current one.
+    pub fn swap(&mut self) {
+        self.current = 1 - self.current;
+    }
+
+    /// Calls f with the current state and the matrix the next state is written to, then swaps.
+    pub fn step<F>(&mut self, f: F)
+    where
+        F: FnOnce(&M, &mut M),
+    {
+        let (src, dst) = self.split();
+        f(src, dst);
+        self.swap();
+    }
+
+    /// Consumes the PingPong and returns the current state and the other matrix.
+    pub fn into_inner(self) -> (M, M) {
+        let [first, second] = self.buffers;
+        if self.current == 0 {
+            (first, second)
+        } else {
+            (second, first)
+        }
+    }
+}
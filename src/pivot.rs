@@ -0,0 +1,130 @@
+use crate::{
+    storage::{assert_col, assert_row, Storage, StorageMut},
+    MemoryPriority,
+};
+use core::ops::Sub;
+
+/// Pivot provides the pivot search of elimination algorithms.
+///
+/// The candidates of a pivot are the elements of a column at and below a row. The search walks
+/// the column directly in the buffer with the stride of the memory priority, no intermediate
+/// column is collected.
+pub trait Pivot<const R: usize, const C: usize, T> {
+    /// Returns the row of the element with the largest absolute value in column col, only rows
+    /// from from_row on are considered. On ties the first row wins, elements that can not be
+    /// compared (NaN) are skipped.
+    ///
+    /// Returns None if from_row is not a row of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// If col is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, Pivot };
+    /// let mut data = vec![9, 1, -4, 2, 7, 3, -8, 4];
+    /// let m = Reftrix::<4, 2, RowPrio, i32>::from_values(&mut data[..]);
+    /// assert_eq!(m.max_abs_in_col(0, 0), Some(0));
+    /// assert_eq!(m.max_abs_in_col(0, 1), Some(3));
+    /// assert_eq!(m.max_abs_in_col(1, 2), Some(3));
+    /// assert_eq!(m.max_abs_in_col(0, 4), None);
+    /// ```
+    fn max_abs_in_col(&self, col: usize, from_row: usize) -> Option<usize>;
+    /// Searches the pivot of column col from row on with
+    /// [`max_abs_in_col`](Pivot::max_abs_in_col) and swaps the pivot row into row.
+    ///
+    /// Returns the row the pivot was found in, or None if row is not a row of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// If col is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, Pivot };
+    /// let mut data = vec![1.0, -3.0, 2.0, 4.0, 5.0, 6.0];
+    /// let mut m = Reftrix::<3, 2, ColumnPrio, f64>::from_values(&mut data[..]);
+    /// assert_eq!(m.swap_to_pivot(0, 0), Some(1));
+    /// assert_eq!(&data[..], &[-3.0, 1.0, 2.0, 5.0, 4.0, 6.0]);
+    /// ```
+    fn swap_to_pivot(&mut self, col: usize, row: usize) -> Option<usize>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Swaps the rows a and b.
+    ///
+    /// # Panics
+    ///
+    /// If a or b is out of bounds.
+    fn swap_rows(&mut self, a: usize, b: usize)
+    where
+        Self: StorageMut<R, C, T>;
+}
+
+impl<M, const R: usize, const C: usize, T> Pivot<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+    T: PartialOrd + Sub<Output = T> + Default + Copy,
+{
+    fn max_abs_in_col(&self, col: usize, from_row: usize) -> Option<usize> {
+        assert_col::<C>(col);
+        if from_row >= R {
+            return None;
+        }
+        let zero = T::default();
+        // Whether |a| > |b|. Only elements that are not negative are negated, the negation of
+        // e.g. i32::MIN overflows.
+        let larger = |a: T, b: T| match (a < zero, b < zero) {
+            (false, false) => a > b,
+            (true, true) => a < b,
+            (true, false) => a < zero - b,
+            (false, true) => zero - a < b,
+        };
+        let start = M::Priority::offset((from_row, col), R, C);
+        let stride = M::Priority::row_stride(R, C);
+        let mut candidates = self.buffer()[start..]
+            .iter()
+            .step_by(stride)
+            .take(R - from_row)
+            .copied()
+            .enumerate();
+        let (mut best, mut max) = candidates.next()?;
+        for (i, el) in candidates {
+            // A NaN max is replaced by the first comparable element.
+            if larger(el, max) || max.partial_cmp(&max).is_none() {
+                best = i;
+                max = el;
+            }
+        }
+        Some(from_row + best)
+    }
+
+    fn swap_to_pivot(&mut self, col: usize, row: usize) -> Option<usize>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        let pivot = self.max_abs_in_col(col, row)?;
+        self.swap_rows(row, pivot);
+        Some(pivot)
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize)
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        assert_row::<R>(a);
+        assert_row::<R>(b);
+        if a == b {
+            return;
+        }
+        let buffer = self.buffer_mut();
+        for col in 0..C {
+            buffer.swap(
+                M::Priority::offset((a, col), R, C),
+                M::Priority::offset((b, col), R, C),
+            );
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Out of bounds behavior for grid sampling.
+//!
+//! An [`IndexPolicy`] maps a signed coordinate onto the rows or columns of a matrix, e.g. to
+//! sample the neighbourhood of an edge element the way textures are sampled. The policy is a
+//! type parameter of [`Sample::sample`], so the choice costs no branching at run time.
+//!
+//! ```
+//! # use mightrix::{ Stacktrix, RowPrio, Sample, policy::{ Clamp, Wrap } };
+//! let m = Stacktrix::<4, 2, 2, RowPrio, u8>::from_rows([[1, 2], [3, 4]]);
+//! assert_eq!(*m.sample::<Clamp>(-1, 5), 2);
+//! assert_eq!(*m.sample::<Wrap>(-1, 5), 4);
+//! ```
+use crate::{
+    storage::{Storage, StorageMut},
+    MemoryPriority,
+};
+
+/// IndexPolicy decides which index a coordinate outside of 0..len is mapped to.
+pub trait IndexPolicy {
+    /// Maps index onto 0..len.
+    ///
+    /// # Panics
+    ///
+    /// If len is zero, or as the policy decides.
+    fn resolve(index: isize, len: usize) -> usize;
+}
+
+/// Panic rejects coordinates outside of the matrix, like indexing does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Panic;
+
+impl IndexPolicy for Panic {
+    fn resolve(index: isize, len: usize) -> usize {
+        match usize::try_from(index) {
+            Ok(index) if index < len => index,
+            _ => panic!("Index {index} out of bounds {len}"),
+        }
+    }
+}
+
+/// Clamp repeats the edge, coordinates are moved to the closest row or column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Clamp;
+
+impl IndexPolicy for Clamp {
+    fn resolve(index: isize, len: usize) -> usize {
+        assert!(len > 0, "can not clamp into an empty dimension");
+        usize::try_from(index).map_or(0, |index| index.min(len - 1))
+    }
+}
+
+/// Wrap repeats the matrix, coordinates are taken modulo the amount of rows or columns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Wrap;
+
+impl IndexPolicy for Wrap {
+    fn resolve(index: isize, len: usize) -> usize {
+        assert!(len > 0, "can not wrap into an empty dimension");
+        match isize::try_from(len) {
+            Ok(len) => index.rem_euclid(len) as usize,
+            // Every isize is a valid index if len exceeds isize::MAX, only negative ones wrap.
+            Err(_) => (index as usize).wrapping_add(len),
+        }
+    }
+}
+
+/// Sample accesses elements by signed coordinates, mapping coordinates outside of the matrix
+/// with an [`IndexPolicy`].
+pub trait Sample<const R: usize, const C: usize, T> {
+    /// Returns the element at (row, col) after the policy I mapped both coordinates.
+    ///
+    /// # Panics
+    ///
+    /// If the policy panics, e.g. [`Panic`] for a coordinate outside of the matrix.
+    fn sample<I: IndexPolicy>(&self, row: isize, col: isize) -> &T;
+    /// Returns the element at (row, col) mutably after the policy I mapped both coordinates.
+    ///
+    /// # Panics
+    ///
+    /// If the policy panics, e.g. [`Panic`] for a coordinate outside of the matrix.
+    fn sample_mut<I: IndexPolicy>(&mut self, row: isize, col: isize) -> &mut T
+    where
+        Self: StorageMut<R, C, T>;
+}
+
+impl<M, const R: usize, const C: usize, T> Sample<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+{
+    fn sample<I: IndexPolicy>(&self, row: isize, col: isize) -> &T {
+        let location = (I::resolve(row, R), I::resolve(col, C));
+        &self.buffer()[M::Priority::offset(location, R, C)]
+    }
+
+    fn sample_mut<I: IndexPolicy>(&mut self, row: isize, col: isize) -> &mut T
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        let location = (I::resolve(row, R), I::resolve(col, C));
+        &mut self.buffer_mut()[M::Priority::offset(location, R, C)]
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{storage::Storage, Arithmetic, MatrixError, MemoryPriority, SemiringOps};
+use core::ops::{Add, Div, Mul, Sub};
+
+/// Convergence is the result of an iteration: the last iterate and how it got there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Convergence<T, const N: usize> {
+    /// The last iterate.
+    pub vector: [T; N],
+    /// The amount of iterations that were run.
+    pub iterations: usize,
+    /// The sum of the absolute differences between the last two iterates.
+    pub change: T,
+    /// True if change dropped to the tolerance within the allowed iterations.
+    pub converged: bool,
+}
+
+/// PowerIteration estimates eigenvectors of square matrices by repeated multiplication.
+///
+/// Every iteration takes O(R * R) time and two vectors on the stack. The start vector is fixed,
+/// so the same matrix always yields the same result.
+pub trait PowerIteration<const R: usize, const C: usize, T> {
+    /// Returns the stationary distribution of the Markov chain with the row stochastic
+    /// transition matrix, i.e. the probability vector pi with pi * P = pi.
+    ///
+    /// Starts at the uniform distribution and iterates the lazy chain (P + I) / 2, which has the
+    /// same stationary distributions as P but converges for periodic chains as well. Every
+    /// iterate is normalized to sum 1, so rounding does not accumulate. Stops once the change
+    /// between two iterates is at most tol or after max_iters iterations, whichever comes
+    /// first. A chain with more than one closed class has more than one stationary
+    /// distribution, the one reached depends on the start.
+    ///
+    /// Returns [`MatrixError::NotSquare`] if R != C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, PowerIteration };
+    /// let p = Stacktrix::<4, 2, 2, RowPrio, f64>::from_rows([[0.9, 0.1], [0.5, 0.5]]);
+    /// let pi = p.stationary_distribution(1e-12, 1000).unwrap();
+    /// assert!(pi.converged);
+    /// assert!((pi.vector[0] - 5.0 / 6.0).abs() < 1e-9);
+    /// assert!((pi.vector[1] - 1.0 / 6.0).abs() < 1e-9);
+    /// ```
+    fn stationary_distribution(
+        &self,
+        tol: T,
+        max_iters: usize,
+    ) -> Result<Convergence<T, R>, MatrixError>;
+    /// Returns the eigenvalue of the largest magnitude together with its eigenvector.
+    ///
+    /// Starts at the vector of ones and multiplies it with the matrix, see
+    /// [`SemiringOps::semiring_mul_vec`], scaling every iterate so that its element of the
+    /// largest magnitude is 1. The eigenvalue is the Rayleigh quotient x * A * x / (x * x) of
+    /// the last but one iterate x. Stops once the change between two iterates is at most tol or
+    /// after max_iters iterations, whichever comes first. If A maps an iterate to zero, the
+    /// eigenvalue 0 is returned with that iterate as converged.
+    ///
+    /// Power iteration does not converge if two eigenvalues of different sign share the largest
+    /// magnitude or the largest ones are complex, and converges slowly if the second largest
+    /// magnitude is close. A start vector orthogonal to the dominant eigenvector finds another
+    /// one.
+    ///
+    /// Returns [`MatrixError::NotSquare`] if R != C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, PowerIteration };
+    /// let m = Stacktrix::<4, 2, 2, ColumnPrio, f64>::from_rows([[2.0, 1.0], [1.0, 2.0]]);
+    /// let (value, pair) = m.dominant_eigenpair(1e-12, 100).unwrap();
+    /// assert!(pair.converged);
+    /// assert!((value - 3.0).abs() < 1e-9);
+    /// assert!((pair.vector[0] - 1.0).abs() < 1e-9 && (pair.vector[1] - 1.0).abs() < 1e-9);
+    /// ```
+    fn dominant_eigenpair(
+        &self,
+        tol: T,
+        max_iters: usize,
+    ) -> Result<(T, Convergence<T, R>), MatrixError>;
+}
+
+impl<M, const R: usize, const C: usize, T> PowerIteration<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+    T: Copy
+        + PartialOrd
+        + From<u8>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    fn stationary_distribution(
+        &self,
+        tol: T,
+        max_iters: usize,
+    ) -> Result<Convergence<T, R>, MatrixError> {
+        if R != C {
+            return Err(MatrixError::NotSquare { rows: R, cols: C });
+        }
+        let (zero, one, two) = (T::from(0), T::from(1), T::from(2));
+        let buffer = self.buffer();
+        let at = |row, col| buffer[M::Priority::offset((row, col), R, C)];
+        let n = (0..R).fold(zero, |n, _| n + one);
+        let mut result = Convergence {
+            vector: [one / n; R],
+            iterations: 0,
+            change: zero,
+            converged: R == 0,
+        };
+        while !result.converged && result.iterations < max_iters {
+            let pi = &result.vector;
+            let mut next: [T; R] = core::array::from_fn(|col| {
+                let moved = (0..R).fold(zero, |sum, row| sum + pi[row] * at(row, col));
+                (pi[col] + moved) / two
+            });
+            let total = next.iter().fold(zero, |sum, &p| sum + p);
+            next.iter_mut().for_each(|p| *p = *p / total);
+            result.change = next.iter().zip(pi).fold(zero, |sum, (&a, &b)| {
+                sum + if a < b { b - a } else { a - b }
+            });
+            result.vector = next;
+            result.iterations += 1;
+            result.converged = result.change <= tol;
+        }
+        Ok(result)
+    }
+
+    fn dominant_eigenpair(
+        &self,
+        tol: T,
+        max_iters: usize,
+    ) -> Result<(T, Convergence<T, R>), MatrixError> {
+        if R != C {
+            return Err(MatrixError::NotSquare { rows: R, cols: C });
+        }
+        let zero = T::from(0);
+        let abs = |el: T| if el < zero { zero - el } else { el };
+        let dot = |a: &[T; R], b: &[T; R]| a.iter().zip(b).fold(zero, |sum, (&a, &b)| sum + a * b);
+        let mut value = zero;
+        let mut result = Convergence {
+            vector: [T::from(1); R],
+            iterations: 0,
+            change: zero,
+            converged: R == 0,
+        };
+        while !result.converged && result.iterations < max_iters {
+            let x = &result.vector;
+            let y: [T; R] = self.semiring_mul_vec(&core::array::from_fn(|i| x[i]), Arithmetic);
+            value = dot(x, &y) / dot(x, x);
+            result.iterations += 1;
+            let scale = y
+                .iter()
+                .fold(zero, |max, &el| if abs(el) > abs(max) { el } else { max });
+            if scale == zero {
+                value = zero;
+                result.change = zero;
+                result.converged = true;
+                break;
+            }
+            let next = y.map(|el| el / scale);
+            result.change = next
+                .iter()
+                .zip(x)
+                .fold(zero, |sum, (&a, &b)| sum + abs(a - b));
+            result.vector = next;
+            result.converged = result.change <= tol;
+        }
+        Ok((value, result))
+    }
+}
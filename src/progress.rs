@@ -0,0 +1,37 @@
+/// Progress is passed to the callback of a long running operation after every step, e.g. a row
+/// of a product or a pivot of an elimination.
+///
+/// The callback returns [`ControlFlow::Break`](core::ops::ControlFlow::Break) to cancel the
+/// operation, which stops before the next step.
+///
+/// # Examples
+///
+/// ```
+/// # use core::ops::ControlFlow;
+/// # use mightrix::{ Stacktrix, RowPrio, Arithmetic, Progress, SemiringOps };
+/// let a = Stacktrix::<4, 2, 2, RowPrio, i32>::from_rows([[1, 2], [3, 4]]);
+/// let mut product = Stacktrix::<4, 2, 2, RowPrio, i32>::zeros();
+/// let mut reports = Vec::new();
+/// let result = a.semiring_mul_into_with_progress(&a, &mut product, Arithmetic, |progress| {
+///     reports.push(progress);
+///     ControlFlow::Continue(())
+/// });
+/// assert_eq!(result, Ok(()));
+/// assert_eq!(reports.last(), Some(&Progress { done: 2, total: Some(2) }));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// The amount of steps that are done.
+    pub done: usize,
+    /// The amount of steps of the operation, None if it is not known in advance.
+    pub total: Option<usize>,
+}
+
+impl Progress {
+    pub(crate) fn of(done: usize, total: usize) -> Self {
+        Self {
+            done,
+            total: Some(total),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Generators for fuzzing and property tests, enabled by the `arbitrary` and `proptest`
+//! features.
+//!
+//! Both generate the elements of a [`Stacktrix`] in memory order, the shape and the memory
+//! priority are given by the type.
+#[cfg(feature = "proptest")]
+use crate::MemoryPriority;
+use crate::Stacktrix;
+
+#[cfg(feature = "arbitrary")]
+impl<'a, const S: usize, const R: usize, const C: usize, P, T> arbitrary::Arbitrary<'a>
+    for Stacktrix<S, R, C, P, T>
+where
+    T: arbitrary::Arbitrary<'a> + Copy,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::with_values(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[T; S] as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+/// The proptest strategy returned by [`Stacktrix::strategy`], enabled by the `proptest` feature.
+#[cfg(feature = "proptest")]
+pub type StacktrixStrategy<E, const S: usize, const R: usize, const C: usize, P, T> =
+    proptest::strategy::Map<
+        proptest::array::UniformArrayStrategy<E, [T; S]>,
+        fn([T; S]) -> Stacktrix<S, R, C, P, T>,
+    >;
+
+#[cfg(feature = "proptest")]
+impl<const S: usize, const R: usize, const C: usize, P, T> proptest::arbitrary::Arbitrary
+    for Stacktrix<S, R, C, P, T>
+where
+    P: MemoryPriority,
+    T: proptest::arbitrary::Arbitrary + Copy,
+{
+    type Parameters = T::Parameters;
+
+    type Strategy = StacktrixStrategy<T::Strategy, S, R, C, P, T>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        Self::strategy(proptest::arbitrary::any_with::<T>(args))
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<const S: usize, const R: usize, const C: usize, P, T> Stacktrix<S, R, C, P, T>
+where
+    P: MemoryPriority,
+    T: Copy + core::fmt::Debug,
+{
+    /// Returns a proptest strategy for matrices whose elements are drawn from element, enabled
+    /// by the `proptest` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, Reduce };
+    /// use proptest::prelude::*;
+    ///
+    /// proptest!(|(m in Stacktrix::<6, 2, 3, RowPrio, i32>::strategy(-10..10))| {
+    ///     prop_assert!(m.sum().abs() < 60);
+    /// });
+    /// ```
+    pub fn strategy<E>(element: E) -> StacktrixStrategy<E, S, R, C, P, T>
+    where
+        E: proptest::strategy::Strategy<Value = T>,
+    {
+        proptest::strategy::Strategy::prop_map(
+            proptest::array::uniform::<E, S>(element),
+            Self::with_values,
+        )
+    }
+}
@@ -0,0 +1,204 @@
+#[cfg(feature = "rand")]
+use crate::Stacktrix;
+use crate::{storage::StorageMut, MemoryPriority};
+#[cfg(feature = "rand")]
+use rand::{
+    distr::{Distribution, StandardUniform},
+    Rng,
+};
+
+/// Returns the index-th value of the SplitMix64 sequence started at seed.
+///
+/// Every value only depends on seed and index, so a matrix can be filled in any order.
+pub(crate) const fn splitmix64(seed: u64, index: u64) -> u64 {
+    let mut z = seed.wrapping_add(index.wrapping_add(1).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// PseudoRandom converts the 64 random bits of the built-in generator into an element.
+///
+/// The conversion only uses integer arithmetic and exact float conversions, so the same seed
+/// yields the same values on every platform. It is not suited for cryptography.
+pub trait PseudoRandom {
+    /// Converts 64 uniformly distributed bits into a value.
+    fn from_random_bits(bits: u64) -> Self;
+}
+
+macro_rules! pseudo_random_int {
+    ($($t:ty),*) => {
+        $(
+            /// Keeps the low bits, all values are equally likely.
+            impl PseudoRandom for $t {
+                fn from_random_bits(bits: u64) -> Self {
+                    bits as $t
+                }
+            }
+        )*
+    };
+}
+
+pseudo_random_int!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+/// Uniformly distributed in [0, 1) with 24 random bits.
+impl PseudoRandom for f32 {
+    fn from_random_bits(bits: u64) -> Self {
+        (bits >> 40) as f32 / (1u32 << 24) as f32
+    }
+}
+
+/// Uniformly distributed in [0, 1) with 53 random bits.
+impl PseudoRandom for f64 {
+    fn from_random_bits(bits: u64) -> Self {
+        (bits >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+impl PseudoRandom for bool {
+    fn from_random_bits(bits: u64) -> Self {
+        bits >> 63 == 1
+    }
+}
+
+/// PseudoRandomFill fills matrices with reproducible values, e.g. as test fixtures.
+pub trait PseudoRandomFill<const R: usize, const C: usize, T> {
+    /// Overwrites every element with a value derived from seed and the logical location of the
+    /// element, so both memory priorities hold the same matrix for the same seed. See
+    /// [`Stacktrix::pseudo_random`](crate::Stacktrix::pseudo_random).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, Stacktrix, ColumnPrio, RowPrio, PseudoRandomFill };
+    /// let mut data = vec![0u32; 6];
+    /// let mut m = Reftrix::<2, 3, ColumnPrio, u32>::from_values(&mut data[..]);
+    /// m.fill_pseudo_random(7);
+    /// let expected = Stacktrix::<6, 2, 3, RowPrio, u32>::pseudo_random(7);
+    /// assert_eq!(m, expected);
+    /// ```
+    fn fill_pseudo_random(&mut self, seed: u64);
+}
+
+impl<M, const R: usize, const C: usize, T> PseudoRandomFill<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+    T: PseudoRandom,
+{
+    fn fill_pseudo_random(&mut self, seed: u64) {
+        let buffer = self.buffer_mut();
+        for row in 0..R {
+            for col in 0..C {
+                let bits = splitmix64(seed, (row * C + col) as u64);
+                buffer[M::Priority::offset((row, col), R, C)] = T::from_random_bits(bits);
+            }
+        }
+    }
+}
+
+/// RandomFill fills matrices from a [`rand`] random number generator, enabled by the `rand`
+/// feature.
+///
+/// The elements are drawn in logical row major order, so both memory priorities hold the same
+/// matrix for generators in the same state.
+#[cfg(feature = "rand")]
+pub trait RandomFill<const R: usize, const C: usize, T> {
+    /// Overwrites every element with a value of the [`StandardUniform`] distribution, e.g.
+    /// floats in [0, 1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, RandomFill };
+    /// # use rand::{ rngs::SmallRng, SeedableRng };
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let mut m = Stacktrix::<6, 2, 3, RowPrio, f32>::zeros();
+    /// m.fill_random(&mut rng);
+    /// assert!(m.as_slice().iter().all(|el| (0.0..1.0).contains(el)));
+    /// ```
+    fn fill_random<G>(&mut self, rng: &mut G)
+    where
+        G: Rng + ?Sized,
+        StandardUniform: Distribution<T>;
+    /// Overwrites every element with a value of distribution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, RandomFill };
+    /// # use rand::{ distr::Uniform, rngs::SmallRng, SeedableRng };
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let mut m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::zeros();
+    /// m.fill_random_with(&Uniform::new_inclusive(-3, 3).unwrap(), &mut rng);
+    /// assert!(m.as_slice().iter().all(|el| (-3..=3).contains(el)));
+    /// ```
+    fn fill_random_with<D, G>(&mut self, distribution: &D, rng: &mut G)
+    where
+        D: Distribution<T>,
+        G: Rng + ?Sized;
+}
+
+#[cfg(feature = "rand")]
+impl<M, const R: usize, const C: usize, T> RandomFill<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+{
+    fn fill_random<G>(&mut self, rng: &mut G)
+    where
+        G: Rng + ?Sized,
+        StandardUniform: Distribution<T>,
+    {
+        self.fill_random_with(&StandardUniform, rng);
+    }
+
+    fn fill_random_with<D, G>(&mut self, distribution: &D, rng: &mut G)
+    where
+        D: Distribution<T>,
+        G: Rng + ?Sized,
+    {
+        let buffer = self.buffer_mut();
+        for row in 0..R {
+            for col in 0..C {
+                buffer[M::Priority::offset((row, col), R, C)] = distribution.sample(rng);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<const S: usize, const R: usize, const C: usize, P, T> Stacktrix<S, R, C, P, T>
+where
+    P: MemoryPriority,
+    T: Copy,
+{
+    /// Constructs a Stacktrix of values of the [`StandardUniform`] distribution, see
+    /// [`RandomFill`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, RowPrio };
+    /// # use rand::{ rngs::SmallRng, SeedableRng };
+    /// let a = Stacktrix::<6, 2, 3, RowPrio, u64>::random(&mut SmallRng::seed_from_u64(1));
+    /// let b = Stacktrix::<6, 2, 3, ColumnPrio, u64>::random(&mut SmallRng::seed_from_u64(1));
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn random<G>(rng: &mut G) -> Self
+    where
+        G: Rng + ?Sized,
+        StandardUniform: Distribution<T>,
+    {
+        Self::random_with(&StandardUniform, rng)
+    }
+
+    /// Constructs a Stacktrix of values of distribution, see [`RandomFill`].
+    pub fn random_with<D, G>(distribution: &D, rng: &mut G) -> Self
+    where
+        D: Distribution<T>,
+        G: Rng + ?Sized,
+    {
+        let rows: [[T; C]; R] =
+            core::array::from_fn(|_| core::array::from_fn(|_| distribution.sample(rng)));
+        Self::from_rows(rows)
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{storage::Storage, Elements, MemoryPriority};
+use core::ops::Add;
+
+/// Reduce folds the elements of a matrix into a single value or one value per row or column.
+///
+/// The elements are visited in logical row major order, so both memory priorities round
+/// floating point sums the same way.
+pub trait Reduce<const R: usize, const C: usize, T> {
+    /// Folds every element into an accumulator, starting with init.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Reduce };
+    /// let m = Stacktrix::<4, 2, 2, ColumnPrio, u8>::from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(m.fold(0u32, |acc, &el| acc * 10 + u32::from(el)), 1234);
+    /// ```
+    fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T) -> B;
+    /// Returns the sum of all elements, 0 for an empty matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, Reduce };
+    /// let m = Stacktrix::<6, 2, 3, RowPrio, i32>::from_rows([[1, -2, 3], [4, 5, -6]]);
+    /// assert_eq!(m.sum(), 5);
+    /// ```
+    fn sum(&self) -> T
+    where
+        T: Copy + From<u8> + Add<Output = T>;
+    /// Returns the smallest element, the first one if several compare equal. Elements that are
+    /// not comparable to themselves, e.g. NaN, are skipped. None if no element is left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, Reduce };
+    /// let m = Stacktrix::<4, 2, 2, RowPrio, f32>::from_rows([[1.5, -2.0], [3.0, 0.0]]);
+    /// assert_eq!(m.min(), Some(-2.0));
+    /// ```
+    fn min(&self) -> Option<T>
+    where
+        T: Copy + PartialOrd;
+    /// Returns the largest element, the first one if several compare equal. Elements that are
+    /// not comparable to themselves, e.g. NaN, are skipped. None if no element is left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Reduce };
+    /// let m = Stacktrix::<4, 2, 2, ColumnPrio, u16>::from_rows([[1, 7], [3, 4]]);
+    /// assert_eq!(m.max(), Some(7));
+    /// ```
+    fn max(&self) -> Option<T>
+    where
+        T: Copy + PartialOrd;
+    /// Returns the sum of every row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Reduce };
+    /// let m = Stacktrix::<6, 2, 3, ColumnPrio, u32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(m.row_sums(), [6, 15]);
+    /// ```
+    fn row_sums(&self) -> [T; R]
+    where
+        T: Copy + From<u8> + Add<Output = T>;
+    /// Returns the sum of every column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, Reduce };
+    /// let m = Stacktrix::<6, 2, 3, RowPrio, u32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(m.col_sums(), [5, 7, 9]);
+    /// ```
+    fn col_sums(&self) -> [T; C]
+    where
+        T: Copy + From<u8> + Add<Output = T>;
+}
+
+impl<M, const R: usize, const C: usize, T> Reduce<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+{
+    fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        self.iter().fold(init, f)
+    }
+
+    fn sum(&self) -> T
+    where
+        T: Copy + From<u8> + Add<Output = T>,
+    {
+        self.fold(T::from(0), |sum, &el| sum + el)
+    }
+
+    fn min(&self) -> Option<T>
+    where
+        T: Copy + PartialOrd,
+    {
+        self.fold(None, |min, &el| match min {
+            _ if el.partial_cmp(&el).is_none() => min,
+            Some(min) if min <= el => Some(min),
+            _ => Some(el),
+        })
+    }
+
+    fn max(&self) -> Option<T>
+    where
+        T: Copy + PartialOrd,
+    {
+        self.fold(None, |max, &el| match max {
+            _ if el.partial_cmp(&el).is_none() => max,
+            Some(max) if max >= el => Some(max),
+            _ => Some(el),
+        })
+    }
+
+    fn row_sums(&self) -> [T; R]
+    where
+        T: Copy + From<u8> + Add<Output = T>,
+    {
+        let buffer = self.buffer();
+        core::array::from_fn(|row| {
+            (0..C).fold(T::from(0), |sum, col| {
+                sum + buffer[M::Priority::offset((row, col), R, C)]
+            })
+        })
+    }
+
+    fn col_sums(&self) -> [T; C]
+    where
+        T: Copy + From<u8> + Add<Output = T>,
+    {
+        let buffer = self.buffer();
+        core::array::from_fn(|col| {
+            (0..R).fold(T::from(0), |sum, row| {
+                sum + buffer[M::Priority::offset((row, col), R, C)]
+            })
+        })
+    }
+}
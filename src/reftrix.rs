@@ -1,21 +1,43 @@
 use crate::{
-    ColumnPrio, ColumnPrioMatrix, IntermittentSlice, IntermittentSliceMut, IterIntermittentSlices,
-    IterMutIntermittentSlices, IterSlices, IterSlicesMut, Position, RowPrio, RowPrioMatrix,
+    display::display_cell,
+    storage::{logical_eq, logical_hash, Storage, StorageMut},
+    Grid, MatrixError, Stacktrix,
+};
+use core::{
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
 };
-use std::{fmt::Debug, marker::PhantomData};
 
 /// Reftrix allows a mutable slice to be used as a Matrix.
 ///
 /// A Reftrix matrix operates on a mutable slice. The number of rows is indicated by R the number
 /// of columns by C. MemoryPriority indicates how the underlying memory is interpreted. (see
-/// [`ColumnPrio`], [`RowPrio`])
+/// [`ColumnPrio`](crate::ColumnPrio), [`RowPrio`](crate::RowPrio))
+///
+/// Reftrix never allocates, the slice may come from any allocator, e.g. an arena or bump
+/// allocator that backs the temporary matrices of a hot loop, or from a buffer that is reused
+/// for every iteration.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix };
+/// let mut scratch = [0u32; 4];
+/// for i in 0..3 {
+///     let mut m = Reftrix::<2, 2, RowPrio, u32>::from_values(&mut scratch[..]);
+///     m.fill_row(0, &[i, i]);
+///     assert_eq!(m.get_row(0), &[i, i]);
+/// }
+/// ```
 pub struct Reftrix<'a, const R: usize, const C: usize, MemoryPriority, T> {
     inner: &'a mut [T],
     _prio: PhantomData<MemoryPriority>,
 }
 
 impl<'a, const R: usize, const C: usize, MemoryPriority, T> Reftrix<'a, R, C, MemoryPriority, T> {
-    /// Constructs a Reftrix from a mutable slice with a [`ColumnPrio`] memory interpretation.
+    /// Constructs a Reftrix from a mutable slice with a [`ColumnPrio`](crate::ColumnPrio) memory
+    /// interpretation.
     ///
     /// # Panics
     ///
@@ -36,262 +58,252 @@ impl<'a, const R: usize, const C: usize, MemoryPriority, T> Reftrix<'a, R, C, Me
             _prio: PhantomData,
         }
     }
-}
-
-impl<'a, 'r, const R: usize, const C: usize, T> ColumnPrioMatrix<'a, R, C, T>
-    for Reftrix<'a, R, C, ColumnPrio, T>
-where
-    Self: 'a,
-    'a: 'r,
-    T: Copy + Default + Debug,
-{
-    fn insert(&mut self, location: Position, value: T) {
-        self.get_mut_column(location.1)[location.0] = value;
-    }
-
-    fn get(&'a self, location: Position) -> &'a T {
-        &self.get_column(location.1)[location.0]
-    }
-
-    fn get_mut(&'a mut self, location: Position) -> &'a mut T {
-        &mut self.get_mut_column(location.1)[location.0]
-    }
-
-    fn fill_col(&mut self, col: usize, data: &[T]) {
-        assert_eq!(data.len(), R);
-        let start = col * C;
-        self.inner[start..start + C].copy_from_slice(data);
-    }
-
-    fn fill_row(&mut self, row: usize, data: &[T]) {
-        assert_eq!(data.len(), C);
-        for (dst, src) in self.get_mut_row(row).into_iter().zip(data.iter()) {
-            *dst = *src;
-        }
-    }
-
-    fn get_column(&self, col: usize) -> &[T] {
-        assert!(
-            col < C,
-            "Column: {} out of bounds {}, be carefull columns are 0 indexed.",
-            col,
-            C
-        );
-        let start = col * R;
-        &self.inner[start..start + R]
-    }
-
-    fn get_mut_column(&mut self, col: usize) -> &mut [T] {
-        assert!(
-            col < C,
-            "Column: {} out of bounds {}, be carefull columns are 0 indexed.",
-            col,
-            C
-        );
-        let start = col * R;
-        &mut self.inner[start..start + R]
-    }
 
-    fn get_row(&self, row: usize) -> IntermittentSlice<'_, R, C, T> {
-        assert!(
-            row < R,
-            "Row: {} out of bounds {}, be carefull rows are 0 indexed.",
-            row,
-            R
-        );
-        IntermittentSlice {
-            start: &self.inner[row],
+    /// Constructs a Reftrix from a mutable slice, returning a [`MatrixError::SizeMismatch`]
+    /// instead of panicking if the slice is not of size R * C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, MatrixError };
+    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
+    /// assert!(Reftrix::<4, 4, ColumnPrio, u8>::try_from_values(&mut data[..]).is_ok());
+    /// assert_eq!(
+    ///     Reftrix::<4, 4, ColumnPrio, u8>::try_from_values(&mut data[..15]).err(),
+    ///     Some(MatrixError::SizeMismatch { expected: 16, got: 15 })
+    /// );
+    /// ```
+    pub fn try_from_values(inner_values: &'a mut [T]) -> Result<Self, MatrixError> {
+        if inner_values.len() != R * C {
+            return Err(MatrixError::SizeMismatch {
+                expected: R * C,
+                got: inner_values.len(),
+            });
         }
+        Ok(Self {
+            inner: inner_values,
+            _prio: PhantomData,
+        })
     }
 
-    fn get_mut_row(&mut self, row: usize) -> IntermittentSliceMut<'_, R, C, T> {
-        assert!(
-            row < R,
-            "Row: {} out of bounds {}, be carefull rows are 0 indexed.",
-            row,
-            R
-        );
-        IntermittentSliceMut {
-            start: &mut self.inner[row],
+    /// Constructs a Reftrix that views a fixed size array in place, unlike
+    /// [`Stacktrix::with_values`] no elements are copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix };
+    /// let mut data = [1, 2, 3, 4, 5, 6];
+    /// let mut m = Reftrix::<2, 3, RowPrio, u8>::from_array_mut(&mut data);
+    /// m.fill_row(1, &[0, 0, 0]);
+    /// assert_eq!(data, [1, 2, 3, 0, 0, 0]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use mightrix::{ Reftrix, RowPrio };
+    /// let mut data = [1, 2, 3, 4, 5, 6];
+    /// let m = Reftrix::<2, 2, RowPrio, u8>::from_array_mut(&mut data);
+    /// ```
+    pub fn from_array_mut<const S: usize>(inner_values: &'a mut [T; S]) -> Self {
+        let () = ArrayShape::<S, R, C>::CHECK;
+        Self {
+            inner: inner_values,
+            _prio: PhantomData,
         }
     }
 
-    fn rows(&self) -> IterIntermittentSlices<'_, R, C, T> {
-        IterIntermittentSlices {
-            slice_index: 0,
-            matrix_buffer: self.inner,
-        }
+    /// Returns the underlying slice in memory order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, RowPrioMatrix };
+    /// let mut data = vec![1, 2, 3, 4];
+    /// let mut m = Reftrix::<2, 2, RowPrio, u8>::from_values(&mut data[..]);
+    /// m.fill_row(0, &[7, 7]);
+    /// assert_eq!(m.as_slice(), &[7, 7, 3, 4]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        self.inner
     }
 
-    fn rows_mut(&mut self) -> IterMutIntermittentSlices<'_, R, C, T> {
-        IterMutIntermittentSlices {
-            slice_index: 0,
-            matrix_buffer: self.inner,
-        }
+    /// Returns the underlying slice in memory order mutably.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.inner
     }
 
-    fn cols(&self) -> IterSlices<'_, R, C, T> {
-        IterSlices {
-            matrix_buffer: self.inner,
-        }
+    /// Consumes the matrix and returns the slice it was constructed from, with the full
+    /// lifetime 'a.
+    pub fn into_inner(self) -> &'a mut [T] {
+        self.inner
     }
 
-    fn cols_mut(&mut self) -> IterSlicesMut<'_, R, C, T> {
-        IterSlicesMut {
-            matrix_buffer: self.inner,
-        }
+    /// Copies the matrix into a [`Stacktrix`] with the same memory priority.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, Stacktrix, ColumnPrio };
+    /// let mut data = vec![1, 2, 3, 4];
+    /// let m = Reftrix::<2, 2, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// let owned: Stacktrix<4, 2, 2, ColumnPrio, u8> = m.to_stacktrix();
+    /// assert_eq!(owned.as_slice(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn to_stacktrix<const S: usize>(&self) -> Stacktrix<S, R, C, MemoryPriority, T>
+    where
+        T: Copy,
+    {
+        Stacktrix::from_values(self.inner)
     }
 
-    fn apply_all(&mut self, f: fn(&mut T)) {
-        for el in self.inner.iter_mut() {
-            f(el);
-        }
+    /// Reinterprets the slice as a matrix of shape R2 x C2. The elements keep their position in
+    /// memory, see [`Stacktrix::reshape`].
+    ///
+    /// # Panics
+    ///
+    /// If R2 * C2 differs from R * C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, ColumnPrioMatrix };
+    /// let mut data = vec![1, 2, 3, 4, 5, 6];
+    /// let m = Reftrix::<2, 3, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// let m = m.reshape::<3, 2>();
+    /// assert_eq!(m.get_column(1), &[4, 5, 6]);
+    /// ```
+    pub fn reshape<const R2: usize, const C2: usize>(
+        self,
+    ) -> Reftrix<'a, R2, C2, MemoryPriority, T> {
+        Reftrix::from_values(self.inner)
     }
+}
 
-    fn pretty_print(&self) {
-        let strings: Vec<Vec<String>> = (0..4)
-            .map(|i| {
-                self.get_row(i)
-                    .into_iter()
-                    .map(|el| format!("{:02x?}", el))
-                    .collect::<Vec<String>>()
-            })
-            .collect();
-        for v in strings {
-            for (i, s) in v.iter().enumerate() {
-                print!("{}", s);
-                if i != C - 1 {
-                    print!("-")
-                }
-            }
-            println!();
-        }
+impl<'a, const S: usize, const R: usize, const C: usize, MemoryPriority, T> From<&'a mut [T; S]>
+    for Reftrix<'a, R, C, MemoryPriority, T>
+{
+    /// See [`Reftrix::from_array_mut`].
+    fn from(inner_values: &'a mut [T; S]) -> Self {
+        Self::from_array_mut(inner_values)
     }
 }
 
-impl<'a, const R: usize, const C: usize, T> RowPrioMatrix<'a, R, C, T>
-    for Reftrix<'a, R, C, RowPrio, T>
+impl<'a, const S: usize, const R: usize, const C: usize, MemoryPriority, T>
+    From<&Reftrix<'a, R, C, MemoryPriority, T>> for Stacktrix<S, R, C, MemoryPriority, T>
 where
-    Self: 'a,
-    T: Copy + Default + Debug,
+    T: Copy,
 {
-    fn insert(&mut self, location: Position, value: T) {
-        self.get_mut_row(location.0)[location.1] = value;
-    }
-
-    fn get(&self, location: Position) -> &T {
-        &self.get_row(location.0)[location.1]
+    /// See [`Reftrix::to_stacktrix`].
+    fn from(matrix: &Reftrix<'a, R, C, MemoryPriority, T>) -> Self {
+        matrix.to_stacktrix()
     }
+}
 
-    fn get_mut(&mut self, location: Position) -> &mut T {
-        &mut self.get_mut_row(location.0)[location.1]
+impl<'a, const S: usize, const R: usize, const C: usize, MemoryPriority, T>
+    From<&'a mut Stacktrix<S, R, C, MemoryPriority, T>> for Reftrix<'a, R, C, MemoryPriority, T>
+{
+    /// See [`Stacktrix::as_reftrix`].
+    fn from(matrix: &'a mut Stacktrix<S, R, C, MemoryPriority, T>) -> Self {
+        matrix.as_reftrix()
     }
+}
 
-    fn fill_row(&mut self, row: usize, data: &[T]) {
-        assert_eq!(data.len(), C);
-        assert!(row < R);
-        let start = row * C;
-        self.inner[start..start + C].copy_from_slice(data);
-    }
+/// Checks the size of an array viewed as an R x C matrix.
+struct ArrayShape<const S: usize, const R: usize, const C: usize>;
 
-    fn fill_col(&'a mut self, col: usize, data: &[T]) {
-        assert_eq!(data.len(), R);
-        assert!(col < C);
-        for (dst, src) in self.get_mut_column(col).into_iter().zip(data.iter()) {
-            *dst = *src;
-        }
-    }
+impl<const S: usize, const R: usize, const C: usize> ArrayShape<S, R, C> {
+    /// Evaluated by [`Reftrix::from_array_mut`], which turns an array with S != R * C into a
+    /// compile error.
+    const CHECK: () = assert!(S == R * C, "a Reftrix has to view S == R * C elements");
+}
 
-    fn get_column(&self, col: usize) -> IntermittentSlice<'_, R, C, T> {
-        assert!(
-            col < C,
-            "Column: {} out of bounds {}, be carefull columns are 0 indexed.",
-            col,
-            C
-        );
-        IntermittentSlice {
-            start: &self.inner[col],
-        }
-    }
+// SAFETY: the constructors only accept slices of R * C elements.
+unsafe impl<'a, const R: usize, const C: usize, MemoryPriority, T> Storage<R, C, T>
+    for Reftrix<'a, R, C, MemoryPriority, T>
+where
+    MemoryPriority: crate::MemoryPriority,
+{
+    type Priority = MemoryPriority;
 
-    fn get_mut_column(&mut self, col: usize) -> IntermittentSliceMut<'_, R, C, T> {
-        assert!(
-            col < C,
-            "Column: {} out of bounds {}, be carefull columns are 0 indexed.",
-            col,
-            C
-        );
-        IntermittentSliceMut {
-            start: &mut self.inner[col],
-        }
+    fn buffer(&self) -> &[T] {
+        self.inner
     }
+}
 
-    fn get_row(&self, row: usize) -> &[T] {
-        assert!(
-            row < R,
-            "Row: {} out of bounds {}, be carefull rows are 0 indexed.",
-            row,
-            R
-        );
-        let start = row * C;
-        &self.inner[start..start + C]
+// SAFETY: buffer_mut returns the same elements as buffer.
+unsafe impl<'a, const R: usize, const C: usize, MemoryPriority, T> StorageMut<R, C, T>
+    for Reftrix<'a, R, C, MemoryPriority, T>
+where
+    MemoryPriority: crate::MemoryPriority,
+{
+    fn buffer_mut(&mut self) -> &mut [T] {
+        self.inner
     }
+}
 
-    fn get_mut_row(&mut self, row: usize) -> &mut [T] {
-        assert!(
-            row < R,
-            "Row: {} out of bounds {}, be carefull rows are 0 indexed.",
-            row,
-            R
-        );
-        let start = row * C;
-        &mut self.inner[start..start + C]
+impl<'a, const R: usize, const C: usize, MemoryPriority, T> Display
+    for Reftrix<'a, R, C, MemoryPriority, T>
+where
+    MemoryPriority: crate::MemoryPriority,
+    T: Display,
+{
+    /// Formats the matrix as a grid, the precision is applied to every cell.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Grid::<R, C, MemoryPriority, T, _>::new(self.inner, display_cell(f.precision())).fmt(f)
     }
+}
 
-    fn rows(&self) -> IterSlices<'_, R, C, T> {
-        IterSlices {
-            matrix_buffer: self.inner,
-        }
+impl<'a, const R: usize, const C: usize, MemoryPriority, T> Debug
+    for Reftrix<'a, R, C, MemoryPriority, T>
+where
+    MemoryPriority: crate::MemoryPriority,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Grid::<R, C, MemoryPriority, T, _>::new(self.inner, |el, f| Debug::fmt(el, f)).fmt(f)
     }
+}
 
-    fn rows_mut(&mut self) -> IterSlicesMut<'_, R, C, T> {
-        IterSlicesMut {
-            matrix_buffer: self.inner,
-        }
+impl<'a, 'b, const R: usize, const C: usize, P, Q, T> PartialEq<Reftrix<'b, R, C, Q, T>>
+    for Reftrix<'a, R, C, P, T>
+where
+    P: crate::MemoryPriority,
+    Q: crate::MemoryPriority,
+    T: PartialEq,
+{
+    /// Matrices are equal if they hold equal elements at every location, independent of their
+    /// memory priorities.
+    fn eq(&self, other: &Reftrix<'b, R, C, Q, T>) -> bool {
+        logical_eq(self, other)
     }
+}
 
-    fn cols(&self) -> IterIntermittentSlices<'_, R, C, T> {
-        IterIntermittentSlices {
-            slice_index: 0,
-            matrix_buffer: self.inner,
-        }
+impl<'a, const S: usize, const R: usize, const C: usize, P, Q, T>
+    PartialEq<Stacktrix<S, R, C, Q, T>> for Reftrix<'a, R, C, P, T>
+where
+    P: crate::MemoryPriority,
+    Q: crate::MemoryPriority,
+    T: PartialEq,
+{
+    fn eq(&self, other: &Stacktrix<S, R, C, Q, T>) -> bool {
+        logical_eq(self, other)
     }
+}
 
-    fn cols_mut(&mut self) -> IterMutIntermittentSlices<'_, R, C, T> {
-        IterMutIntermittentSlices {
-            slice_index: 0,
-            matrix_buffer: self.inner,
-        }
-    }
-    fn apply_all(&mut self, f: fn(&mut T)) {
-        for el in self.inner.iter_mut() {
-            f(el);
-        }
-    }
+impl<'a, const R: usize, const C: usize, P, T> Eq for Reftrix<'a, R, C, P, T>
+where
+    P: crate::MemoryPriority,
+    T: Eq,
+{
+}
 
-    fn pretty_print(&self) {
-        let strings: Vec<String> = self.inner.iter().map(|el| format!("{:02x?}", el)).collect();
-        let _column_width = strings.iter().map(|el| el.len()).max();
-        let mut index = 0;
-        for _ in 0..R {
-            for i in 0..C {
-                print!("{}", strings[index]);
-                if i != C - 1 {
-                    print!("-")
-                }
-                index += 1;
-            }
-            println!();
-        }
+impl<'a, const R: usize, const C: usize, P, T> Hash for Reftrix<'a, R, C, P, T>
+where
+    P: crate::MemoryPriority,
+    T: Hash,
+{
+    /// Hashes the elements in logical order, consistent with equality across memory priorities.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        logical_hash(self, state);
     }
 }
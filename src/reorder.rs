@@ -0,0 +1,112 @@
+use crate::{
+    storage::{assert_col, assert_row, StorageMut},
+    MemoryPriority,
+};
+
+/// Reorder moves rows and columns to a new position, keeping the order of the others, like
+/// dragging a column of a table.
+///
+/// Only the rows / columns between the old and the new position are rotated. Lanes along the
+/// memory priority are rotated as one continuous block, lanes across it element wise within
+/// each continuous lane.
+pub trait Reorder<const R: usize, const C: usize, T> {
+    /// Moves row from to row to, the rows in between shift by one towards from.
+    ///
+    /// # Panics
+    ///
+    /// If from or to is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Reorder, Export };
+    /// let mut m = Stacktrix::<8, 4, 2, ColumnPrio, u8>::from_rows([[0, 0], [1, 1], [2, 2], [3, 3]]);
+    /// m.move_row(0, 2);
+    /// assert_eq!(m.to_rows(), [[1, 1], [2, 2], [0, 0], [3, 3]]);
+    /// m.move_row(3, 0);
+    /// assert_eq!(m.to_rows(), [[3, 3], [1, 1], [2, 2], [0, 0]]);
+    /// ```
+    fn move_row(&mut self, from: usize, to: usize);
+    /// Moves column from to column to, the columns in between shift by one towards from.
+    ///
+    /// # Panics
+    ///
+    /// If from or to is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, Reorder, Export };
+    /// let mut m = Stacktrix::<8, 2, 4, RowPrio, char>::from_rows([
+    ///     ['a', 'b', 'c', 'd'],
+    ///     ['e', 'f', 'g', 'h'],
+    /// ]);
+    /// m.move_col(3, 1);
+    /// assert_eq!(m.to_rows(), [['a', 'd', 'b', 'c'], ['e', 'h', 'f', 'g']]);
+    /// ```
+    fn move_col(&mut self, from: usize, to: usize);
+}
+
+impl<M, const R: usize, const C: usize, T> Reorder<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+{
+    fn move_row(&mut self, from: usize, to: usize) {
+        assert_row::<R>(from);
+        assert_row::<R>(to);
+        move_lane(
+            self.buffer_mut(),
+            from,
+            to,
+            M::Priority::row_stride(R, C),
+            M::Priority::col_stride(R, C),
+            C,
+        );
+    }
+
+    fn move_col(&mut self, from: usize, to: usize) {
+        assert_col::<C>(from);
+        assert_col::<C>(to);
+        move_lane(
+            self.buffer_mut(),
+            from,
+            to,
+            M::Priority::col_stride(R, C),
+            M::Priority::row_stride(R, C),
+            R,
+        );
+    }
+}
+
+/// Moves the lane from to to, lanes start stride apart and hold len elements that are
+/// element_stride apart.
+fn move_lane<T>(
+    buffer: &mut [T],
+    from: usize,
+    to: usize,
+    stride: usize,
+    element_stride: usize,
+    len: usize,
+) {
+    if from == to {
+        return;
+    }
+    let (lo, hi) = (from.min(to), from.max(to));
+    let rotate = |range: &mut [T], mid: usize| {
+        if from < to {
+            range.rotate_left(mid);
+        } else {
+            range.rotate_right(mid);
+        }
+    };
+    if stride == 1 {
+        // The lanes lie next to each other within every continuous lane across them.
+        for k in 0..len {
+            let base = k * element_stride;
+            rotate(&mut buffer[base + lo..=base + hi], 1);
+        }
+    } else {
+        // The lanes are continuous and follow each other.
+        rotate(&mut buffer[lo * stride..(hi + 1) * stride], stride);
+    }
+}
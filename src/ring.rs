@@ -0,0 +1,108 @@
+use crate::{storage::StorageMut, MemoryPriority};
+use core::marker::PhantomData;
+
+/// RowRing uses the rows of a matrix as a ring buffer, e.g. a sliding window over the R latest
+/// samples of C channels.
+///
+/// Pushing a row overwrites the oldest one once all rows are in use, no rows are moved.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ RowRing, Stacktrix, RowPrio };
+/// let mut window = RowRing::new(Stacktrix::<6, 3, 2, RowPrio, i32>::zeros());
+/// for sample in 1..=4 {
+///     window.push_row_rotating(&[sample, -sample]);
+/// }
+/// let rows: Vec<_> = window.rows_in_order().collect();
+/// assert_eq!(rows, vec![[2, -2], [3, -3], [4, -4]]);
+/// ```
+pub struct RowRing<M, const R: usize, const C: usize, T> {
+    matrix: M,
+    oldest: usize,
+    len: usize,
+    _elements: PhantomData<T>,
+}
+
+impl<M, const R: usize, const C: usize, T> RowRing<M, R, C, T>
+where
+    M: StorageMut<R, C, T>,
+{
+    /// Constructs an empty RowRing, the current content of matrix is ignored.
+    pub fn new(matrix: M) -> Self {
+        Self {
+            matrix,
+            oldest: 0,
+            len: 0,
+            _elements: PhantomData,
+        }
+    }
+
+    /// Writes row into the next free row of the matrix, or over the oldest row if all rows are
+    /// in use.
+    ///
+    /// # Panics
+    ///
+    /// If the row does not hold C elements or the matrix has no rows.
+    pub fn push_row_rotating(&mut self, row: &[T])
+    where
+        T: Copy,
+    {
+        assert_eq!(row.len(), C);
+        assert!(R > 0, "a matrix without rows can not hold a row");
+        let target = (self.oldest + self.len) % R;
+        let buffer = self.matrix.buffer_mut();
+        for (col, &value) in row.iter().enumerate() {
+            buffer[M::Priority::offset((target, col), R, C)] = value;
+        }
+        if self.len == R {
+            self.oldest = (self.oldest + 1) % R;
+        } else {
+            self.len += 1;
+        }
+    }
+
+    /// Returns an iterator over copies of the pushed rows from the oldest to the newest.
+    pub fn rows_in_order(&self) -> impl Iterator<Item = [T; C]> + '_
+    where
+        T: Copy,
+    {
+        let buffer = self.matrix.buffer();
+        (0..self.len).map(move |i| {
+            let row = (self.oldest + i) % R;
+            core::array::from_fn(|col| buffer[M::Priority::offset((row, col), R, C)])
+        })
+    }
+
+    /// Returns the row of the matrix the i-th oldest row was written to, or None if fewer rows
+    /// were pushed.
+    pub fn physical_row(&self, i: usize) -> Option<usize> {
+        (i < self.len).then(|| (self.oldest + i) % R)
+    }
+
+    /// Returns the amount of rows that were pushed, at most R.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no row was pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the next push overwrites the oldest row.
+    pub fn is_full(&self) -> bool {
+        self.len == R
+    }
+
+    /// Returns the underlying matrix, its rows are in ring order starting at
+    /// [`physical_row(0)`](RowRing::physical_row).
+    pub fn matrix(&self) -> &M {
+        &self.matrix
+    }
+
+    /// Consumes the RowRing and returns the underlying matrix.
+    pub fn into_inner(self) -> M {
+        self.matrix
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    storage::{assert_col, assert_row, StorageMut},
+    MemoryPriority,
+};
+use core::ops::{Add, Mul};
+
+/// ScaleAdd combines a row or column with a multiple of another one, the kernel of elimination
+/// and orthogonalization algorithms.
+///
+/// Lanes along the memory priority are processed as two disjoint slices, strided lanes element
+/// wise. The source may be the destination, in which case the lane is combined with itself.
+pub trait ScaleAdd<const R: usize, const C: usize, T> {
+    /// Sets row dst to k * row src.
+    ///
+    /// # Panics
+    ///
+    /// If src or dst is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, ScaleAdd, Export };
+    /// let mut m = Stacktrix::<6, 3, 2, ColumnPrio, i32>::from_rows([[1, 2], [3, 4], [5, 6]]);
+    /// m.copy_scaled_row(0, 2, -2);
+    /// assert_eq!(m.to_rows(), [[1, 2], [3, 4], [-2, -4]]);
+    /// ```
+    fn copy_scaled_row(&mut self, src: usize, dst: usize, k: T);
+    /// Adds k * row src to row dst.
+    ///
+    /// # Panics
+    ///
+    /// If src or dst is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, ScaleAdd, Export };
+    /// let mut m = Stacktrix::<4, 2, 2, RowPrio, f64>::from_rows([[2.0, 1.0], [4.0, 5.0]]);
+    /// m.add_scaled_row(0, 1, -2.0);
+    /// assert_eq!(m.to_rows(), [[2.0, 1.0], [0.0, 3.0]]);
+    /// ```
+    fn add_scaled_row(&mut self, src: usize, dst: usize, k: T);
+    /// Sets column dst to k * column src.
+    ///
+    /// # Panics
+    ///
+    /// If src or dst is out of bounds.
+    fn copy_scaled_col(&mut self, src: usize, dst: usize, k: T);
+    /// Adds k * column src to column dst.
+    ///
+    /// # Panics
+    ///
+    /// If src or dst is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, ScaleAdd, Export };
+    /// let mut m = Stacktrix::<4, 2, 2, RowPrio, i32>::from_rows([[1, 2], [3, 4]]);
+    /// m.add_scaled_col(1, 1, 2);
+    /// assert_eq!(m.to_rows(), [[1, 6], [3, 12]]);
+    /// ```
+    fn add_scaled_col(&mut self, src: usize, dst: usize, k: T);
+}
+
+impl<M, const R: usize, const C: usize, T> ScaleAdd<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    fn copy_scaled_row(&mut self, src: usize, dst: usize, k: T) {
+        assert_row::<R>(src);
+        assert_row::<R>(dst);
+        let (src, dst) = (
+            M::Priority::offset((src, 0), R, C),
+            M::Priority::offset((dst, 0), R, C),
+        );
+        let stride = M::Priority::col_stride(R, C);
+        combine(self.buffer_mut(), src, dst, stride, C, |d, s| *d = k * s);
+    }
+
+    fn add_scaled_row(&mut self, src: usize, dst: usize, k: T) {
+        assert_row::<R>(src);
+        assert_row::<R>(dst);
+        let (src, dst) = (
+            M::Priority::offset((src, 0), R, C),
+            M::Priority::offset((dst, 0), R, C),
+        );
+        let stride = M::Priority::col_stride(R, C);
+        combine(self.buffer_mut(), src, dst, stride, C, |d, s| {
+            *d = *d + k * s
+        });
+    }
+
+    fn copy_scaled_col(&mut self, src: usize, dst: usize, k: T) {
+        assert_col::<C>(src);
+        assert_col::<C>(dst);
+        let (src, dst) = (
+            M::Priority::offset((0, src), R, C),
+            M::Priority::offset((0, dst), R, C),
+        );
+        let stride = M::Priority::row_stride(R, C);
+        combine(self.buffer_mut(), src, dst, stride, R, |d, s| *d = k * s);
+    }
+
+    fn add_scaled_col(&mut self, src: usize, dst: usize, k: T) {
+        assert_col::<C>(src);
+        assert_col::<C>(dst);
+        let (src, dst) = (
+            M::Priority::offset((0, src), R, C),
+            M::Priority::offset((0, dst), R, C),
+        );
+        let stride = M::Priority::row_stride(R, C);
+        combine(self.buffer_mut(), src, dst, stride, R, |d, s| {
+            *d = *d + k * s
+        });
+    }
+}
+
+/// Calls f with every element of the lane starting at dst and the matching element of the lane
+/// starting at src, both lanes hold len elements that are stride apart.
+fn combine<T: Copy>(
+    buffer: &mut [T],
+    src: usize,
+    dst: usize,
+    stride: usize,
+    len: usize,
+    f: impl Fn(&mut T, T),
+) {
+    // Empty lanes may start past the end of the empty buffer.
+    if len == 0 {
+        return;
+    }
+    if src == dst {
+        for el in buffer[dst..].iter_mut().step_by(stride).take(len) {
+            f(el, *el);
+        }
+    } else if stride == 1 {
+        // Distinct lanes along the memory priority do not overlap.
+        let (src, dst) = if src < dst {
+            let (head, tail) = buffer.split_at_mut(dst);
+            (&head[src..src + len], &mut tail[..len])
+        } else {
+            let (head, tail) = buffer.split_at_mut(src);
+            (&tail[..len], &mut head[dst..dst + len])
+        };
+        for (d, &s) in dst.iter_mut().zip(src) {
+            f(d, s);
+        }
+    } else {
+        for i in 0..len {
+            let s = buffer[src + i * stride];
+            f(&mut buffer[dst + i * stride], s);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{storage::Storage, Elements, Position};
+
+/// Search finds the locations of elements, independent of the memory priority.
+///
+/// The elements are visited in logical row major order, ties resolve to the first location in
+/// that order.
+pub trait Search<const R: usize, const C: usize, T> {
+    /// Returns the location of the largest element, see [`Reduce::max`](crate::Reduce::max).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Search };
+    /// let m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 9, 3], [9, -5, 6]]);
+    /// assert_eq!(m.argmax(), Some((0, 1)));
+    /// ```
+    fn argmax(&self) -> Option<Position>
+    where
+        T: PartialOrd;
+    /// Returns the location of the smallest element, see [`Reduce::min`](crate::Reduce::min).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, Search };
+    /// let m = Stacktrix::<6, 2, 3, RowPrio, f64>::from_rows([[1.0, f64::NAN, 3.0], [4.0, -5.0, 6.0]]);
+    /// assert_eq!(m.argmin(), Some((1, 1)));
+    /// ```
+    fn argmin(&self) -> Option<Position>
+    where
+        T: PartialOrd;
+    /// Returns the location of the first element the predicate is true for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Search };
+    /// let m = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(m.position(|&el| el % 2 == 0), Some((0, 1)));
+    /// assert_eq!(m.position(|&el| el > 6), None);
+    /// ```
+    fn position<F>(&self, predicate: F) -> Option<Position>
+    where
+        F: FnMut(&T) -> bool;
+    /// Returns true if an element equals value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, Search };
+    /// let m = Stacktrix::<4, 2, 2, RowPrio, char>::with_values(['a', 'b', 'c', 'd']);
+    /// assert!(m.contains(&'c'));
+    /// assert!(!m.contains(&'e'));
+    /// ```
+    fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq;
+}
+
+impl<M, const R: usize, const C: usize, T> Search<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+{
+    fn argmax(&self) -> Option<Position>
+    where
+        T: PartialOrd,
+    {
+        extreme(self.iter_indexed(), |el, max| el > max)
+    }
+
+    fn argmin(&self) -> Option<Position>
+    where
+        T: PartialOrd,
+    {
+        extreme(self.iter_indexed(), |el, min| el < min)
+    }
+
+    fn position<F>(&self, mut predicate: F) -> Option<Position>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter_indexed()
+            .find(|(_, el)| predicate(el))
+            .map(|(location, _)| location)
+    }
+
+    fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.buffer().contains(value)
+    }
+}
+
+/// Returns the location of the first element that beats all others, skipping elements that are
+/// not comparable to themselves.
+fn extreme<'a, T, I, F>(elements: I, beats: F) -> Option<Position>
+where
+    T: PartialOrd + 'a,
+    I: Iterator<Item = (Position, &'a T)>,
+    F: Fn(&T, &T) -> bool,
+{
+    elements
+        .filter(|(_, el)| el.partial_cmp(el).is_some())
+        .fold(
+            None,
+            |best: Option<(Position, &T)>, (location, el)| match best {
+                Some((_, current)) if !beats(el, current) => best,
+                _ => Some((location, el)),
+            },
+        )
+        .map(|(location, _)| location)
+}
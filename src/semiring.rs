@@ -0,0 +1,332 @@
+use crate::{
+    storage::{Storage, StorageMut},
+    MatrixError, MemoryPriority, Progress,
+};
+use core::ops::{Add, ControlFlow, Mul};
+
+/// Semiring defines the addition and multiplication a [`SemiringOps`] product is computed with.
+///
+/// zero is the identity of add and annihilates under mul, one is the identity of mul.
+pub trait Semiring<T> {
+    /// The identity of add.
+    fn zero(&self) -> T;
+    /// The identity of mul.
+    fn one(&self) -> T;
+    /// Combines two products.
+    fn add(&self, a: T, b: T) -> T;
+    /// Combines two elements.
+    fn mul(&self, a: T, b: T) -> T;
+}
+
+/// The ordinary addition and multiplication.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Arithmetic;
+
+impl<T> Semiring<T> for Arithmetic
+where
+    T: From<u8> + Add<Output = T> + Mul<Output = T>,
+{
+    fn zero(&self) -> T {
+        T::from(0)
+    }
+
+    fn one(&self) -> T {
+        T::from(1)
+    }
+
+    fn add(&self, a: T, b: T) -> T {
+        a + b
+    }
+
+    fn mul(&self, a: T, b: T) -> T {
+        a * b
+    }
+}
+
+/// Or as addition and and as multiplication, the product of adjacency matrices tells which
+/// nodes are connected by a path of the combined length.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Boolean;
+
+impl Semiring<bool> for Boolean {
+    fn zero(&self) -> bool {
+        false
+    }
+
+    fn one(&self) -> bool {
+        true
+    }
+
+    fn add(&self, a: bool, b: bool) -> bool {
+        a || b
+    }
+
+    fn mul(&self, a: bool, b: bool) -> bool {
+        a && b
+    }
+}
+
+/// Minimum as addition and addition as multiplication, the tropical semiring. The product of
+/// distance matrices holds the shortest distances over paths of the combined length, missing
+/// edges are [`Tropical::INFINITY`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MinPlus;
+
+/// Tropical is implemented by the element types of the [`MinPlus`] semiring.
+pub trait Tropical: Copy + PartialOrd {
+    /// The distance of a missing edge, larger than every other distance.
+    const INFINITY: Self;
+    /// The distance from a node to itself.
+    const ZERO: Self;
+    /// Adds two distances, the sum is INFINITY if either of them is INFINITY.
+    fn plus(self, other: Self) -> Self;
+}
+
+macro_rules! tropical_float {
+    ($($t:ty),*) => {
+        $(
+            impl Tropical for $t {
+                const INFINITY: Self = <$t>::INFINITY;
+                const ZERO: Self = 0.0;
+
+                fn plus(self, other: Self) -> Self {
+                    self + other
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! tropical_int {
+    ($($t:ty),*) => {
+        $(
+            /// MAX is INFINITY, sums saturate at it.
+            impl Tropical for $t {
+                const INFINITY: Self = <$t>::MAX;
+                const ZERO: Self = 0;
+
+                fn plus(self, other: Self) -> Self {
+                    if self == Self::INFINITY || other == Self::INFINITY {
+                        Self::INFINITY
+                    } else {
+                        self.saturating_add(other)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+tropical_float!(f32, f64);
+tropical_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl<T: Tropical> Semiring<T> for MinPlus {
+    fn zero(&self) -> T {
+        T::INFINITY
+    }
+
+    fn one(&self) -> T {
+        T::ZERO
+    }
+
+    fn add(&self, a: T, b: T) -> T {
+        if b < a {
+            b
+        } else {
+            a
+        }
+    }
+
+    fn mul(&self, a: T, b: T) -> T {
+        a.plus(b)
+    }
+}
+
+/// SemiringOps multiplies and reduces matrices with the operations of a [`Semiring`].
+pub trait SemiringOps<const R: usize, const C: usize, T> {
+    /// Writes the product of the matrix and rhs to dst, i.e. the element (i, j) of dst is the
+    /// semiring sum over k of the products of (i, k) and (k, j).
+    ///
+    /// The memory priorities of the matrix, rhs and dst may differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, ColumnPrio, ColumnPrioMatrix, MinPlus, SemiringOps,
+    /// #     Tropical };
+    /// const INF: u32 = u32::INFINITY;
+    /// // Edge weights of a path 0 -> 1 -> 2.
+    /// let d = Stacktrix::<9, 3, 3, RowPrio, u32>::from_rows([
+    ///     [0, 2, INF],
+    ///     [INF, 0, 3],
+    ///     [INF, INF, 0],
+    /// ]);
+    /// let mut two_steps = Stacktrix::<9, 3, 3, ColumnPrio, u32>::zeros();
+    /// d.semiring_mul_into(&d, &mut two_steps, MinPlus);
+    /// assert_eq!(*two_steps.get((0, 2)), 5);
+    /// ```
+    fn semiring_mul_into<S, O, D, const K: usize>(&self, rhs: &O, dst: &mut D, semiring: S)
+    where
+        S: Semiring<T>,
+        O: Storage<C, K, T>,
+        D: StorageMut<R, K, T>;
+    /// Writes the product of the matrix and rhs into dst like
+    /// [`SemiringOps::semiring_mul_into`], reporting the rows of dst that are done to progress.
+    ///
+    /// Returns [`MatrixError::Cancelled`] if progress cancelled the product, the rows after the
+    /// last reported one keep their previous values in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::ops::ControlFlow;
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Arithmetic, Export, MatrixError, SemiringOps };
+    /// let a = Stacktrix::<9, 3, 3, ColumnPrio, i32>::from_fn(|i, j| (i + j) as i32);
+    /// let mut product = Stacktrix::<9, 3, 3, ColumnPrio, i32>::zeros();
+    /// let cancelled = a.semiring_mul_into_with_progress(&a, &mut product, Arithmetic, |progress| {
+    ///     match progress.done {
+    ///         1 => ControlFlow::Break(()),
+    ///         _ => ControlFlow::Continue(()),
+    ///     }
+    /// });
+    /// assert_eq!(cancelled, Err(MatrixError::Cancelled));
+    /// assert_eq!(product.to_rows(), [[5, 8, 11], [0, 0, 0], [0, 0, 0]]);
+    /// ```
+    fn semiring_mul_into_with_progress<S, O, D, F, const K: usize>(
+        &self,
+        rhs: &O,
+        dst: &mut D,
+        semiring: S,
+        progress: F,
+    ) -> Result<(), MatrixError>
+    where
+        S: Semiring<T>,
+        O: Storage<C, K, T>,
+        D: StorageMut<R, K, T>,
+        F: FnMut(Progress) -> ControlFlow<()>;
+    /// Returns the product of the matrix and the column vector v.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, Arithmetic, SemiringOps };
+    /// let m = Stacktrix::<6, 2, 3, RowPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(m.semiring_mul_vec(&[1, 0, -1], Arithmetic), [-2, -2]);
+    /// ```
+    fn semiring_mul_vec<S>(&self, v: &[T; C], semiring: S) -> [T; R]
+    where
+        S: Semiring<T>;
+    /// Returns the semiring sum of every row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Boolean, SemiringOps };
+    /// let m = Stacktrix::<4, 2, 2, ColumnPrio, bool>::from_rows([[false, true], [false, false]]);
+    /// assert_eq!(m.semiring_sum_rows(Boolean), [true, false]);
+    /// ```
+    fn semiring_sum_rows<S>(&self, semiring: S) -> [T; R]
+    where
+        S: Semiring<T>;
+    /// Returns the semiring sum of every column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, MinPlus, SemiringOps };
+    /// let m = Stacktrix::<4, 2, 2, RowPrio, f32>::from_rows([[1.0, 5.0], [3.0, 2.0]]);
+    /// assert_eq!(m.semiring_sum_cols(MinPlus), [1.0, 2.0]);
+    /// ```
+    fn semiring_sum_cols<S>(&self, semiring: S) -> [T; C]
+    where
+        S: Semiring<T>;
+}
+
+impl<M, const R: usize, const C: usize, T> SemiringOps<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+    T: Copy,
+{
+    fn semiring_mul_into<S, O, D, const K: usize>(&self, rhs: &O, dst: &mut D, semiring: S)
+    where
+        S: Semiring<T>,
+        O: Storage<C, K, T>,
+        D: StorageMut<R, K, T>,
+    {
+        let _ =
+            self.semiring_mul_into_with_progress(rhs, dst, semiring, |_| ControlFlow::Continue(()));
+    }
+
+    fn semiring_mul_into_with_progress<S, O, D, F, const K: usize>(
+        &self,
+        rhs: &O,
+        dst: &mut D,
+        semiring: S,
+        mut progress: F,
+    ) -> Result<(), MatrixError>
+    where
+        S: Semiring<T>,
+        O: Storage<C, K, T>,
+        D: StorageMut<R, K, T>,
+        F: FnMut(Progress) -> ControlFlow<()>,
+    {
+        let (lhs, rhs) = (self.buffer(), rhs.buffer());
+        let dst = dst.buffer_mut();
+        for i in 0..R {
+            for j in 0..K {
+                let mut sum = semiring.zero();
+                for k in 0..C {
+                    let product = semiring.mul(
+                        lhs[M::Priority::offset((i, k), R, C)],
+                        rhs[O::Priority::offset((k, j), C, K)],
+                    );
+                    sum = semiring.add(sum, product);
+                }
+                dst[D::Priority::offset((i, j), R, K)] = sum;
+            }
+            if progress(Progress::of(i + 1, R)).is_break() {
+                return Err(MatrixError::Cancelled);
+            }
+        }
+        Ok(())
+    }
+
+    fn semiring_mul_vec<S>(&self, v: &[T; C], semiring: S) -> [T; R]
+    where
+        S: Semiring<T>,
+    {
+        let buffer = self.buffer();
+        core::array::from_fn(|row| {
+            v.iter()
+                .enumerate()
+                .fold(semiring.zero(), |sum, (col, &x)| {
+                    let el = buffer[M::Priority::offset((row, col), R, C)];
+                    semiring.add(sum, semiring.mul(el, x))
+                })
+        })
+    }
+
+    fn semiring_sum_rows<S>(&self, semiring: S) -> [T; R]
+    where
+        S: Semiring<T>,
+    {
+        let buffer = self.buffer();
+        core::array::from_fn(|row| {
+            (0..C).fold(semiring.zero(), |sum, col| {
+                semiring.add(sum, buffer[M::Priority::offset((row, col), R, C)])
+            })
+        })
+    }
+
+    fn semiring_sum_cols<S>(&self, semiring: S) -> [T; C]
+    where
+        S: Semiring<T>,
+    {
+        let buffer = self.buffer();
+        core::array::from_fn(|col| {
+            (0..R).fold(semiring.zero(), |sum, row| {
+                semiring.add(sum, buffer[M::Priority::offset((row, col), R, C)])
+            })
+        })
+    }
+}
@@ -0,0 +1,112 @@
+//! Serialization of matrices, enabled by the `serde` feature.
+//!
+//! A matrix is serialized as its shape, its memory priority and its buffer:
+//! `{ "rows": 2, "cols": 3, "layout": "RowPrio", "data": [1, 2, 3, 4, 5, 6] }`.
+//! [`Reftrix`] only borrows its memory and is therefore only serialized, the owned counterpart
+//! [`Stacktrix`] deserializes the same representation. Data in the other memory priority is
+//! reordered while deserializing.
+use crate::{
+    storage::{Storage, StorageMut},
+    ColumnPrio, MemoryPriority, Reftrix, RowPrio, Stacktrix,
+};
+use alloc::{format, string::String, vec::Vec};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize)]
+#[serde(rename = "Matrix")]
+struct Repr<'a, T> {
+    rows: usize,
+    cols: usize,
+    layout: &'static str,
+    data: &'a [T],
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Matrix")]
+struct OwnedRepr<T> {
+    rows: usize,
+    cols: usize,
+    layout: String,
+    data: Vec<T>,
+}
+
+fn serialize<M, const R: usize, const C: usize, T, S>(
+    m: &M,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    M: Storage<R, C, T>,
+    T: Serialize,
+    S: Serializer,
+{
+    Repr {
+        rows: R,
+        cols: C,
+        layout: M::Priority::NAME,
+        data: m.buffer(),
+    }
+    .serialize(serializer)
+}
+
+impl<const R: usize, const C: usize, P, T> Serialize for Reftrix<'_, R, C, P, T>
+where
+    P: MemoryPriority,
+    T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<const S: usize, const R: usize, const C: usize, P, T> Serialize for Stacktrix<S, R, C, P, T>
+where
+    P: MemoryPriority,
+    T: Serialize,
+{
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<'de, const S: usize, const R: usize, const C: usize, P, T> Deserialize<'de>
+    for Stacktrix<S, R, C, P, T>
+where
+    P: MemoryPriority,
+    T: Deserialize<'de> + Copy,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = OwnedRepr::<T>::deserialize(deserializer)?;
+        if repr.rows != R || repr.cols != C {
+            return Err(de::Error::custom(format_args!(
+                "expected a {} x {} matrix, got {} x {}",
+                R, C, repr.rows, repr.cols
+            )));
+        }
+        if repr.data.len() != R * C || S != R * C {
+            return Err(de::Error::invalid_length(
+                repr.data.len(),
+                &format!("{} elements", R * C).as_str(),
+            ));
+        }
+        let offset: fn((usize, usize), usize, usize) -> usize = match repr.layout.as_str() {
+            ColumnPrio::NAME => ColumnPrio::offset,
+            RowPrio::NAME => RowPrio::offset,
+            layout => {
+                return Err(de::Error::unknown_variant(
+                    layout,
+                    &[ColumnPrio::NAME, RowPrio::NAME],
+                ))
+            }
+        };
+        let mut m = Stacktrix::from_values(&repr.data);
+        if repr.layout != P::NAME {
+            let buffer = m.buffer_mut();
+            for row in 0..R {
+                for col in 0..C {
+                    buffer[P::offset((row, col), R, C)] = repr.data[offset((row, col), R, C)];
+                }
+            }
+        }
+        Ok(m)
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    storage::{dense, StorageMut},
+    MemoryPriority,
+};
+use core::fmt::Debug;
+
+/// Shadow wraps a matrix together with a naive reference copy of it, stored as an array of its
+/// rows.
+///
+/// Every mutating operation is applied to both, afterwards they are compared element by
+/// element. A divergence panics with the name of the operation and the first location that
+/// differs. This is meant for testing layout sensitive code, e.g. strided fills, against an
+/// implementation that is obviously correct.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ Shadow, Stacktrix, RowPrio };
+/// let m = Stacktrix::<6, 2, 3, RowPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+/// let mut shadow = Shadow::new(m);
+/// shadow.fill_col(1, &[0, 0]);
+/// shadow.apply("swap the corners", |m| m.as_mut_slice().swap(0, 5), |r| {
+///     let corner = r[0][0];
+///     r[0][0] = r[1][2];
+///     r[1][2] = corner;
+/// });
+/// assert_eq!(shadow.into_inner().as_slice(), &[6, 0, 3, 4, 0, 1]);
+/// ```
+pub struct Shadow<M, const R: usize, const C: usize, T> {
+    matrix: M,
+    reference: [[T; C]; R],
+}
+
+impl<M, const R: usize, const C: usize, T> Shadow<M, R, C, T>
+where
+    M: StorageMut<R, C, T>,
+    T: Copy + Debug + PartialEq,
+{
+    /// Wraps matrix, the reference starts as a copy of it.
+    pub fn new(matrix: M) -> Self {
+        let buffer = matrix.buffer();
+        let reference = core::array::from_fn(|row| {
+            core::array::from_fn(|col| buffer[M::Priority::offset((row, col), R, C)])
+        });
+        Self { matrix, reference }
+    }
+
+    /// Returns the wrapped matrix.
+    pub fn matrix(&self) -> &M {
+        &self.matrix
+    }
+
+    /// Returns the reference copy.
+    pub fn reference(&self) -> &[[T; C]; R] {
+        &self.reference
+    }
+
+    /// Unwraps the matrix.
+    pub fn into_inner(self) -> M {
+        self.matrix
+    }
+
+    /// Applies op to the matrix and reference to the reference copy, then compares them.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix and the reference differ afterwards, the message names the operation.
+    pub fn apply<F, G>(&mut self, name: &str, op: F, reference: G)
+    where
+        F: FnOnce(&mut M),
+        G: FnOnce(&mut [[T; C]; R]),
+    {
+        op(&mut self.matrix);
+        reference(&mut self.reference);
+        self.verify(name);
+    }
+
+    /// Inserts value at location, see
+    /// [`ColumnPrioMatrix::insert`](crate::ColumnPrioMatrix::insert).
+    pub fn insert(&mut self, location: (usize, usize), value: T) {
+        dense::insert(&mut self.matrix, location, value);
+        self.reference[location.0][location.1] = value;
+        self.verify("insert");
+    }
+
+    /// Fills row row with data, see
+    /// [`ColumnPrioMatrix::fill_row`](crate::ColumnPrioMatrix::fill_row).
+    pub fn fill_row(&mut self, row: usize, data: &[T]) {
+        dense::fill_row(&mut self.matrix, row, data);
+        self.reference[row].copy_from_slice(data);
+        self.verify("fill_row");
+    }
+
+    /// Fills column col with data, see
+    /// [`ColumnPrioMatrix::fill_col`](crate::ColumnPrioMatrix::fill_col).
+    pub fn fill_col(&mut self, col: usize, data: &[T]) {
+        dense::fill_col(&mut self.matrix, col, data);
+        for (row, &value) in self.reference.iter_mut().zip(data) {
+            row[col] = value;
+        }
+        self.verify("fill_col");
+    }
+
+    /// Applies f to every element, see
+    /// [`ColumnPrioMatrix::apply_all`](crate::ColumnPrioMatrix::apply_all).
+    ///
+    /// f is called twice for every element, once for the matrix and once for the reference,
+    /// so it should not depend on how often or in which order it is called.
+    pub fn apply_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        dense::apply_all(&mut self.matrix, &mut f);
+        self.reference.iter_mut().flatten().for_each(f);
+        self.verify("apply_all");
+    }
+
+    /// Panics at the first location where the matrix and the reference differ.
+    fn verify(&self, name: &str) {
+        let buffer = self.matrix.buffer();
+        for (row, values) in self.reference.iter().enumerate() {
+            for (col, expected) in values.iter().enumerate() {
+                let got = &buffer[M::Priority::offset((row, col), R, C)];
+                assert!(
+                    got == expected,
+                    "{name} diverged from the reference at ({row}, {col}): the matrix holds \
+                     {got:?}, the reference {expected:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Kernels for bulk operations on continuous buffers of `u8` and `u32`.
+//!
+//! With the `simd` feature x86_64 targets process 16 bytes at a time with SSE2, the remaining
+//! elements and all other targets use plain loops.
+#[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "sse2"))]
+mod sse2 {
+    use core::{arch::x86_64::*, mem::size_of};
+
+    /// Combines all full 16 byte blocks of dst and src with op and returns the remainders.
+    pub(super) fn blocks<'d, 's, T>(
+        dst: &'d mut [T],
+        src: &'s [T],
+        op: impl Fn(__m128i, __m128i) -> __m128i,
+    ) -> (&'d mut [T], &'s [T]) {
+        let len = dst.len().min(src.len());
+        let per_block = 16 / size_of::<T>();
+        let blocks = len / per_block;
+        for block in 0..blocks {
+            // SAFETY:
+            // The block lies within the first len elements of both slices, the loads and stores
+            // are unaligned.
+            unsafe {
+                let d = dst.as_mut_ptr().add(block * per_block).cast::<__m128i>();
+                let s = src.as_ptr().add(block * per_block).cast::<__m128i>();
+                _mm_storeu_si128(d, op(_mm_loadu_si128(d), _mm_loadu_si128(s)));
+            }
+        }
+        let done = blocks * per_block;
+        (&mut dst[done..], &src[done..])
+    }
+
+    // Safety of the intrinsics:
+    // The module is only compiled if the target enables SSE2.
+
+    pub(super) fn prefetch<T>(data: &[T]) {
+        let bytes = core::mem::size_of_val(data);
+        for line in (0..bytes).step_by(64) {
+            // SAFETY:
+            // Prefetching is a hint and never faults, the address lies within data anyway.
+            unsafe { _mm_prefetch::<_MM_HINT_T0>(data.as_ptr().cast::<i8>().add(line)) };
+        }
+    }
+
+    pub(super) fn xor<'d, 's, T>(dst: &'d mut [T], src: &'s [T]) -> (&'d mut [T], &'s [T]) {
+        blocks(dst, src, |a, b| unsafe { _mm_xor_si128(a, b) })
+    }
+
+    pub(super) fn add_u8<'d, 's>(dst: &'d mut [u8], src: &'s [u8]) -> (&'d mut [u8], &'s [u8]) {
+        blocks(dst, src, |a, b| unsafe { _mm_add_epi8(a, b) })
+    }
+
+    pub(super) fn add_u32<'d, 's>(
+        dst: &'d mut [u32],
+        src: &'s [u32],
+    ) -> (&'d mut [u32], &'s [u32]) {
+        blocks(dst, src, |a, b| unsafe { _mm_add_epi32(a, b) })
+    }
+}
+
+/// Hints the CPU to load data into the cache, a no-op without the `simd` feature or on other
+/// targets than x86_64.
+pub(crate) fn prefetch<T>(data: &[T]) {
+    #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "sse2"))]
+    sse2::prefetch(data);
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64", target_feature = "sse2")))]
+    let _ = data;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Lane is implemented by the element types that have bulk kernels, the element types of
+/// [`BulkOps`](crate::BulkOps).
+///
+/// The trait is sealed and only implemented by `u8` and `u32`.
+pub trait Lane: Copy + sealed::Sealed {
+    /// dst\[i\] ^= src\[i\]
+    fn xor(dst: &mut [Self], src: &[Self]);
+    /// dst\[i\] = dst\[i\].wrapping_add(src\[i\])
+    fn wrapping_add(dst: &mut [Self], src: &[Self]);
+    /// Substitutes every byte of every element with its entry in lut.
+    fn substitute_bytes(dst: &mut [Self], lut: &[u8; 256]);
+    /// The scalar variants for strided access.
+    fn xor_one(self, other: Self) -> Self;
+    /// See [`xor_one`](Lane::xor_one).
+    fn wrapping_add_one(self, other: Self) -> Self;
+}
+
+impl sealed::Sealed for u8 {}
+
+impl Lane for u8 {
+    fn xor(dst: &mut [u8], src: &[u8]) {
+        #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "sse2"))]
+        let (dst, src) = sse2::xor(dst, src);
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d ^= s;
+        }
+    }
+
+    fn wrapping_add(dst: &mut [u8], src: &[u8]) {
+        #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "sse2"))]
+        let (dst, src) = sse2::add_u8(dst, src);
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = d.wrapping_add(*s);
+        }
+    }
+
+    fn substitute_bytes(dst: &mut [u8], lut: &[u8; 256]) {
+        // Table lookups do not vectorize, unrolling by 8 keeps several loads in flight.
+        let mut chunks = dst.chunks_exact_mut(8);
+        for chunk in chunks.by_ref() {
+            for el in chunk {
+                *el = lut[*el as usize];
+            }
+        }
+        for el in chunks.into_remainder() {
+            *el = lut[*el as usize];
+        }
+    }
+
+    fn xor_one(self, other: u8) -> u8 {
+        self ^ other
+    }
+
+    fn wrapping_add_one(self, other: u8) -> u8 {
+        self.wrapping_add(other)
+    }
+}
+
+impl sealed::Sealed for u32 {}
+
+impl Lane for u32 {
+    fn xor(dst: &mut [u32], src: &[u32]) {
+        #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "sse2"))]
+        let (dst, src) = sse2::xor(dst, src);
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d ^= s;
+        }
+    }
+
+    fn wrapping_add(dst: &mut [u32], src: &[u32]) {
+        #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "sse2"))]
+        let (dst, src) = sse2::add_u32(dst, src);
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = d.wrapping_add(*s);
+        }
+    }
+
+    fn substitute_bytes(dst: &mut [u32], lut: &[u8; 256]) {
+        for el in dst {
+            *el = u32::from_ne_bytes(el.to_ne_bytes().map(|b| lut[b as usize]));
+        }
+    }
+
+    fn xor_one(self, other: u32) -> u32 {
+        self ^ other
+    }
+
+    fn wrapping_add_one(self, other: u32) -> u32 {
+        self.wrapping_add(other)
+    }
+}
@@ -0,0 +1,57 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::{
+    storage::{same_layout, Storage, StorageMut},
+    MemoryPriority,
+};
+
+/// Smoothing blends sequences of matrices of the same shape, e.g. to denoise a stream of sensor
+/// frames.
+pub trait Smoothing<const R: usize, const C: usize, T> {
+    /// Blends sample into the matrix as an exponential moving average:
+    /// self = alpha * sample + (1 - alpha) * self.
+    ///
+    /// The shapes are checked at compile time. If both matrices share their memory priority the
+    /// buffers are blended in one continuous loop the compiler can vectorize, otherwise element
+    /// wise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, RowPrio, Smoothing, Export };
+    /// let mut average = Stacktrix::<4, 2, 2, RowPrio, f32>::from_rows([[0.0, 1.0], [2.0, 4.0]]);
+    /// let sample = Stacktrix::<4, 2, 2, ColumnPrio, f32>::from_rows([[4.0, 1.0], [0.0, 8.0]]);
+    /// average.ema_update(&sample, 0.25);
+    /// assert_eq!(average.to_rows(), [[1.0, 1.0], [1.5, 5.0]]);
+    /// ```
+    fn ema_update<O>(&mut self, sample: &O, alpha: T)
+    where
+        O: Storage<R, C, T>,
+        T: Copy + From<u8> + Add<Output = T> + Sub<Output = T> + Mul<Output = T>;
+}
+
+impl<M, const R: usize, const C: usize, T> Smoothing<R, C, T> for M
+where
+    M: StorageMut<R, C, T>,
+{
+    fn ema_update<O>(&mut self, sample: &O, alpha: T)
+    where
+        O: Storage<R, C, T>,
+        T: Copy + From<u8> + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        let keep = T::from(1) - alpha;
+        let (dst, src) = (self.buffer_mut(), sample.buffer());
+        if same_layout::<M::Priority, O::Priority>(R, C) {
+            for (el, &x) in dst.iter_mut().zip(src) {
+                *el = alpha * x + keep * *el;
+            }
+            return;
+        }
+        for row in 0..R {
+            for col in 0..C {
+                let el = &mut dst[M::Priority::offset((row, col), R, C)];
+                *el = alpha * src[O::Priority::offset((row, col), R, C)] + keep * *el;
+            }
+        }
+    }
+}
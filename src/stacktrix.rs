@@ -1,15 +1,36 @@
 use crate::{
-    ColumnPrio, ColumnPrioMatrix, IntermittentSlice, IntermittentSliceMut, IterIntermittentSlices,
-    IterMutIntermittentSlices, IterSlices, IterSlicesMut, Position, RowPrio, RowPrioMatrix,
+    display::display_cell,
+    random::splitmix64,
+    storage::{location, logical_eq, logical_hash, Storage, StorageMut},
+    Grid, MatrixError, PseudoRandom, Reftrix,
+};
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem::MaybeUninit,
 };
-use std::{fmt::Debug, marker::PhantomData, mem::MaybeUninit};
 
 /// Stacktrix allows a stack based array to be used as a Matrix.
 ///
 /// A Stacktrix matrix operates on a a stack based array. The number of rows is indicated by R the number
 /// of columns by C, S indicates the entire size this is necessary since const expressions are
 /// still nightly only. MemoryPriority indicates how the underlying memory is interpreted. (see
-/// [`ColumnPrio`], [`RowPrio`])
+/// [`ColumnPrio`](crate::ColumnPrio), [`RowPrio`](crate::RowPrio))
+///
+/// A shape with S != R * C is rejected at compile time by the constructors, e.g.
+/// [`Stacktrix::with_values`] or [`Stacktrix::from_fn`]. The exception is
+/// [`Stacktrix::try_from_values`], which returns [`MatrixError::InvalidShape`] at run time
+/// instead.
+///
+/// # Layout
+///
+/// Stacktrix is `#[repr(transparent)]` over `[T; S]`: it has the size, the alignment and the
+/// ABI of the array, the elements are stored in memory order. This is part of the API and may be
+/// relied upon, e.g. to pass a Stacktrix through FFI or to cast references with
+/// [`Stacktrix::from_array_ref`].
+#[repr(transparent)]
 pub struct Stacktrix<const S: usize, const R: usize, const C: usize, MemoryPrio, T> {
     inner: [T; S],
     _prio: PhantomData<MemoryPrio>,
@@ -22,30 +43,31 @@ where
 {
     /// Constructs a Stacktrix from an array with memory interpretation given by MemoryPriority.
     ///
-    /// # Panics
-    ///
-    /// The function will panic if the given slice is not equal to the size of the to be created
-    /// matrix R * C or if S != R * C.
-    ///
     /// # Examples
     ///
     /// ```
     /// # use mightrix::{ Stacktrix, ColumnPrio };
     /// let reftrix = Stacktrix::<6, 3, 2, ColumnPrio, u8>::with_values([1,2,3,4,5,6]);
     /// ```
+    ///
+    /// ```compile_fail
+    /// # use mightrix::{ Stacktrix, ColumnPrio };
+    /// let reftrix = Stacktrix::<6, 2, 2, ColumnPrio, u8>::with_values([1,2,3,4,5,6]);
+    /// ```
     pub const fn with_values(inner_values: [T; S]) -> Self {
-        assert!(S == R * C);
+        let () = Self::SHAPE;
         Self {
             inner: inner_values,
             _prio: PhantomData,
         }
     }
-    /// Constructs a Stacktrix from a slice with a [`ColumnPrio`] memory interpretation.
+    /// Constructs a Stacktrix from a slice with a [`ColumnPrio`](crate::ColumnPrio) memory
+    /// interpretation.
     ///
     /// # Panics
     ///
     /// The function will panic if the given slice is not equal to the size of the to be created
-    /// matrix R * C or if S != R * C.
+    /// matrix R * C.
     ///
     /// # Examples
     ///
@@ -55,13 +77,18 @@ where
     /// let reftrix = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&data[..]);
     /// ```
     pub fn from_values(inner_values: &[T]) -> Self {
+        let () = Self::SHAPE;
         assert!(inner_values.len() == R * C);
-        assert!(S == R * C);
+        Self::copy_from(inner_values)
+    }
+
+    /// Copies the matrix out of a slice of size S.
+    fn copy_from(inner_values: &[T]) -> Self {
         let mut inner: [MaybeUninit<T>; S] = unsafe { [MaybeUninit::uninit().assume_init(); S] };
         // Safety:
         // inner and inner_values are valid pointers and do not overlap.
         unsafe {
-            std::ptr::copy_nonoverlapping(inner_values.as_ptr(), inner.as_mut_ptr().cast::<T>(), S)
+            core::ptr::copy_nonoverlapping(inner_values.as_ptr(), inner.as_mut_ptr().cast::<T>(), S)
         };
         // Safety:
         // T and MaybeUninit<T> have the same size.
@@ -71,486 +98,528 @@ where
             _prio: PhantomData,
         }
     }
-}
 
-impl<'a, const S: usize, const R: usize, const C: usize, T> ColumnPrioMatrix<'a, R, C, T>
-    for Stacktrix<S, R, C, ColumnPrio, T>
-where
-    Self: 'a,
-    T: Copy + Default + Debug,
-{
-    /// Inserts a value at position (x, y) inside the matrix.
-    ///
-    /// # Panics
+    /// Constructs a Stacktrix from a slice, returning an error instead of panicking.
     ///
-    /// If the location given is out of bounds in x or y the function panics.
+    /// Returns [`MatrixError::InvalidShape`] if S != R * C and [`MatrixError::SizeMismatch`] if
+    /// the slice is not of size R * C.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
-    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
-    /// let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&mut data[..]);
-    /// m.insert((3, 0), 0);
-    /// assert_eq!(m.get((3,0)), &0);
+    /// # use mightrix::{ Stacktrix, ColumnPrio, MatrixError };
+    /// let data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
+    /// assert!(Stacktrix::<16, 4, 4, ColumnPrio, u8>::try_from_values(&data[..]).is_ok());
+    /// assert_eq!(
+    ///     Stacktrix::<16, 4, 4, ColumnPrio, u8>::try_from_values(&data[..8]).err(),
+    ///     Some(MatrixError::SizeMismatch { expected: 16, got: 8 })
+    /// );
+    /// assert_eq!(
+    ///     Stacktrix::<16, 2, 4, ColumnPrio, u8>::try_from_values(&data[..8]).err(),
+    ///     Some(MatrixError::InvalidShape { size: 16, rows: 2, cols: 4 })
+    /// );
     /// ```
-    fn insert(&mut self, location: Position, value: T) {
-        self.get_mut_column(location.1)[location.0] = value;
+    pub fn try_from_values(inner_values: &[T]) -> Result<Self, MatrixError> {
+        if S != R * C {
+            return Err(MatrixError::InvalidShape {
+                size: S,
+                rows: R,
+                cols: C,
+            });
+        }
+        if inner_values.len() != S {
+            return Err(MatrixError::SizeMismatch {
+                expected: S,
+                got: inner_values.len(),
+            });
+        }
+        Ok(Self::copy_from(inner_values))
     }
-    /// Get a immutable reference to a value in the matrix at location (x, y)
-    ///
-    /// # Panics
+}
+
+impl<const S: usize, const R: usize, const C: usize, MemoryPriority>
+    Stacktrix<S, R, C, MemoryPriority, u64>
+{
+    /// Constructs a Stacktrix of reproducible pseudo random bits in a const context, e.g. a
+    /// fixture in a static.
     ///
-    /// If the location given is out of bounds in x or y the function panics.
+    /// Unlike [`Stacktrix::pseudo_random`] the values are derived from the index in memory, the
+    /// memory priority is not known in a const context. For [`RowPrio`](crate::RowPrio) both
+    /// agree.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
-    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
-    /// let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&mut data[..]);
-    /// assert_eq!(m.get((0, 2)), &3);
+    /// # use mightrix::{ Stacktrix, RowPrio };
+    /// static FIXTURE: Stacktrix<6, 2, 3, RowPrio, u64> = Stacktrix::pseudo_random_bits(1);
+    /// assert_eq!(FIXTURE, Stacktrix::<6, 2, 3, RowPrio, u64>::pseudo_random(1));
     /// ```
-    fn get(&'a self, location: Position) -> &'a T {
-        &self.get_column(location.1)[location.0]
-    }
-
-    /// Get a mutable reference to a value in the matrix at location (x, y)
-    ///
-    /// # Panics
-    ///
-    /// If the location given is out of bounds in x or y the function panics.
-    fn get_mut(&'a mut self, location: Position) -> &'a mut T {
-        &mut self.get_mut_column(location.1)[location.0]
+    pub const fn pseudo_random_bits(seed: u64) -> Self {
+        let mut inner = [0; S];
+        let mut index = 0;
+        while index < S {
+            inner[index] = splitmix64(seed, index as u64);
+            index += 1;
+        }
+        Self::with_values(inner)
     }
+}
 
-    /// Fills an entire column with the given data.
-    ///
-    /// # Panics
-    ///
-    /// If the column is out of bounds.
+impl<const S: usize, const R: usize, const C: usize, MemoryPriority, T>
+    Stacktrix<S, R, C, MemoryPriority, T>
+where
+    MemoryPriority: crate::MemoryPriority,
+{
+    /// Constructs a Stacktrix where the element at (row, col) is f(row, col).
     ///
-    /// If the data is not the size of a column.
+    /// The elements are created in memory order of the MemoryPriority.
     ///
     /// # Examples
     ///
     /// ```
     /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
-    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
-    /// let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&mut data[..]);
-    /// m.fill_col(1, &[7,7,7,7]);
-    /// assert_eq!(m.get_column(1), &[7,7,7,7]);
+    /// let m = Stacktrix::<6, 2, 3, ColumnPrio, usize>::from_fn(|row, col| row * 10 + col);
+    /// assert_eq!(m.get_column(2), &[2, 12]);
     /// ```
-    fn fill_col(&mut self, col: usize, data: &[T]) {
-        assert_eq!(data.len(), C);
-        let start = col * C;
-        self.inner[start..start + C].copy_from_slice(data);
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let () = Self::SHAPE;
+        Self {
+            inner: core::array::from_fn(|index| {
+                let (row, col) = location::<MemoryPriority>(index, R, C);
+                f(row, col)
+            }),
+            _prio: PhantomData,
+        }
     }
 
-    /// Fills an entire row with the given data.
-    ///
-    /// # Panics
-    ///
-    /// If the row is out of bounds.
-    ///
-    /// If the data is not the size of a row.
+    /// Constructs a Stacktrix from its rows in logical order, independent of the
+    /// MemoryPriority.
     ///
     /// # Examples
     ///
     /// ```
     /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
-    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
-    /// let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&mut data[..]);
-    /// m.fill_row(1, &[7,7,7,7]);
-    /// assert_eq!(m.get((1,0)), &7);
-    /// assert_eq!(m.get((1,1)), &7);
-    /// assert_eq!(m.get((1,2)), &7);
-    /// assert_eq!(m.get((1,3)), &7);
-    /// ```
-    fn fill_row(&mut self, row: usize, data: &[T]) {
-        assert_eq!(data.len(), R);
-        for (dst, src) in self.get_mut_row(row).into_iter().zip(data.iter()) {
-            *dst = *src;
-        }
+    /// let m = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(m.get_column(0), &[1, 4]);
+    /// ```
+    pub fn from_rows(rows: [[T; C]; R]) -> Self
+    where
+        T: Copy,
+    {
+        Self::from_fn(|row, col| rows[row][col])
     }
 
-    /// Retrieves a immutable slice that represents the column.
-    ///
-    /// # Panics
-    ///
-    /// If the column is out of bounds.
+    /// Constructs a Stacktrix from its columns in logical order, independent of the
+    /// MemoryPriority.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
-    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
-    /// let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&mut data[..]);
-    /// assert_eq!(m.get_column(0), &[1,1,1,1]);
-    /// ```
-    fn get_column(&self, col: usize) -> &[T] {
-        assert!(
-            col < C,
-            "Column: {} out of bounds {}, be carefull columns are 0 indexed.",
-            col,
-            C
-        );
-        let start = col * C;
-        &self.inner[start..start + C]
-    }
-
-    /// Retrieves a mutable slice that represents the column.
-    ///
-    /// # Panics
-    ///
-    /// If the column is out of bounds.
-    fn get_mut_column(&mut self, col: usize) -> &mut [T] {
-        assert!(
-            col < C,
-            "Column: {} out of bounds {}, be carefull columns are 0 indexed.",
-            col,
-            C
-        );
-        let start = col * C;
-        &mut self.inner[start..start + C]
+    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix };
+    /// let m = Stacktrix::<6, 2, 3, RowPrio, u8>::from_cols([[1, 4], [2, 5], [3, 6]]);
+    /// assert_eq!(m.get_row(0), &[1, 2, 3]);
+    /// ```
+    pub fn from_cols(cols: [[T; R]; C]) -> Self
+    where
+        T: Copy,
+    {
+        Self::from_fn(|row, col| cols[col][row])
     }
 
-    /// Retrieves a [`IntermittentSlice`].
+    /// Constructs a Stacktrix from an iterator over its rows in logical order, e.g. rows
+    /// gathered from several matrices of either memory priority.
     ///
-    /// # Panics
+    /// A row is anything that iterates over its elements, like a row slice of a
+    /// [`RowPrio`](crate::RowPrio) matrix, an [`IntermittentSlice`](crate::IntermittentSlice)
+    /// of a [`ColumnPrio`](crate::ColumnPrio) matrix or an array.
     ///
-    /// If the row is out of bounds.
-    fn get_row(&self, row: usize) -> IntermittentSlice<'_, R, C, T> {
-        assert!(
-            row < R,
-            "Row: {} out of bounds {}, be carefull rows are 0 indexed.",
-            row,
-            R
-        );
-        IntermittentSlice {
-            start: &self.inner[row],
-        }
-    }
-
-    /// Retrieves a [`IntermittentSliceMut`].
+    /// Returns [`MatrixError::SizeMismatch`] with C elements expected if a row does not hold C
+    /// elements, and with R rows expected if the iterator does not yield exactly R rows.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// If the row is out of bounds.
-    fn get_mut_row(&mut self, row: usize) -> IntermittentSliceMut<'_, R, C, T> {
-        assert!(
-            row < R,
-            "Row: {} out of bounds {}, be carefull rows are 0 indexed.",
-            row,
-            R
-        );
-        IntermittentSliceMut {
-            start: &mut self.inner[row],
-        }
-    }
-
-    fn rows(&self) -> IterIntermittentSlices<'_, R, C, T> {
-        IterIntermittentSlices {
-            slice_index: 0,
-            matrix_buffer: &self.inner,
-        }
-    }
-    fn rows_mut(&mut self) -> IterMutIntermittentSlices<'_, R, C, T> {
-        IterMutIntermittentSlices {
-            slice_index: 0,
-            matrix_buffer: &mut self.inner,
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, RowPrio, ColumnPrioMatrix, RowPrioMatrix, MatrixError };
+    /// let a = Stacktrix::<6, 2, 3, RowPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let b = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from_rows([[7, 8, 9], [10, 11, 12]]);
+    /// let rows = ColumnPrioMatrix::rows(&b).rev();
+    /// let m = Stacktrix::<6, 2, 3, RowPrio, u8>::try_from_row_iter(rows).unwrap();
+    /// assert_eq!(m.get_row(0), &[10, 11, 12]);
+    /// assert_eq!(m.get_column(2), [12, 9]);
+    /// assert_eq!(
+    ///     Stacktrix::<6, 2, 3, RowPrio, u8>::try_from_row_iter([a.get_row(0)]).err(),
+    ///     Some(MatrixError::SizeMismatch { expected: 2, got: 1 })
+    /// );
+    /// ```
+    pub fn try_from_row_iter<I>(rows: I) -> Result<Self, MatrixError>
+    where
+        I: IntoIterator,
+        I::Item: IntoIterator,
+        <I::Item as IntoIterator>::Item: Borrow<T>,
+        T: Copy,
+    {
+        let () = Self::SHAPE;
+        let mut inner = [const { MaybeUninit::<T>::uninit() }; S];
+        let mut rows = rows.into_iter();
+        let mut filled = 0;
+        for (row, lane) in rows.by_ref().take(R).enumerate() {
+            let mut len = 0;
+            for el in lane {
+                if len < C {
+                    inner[MemoryPriority::offset((row, len), R, C)].write(*el.borrow());
+                }
+                len += 1;
+            }
+            if len != C {
+                return Err(MatrixError::SizeMismatch {
+                    expected: C,
+                    got: len,
+                });
+            }
+            filled += 1;
         }
-    }
-    fn cols(&self) -> IterSlices<'_, R, C, T> {
-        IterSlices {
-            matrix_buffer: &self.inner[..],
+        let surplus = rows.count();
+        if filled < R || surplus > 0 {
+            return Err(MatrixError::SizeMismatch {
+                expected: R,
+                got: filled + surplus,
+            });
         }
+        // SAFETY: every one of the R rows wrote its C elements, which covers all S elements.
+        Ok(Self {
+            inner: inner.map(|el| unsafe { el.assume_init() }),
+            _prio: PhantomData,
+        })
     }
 
-    fn cols_mut(&mut self) -> IterSlicesMut<'_, R, C, T> {
-        IterSlicesMut {
-            matrix_buffer: &mut self.inner[..],
-        }
+    /// Constructs a Stacktrix where every element is zero.
+    pub fn zeros() -> Self
+    where
+        T: From<u8>,
+    {
+        Self::from_fn(|_, _| T::from(0))
     }
 
-    /// Applies a function on all elements of the matrix.
+    /// Constructs a Stacktrix with ones on the main diagonal and zeros everywhere else, the
+    /// matrix does not have to be square.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use mightrix::{ Stacktrix, ColumnPrio, ColumnPrioMatrix };
-    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
-    /// let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&mut data[..]);
-    /// m.apply_all(|el| *el *= 2);
-    /// assert_eq!(m.get_column(0), &[2,2,2,2]);
-    /// assert_eq!(m.get_column(1), &[4,4,4,4]);
-    /// assert_eq!(m.get_column(2), &[6,6,6,6]);
-    /// assert_eq!(m.get_column(3), &[8,8,8,8]);
-    /// ```
-    fn apply_all(&mut self, f: fn(&mut T)) {
-        for el in self.inner.iter_mut() {
-            f(el);
-        }
-    }
-
-    /// Prints out the matrix, this is only usefull for numeric types.
-    fn pretty_print(&self) {
-        let strings: Vec<Vec<String>> = (0..4)
-            .map(|i| {
-                self.get_row(i)
-                    .into_iter()
-                    .map(|el| format!("{:02x?}", el))
-                    .collect::<Vec<String>>()
-            })
-            .collect();
-        for v in strings {
-            for (i, s) in v.iter().enumerate() {
-                print!("{}", s);
-                if i != C - 1 {
-                    print!("-")
-                }
-            }
-            println!();
-        }
+    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix };
+    /// let m = Stacktrix::<6, 2, 3, RowPrio, f32>::identity();
+    /// assert_eq!(m.get_row(0), &[1.0, 0.0, 0.0]);
+    /// assert_eq!(m.get_row(1), &[0.0, 1.0, 0.0]);
+    /// ```
+    pub fn identity() -> Self
+    where
+        T: From<u8>,
+    {
+        Self::from_fn(|row, col| T::from((row == col) as u8))
     }
-}
 
-impl<'a, const S: usize, const R: usize, const C: usize, T> RowPrioMatrix<'a, R, C, T>
-    for Stacktrix<S, R, C, RowPrio, T>
-where
-    Self: 'a,
-    T: Copy + Default + Debug,
-{
-    /// Inserts a value at position (x, y) inside the matrix.
-    ///
-    /// # Panics
+    /// Constructs a Stacktrix of reproducible pseudo random values, e.g. as a test fixture.
     ///
-    /// If the location given is out of bounds in x or y the function panics.
+    /// The element at (row, col) is derived from seed and its logical index row * C + col by
+    /// the SplitMix64 generator, see [`PseudoRandom`] for the value ranges. The same seed
+    /// yields the same matrix on every platform and for both memory priorities.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix};
-    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
-    /// let mut m = Stacktrix::<16, 4, 4, RowPrio, u8>::from_values(&mut data[..]);
-    /// m.insert((3, 1), 0);
-    /// assert_eq!(m.get((3,1)), &0);
+    /// # use mightrix::{ Stacktrix, ColumnPrio, RowPrio };
+    /// let a = Stacktrix::<12, 3, 4, ColumnPrio, f64>::pseudo_random(42);
+    /// let b = Stacktrix::<12, 3, 4, RowPrio, f64>::pseudo_random(42);
+    /// assert_eq!(a, b);
+    /// assert!(a.as_slice().iter().all(|&el| (0.0..1.0).contains(&el)));
+    /// assert_ne!(a, Stacktrix::<12, 3, 4, ColumnPrio, f64>::pseudo_random(43));
     /// ```
-    fn insert(&mut self, location: Position, value: T) {
-        self.get_mut_row(location.0)[location.1] = value;
+    pub fn pseudo_random(seed: u64) -> Self
+    where
+        T: PseudoRandom,
+    {
+        Self::from_fn(|row, col| T::from_random_bits(splitmix64(seed, (row * C + col) as u64)))
     }
 
-    /// Get a immutable reference to a value in the matrix at location (x, y)
+    /// Copies the matrix into one of shape R2 x C2 with the same MemoryPriority. Elements that lie
+    /// inside both shapes keep their location, the rows and columns that are added are filled
+    /// with fill, the ones that are cut off are dropped.
     ///
-    /// # Panics
-    ///
-    /// If the location given is out of bounds in x or y the function panics.
+    /// A shape with S2 != R2 * C2 is rejected at compile time.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix};
-    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
-    /// let mut m = Stacktrix::<16, 4, 4, RowPrio, u8>::from_values(&mut data[..]);
-    /// assert_eq!(m.get((0, 2)), &1);
+    /// # use mightrix::{ Stacktrix, ColumnPrio };
+    /// let m = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let grown = m.resize::<9, 3, 3>(0);
+    /// assert_eq!(grown.as_slice(), &[1, 4, 0, 2, 5, 0, 3, 6, 0]);
+    /// let shrunk = m.resize::<2, 2, 1>(0);
+    /// assert_eq!(shrunk.as_slice(), &[1, 4]);
     /// ```
-    fn get(&self, location: Position) -> &T {
-        &self.get_row(location.0)[location.1]
+    pub fn resize<const S2: usize, const R2: usize, const C2: usize>(
+        &self,
+        fill: T,
+    ) -> Stacktrix<S2, R2, C2, MemoryPriority, T>
+    where
+        T: Copy,
+    {
+        Stacktrix::from_fn(|row, col| {
+            if row < R && col < C {
+                self.inner[MemoryPriority::offset((row, col), R, C)]
+            } else {
+                fill
+            }
+        })
     }
+}
 
-    /// Get a mutable reference to a value in the matrix at location (x, y)
-    ///
-    /// # Panics
-    ///
-    /// If the location given is out of bounds in x or y the function panics.
-    fn get_mut(&mut self, location: Position) -> &mut T {
-        &mut self.get_mut_row(location.0)[location.1]
-    }
+impl<const S: usize, const R: usize, const C: usize, MemoryPriority, T>
+    Stacktrix<S, R, C, MemoryPriority, T>
+{
+    /// Evaluated by the constructors, which turns a shape with S != R * C into a compile error.
+    const SHAPE: () = assert!(S == R * C, "a Stacktrix has to hold S == R * C elements");
 
-    /// Fills an entire column with the given data.
-    ///
-    /// # Panics
-    ///
-    /// If the column is out of bounds.
-    ///
-    /// If the data is not the size of a column.
+    /// Returns the underlying array as a slice in memory order.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix};
-    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
-    /// let mut m = Stacktrix::<16, 4, 4, RowPrio, u8>::from_values(&mut data[..]);
-    /// m.fill_col(1, &[7,7,7,7]);
-    /// assert_eq!(m.get((0,1)), &7);
-    /// assert_eq!(m.get((1,1)), &7);
-    /// assert_eq!(m.get((2,1)), &7);
-    /// assert_eq!(m.get((3,1)), &7);
-    /// ```
-    fn fill_col(&'a mut self, col: usize, data: &[T]) {
-        assert_eq!(data.len(), R);
-        for (dst, src) in self.get_mut_column(col).into_iter().zip(data.iter()) {
-            *dst = *src;
-        }
+    /// # use mightrix::{ Stacktrix, ColumnPrio };
+    /// let m = Stacktrix::<4, 2, 2, ColumnPrio, u8>::from_rows([[1, 2], [3, 4]]);
+    /// assert_eq!(m.as_slice(), &[1, 3, 2, 4]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.inner
     }
 
-    /// Fills an entire row with the given data.
-    ///
-    /// # Panics
-    ///
-    /// If the row is out of bounds.
-    ///
-    /// If the data is not the size of a row.
+    /// Returns the underlying array as a mutable slice in memory order.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+
+    /// Borrows the matrix as a [`Reftrix`], e.g. to pass it to code written for borrowed
+    /// matrices. No elements are copied.
     ///
     /// # Examples
     ///
     /// ```
     /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix };
-    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
-    /// let mut m = Stacktrix::<16, 4, 4, RowPrio, u8>::from_values(&mut data[..]);
-    /// m.fill_row(1, &[7,7,7,7]);
-    /// assert_eq!(m.get_row(1), &[7,7,7,7]);
+    /// let mut m = Stacktrix::<4, 2, 2, RowPrio, u8>::from_rows([[1, 2], [3, 4]]);
+    /// m.as_reftrix().fill_row(0, &[0, 0]);
+    /// assert_eq!(m.as_slice(), &[0, 0, 3, 4]);
     /// ```
-    fn fill_row(&mut self, row: usize, data: &[T]) {
-        assert_eq!(data.len(), C);
-        let start = row * C;
-        self.inner[start..start + C].copy_from_slice(data);
+    pub fn as_reftrix(&mut self) -> Reftrix<'_, R, C, MemoryPriority, T> {
+        Reftrix::from_array_mut(&mut self.inner)
     }
 
-    /// Retrieves a [`IntermittentSlice`].
+    /// Views an array as a Stacktrix without copying it, see the [layout](Stacktrix#layout)
+    /// guarantees.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// If the col is out of bounds.
-    fn get_column(&self, col: usize) -> IntermittentSlice<'_, R, C, T> {
-        assert!(
-            col < C,
-            "Column: {} out of bounds {}, be carefull columns are 0 indexed.",
-            col,
-            C
-        );
-        IntermittentSlice {
-            start: &self.inner[col],
-        }
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, Export };
+    /// let data = [1, 2, 3, 4, 5, 6];
+    /// let m: &Stacktrix<6, 3, 2, ColumnPrio, u8> = Stacktrix::from_array_ref(&data);
+    /// assert_eq!(m.to_rows(), [[1, 4], [2, 5], [3, 6]]);
+    /// ```
+    pub fn from_array_ref(inner_values: &[T; S]) -> &Self {
+        let () = Self::SHAPE;
+        // SAFETY: Stacktrix is repr(transparent) over [T; S].
+        unsafe { &*(inner_values as *const [T; S] as *const Self) }
     }
 
-    /// Retrieves a [`IntermittentSliceMut`].
-    ///
-    /// # Panics
-    ///
-    /// If the col is out of bounds.
-    fn get_mut_column(&mut self, col: usize) -> IntermittentSliceMut<'_, R, C, T> {
-        assert!(
-            col < C,
-            "Column: {} out of bounds {}, be carefull columns are 0 indexed.",
-            col,
-            C
-        );
-        IntermittentSliceMut {
-            start: &mut self.inner[col],
-        }
+    /// Views an array as a Stacktrix mutably without copying it, see
+    /// [`from_array_ref`](Stacktrix::from_array_ref).
+    pub fn from_array_mut(inner_values: &mut [T; S]) -> &mut Self {
+        let () = Self::SHAPE;
+        // SAFETY: Stacktrix is repr(transparent) over [T; S].
+        unsafe { &mut *(inner_values as *mut [T; S] as *mut Self) }
     }
 
-    /// Retrieves a immutable slice that represents the row.
-    ///
-    /// # Panics
+    /// Returns the underlying array.
+    pub fn as_array(&self) -> &[T; S] {
+        &self.inner
+    }
+
+    /// Consumes the matrix and returns the underlying array.
+    pub fn into_inner(self) -> [T; S] {
+        self.inner
+    }
+
+    /// Reinterprets the elements as a matrix of shape R2 x C2 without moving them. The elements
+    /// keep their position in memory, so a [`RowPrio`](crate::RowPrio) matrix is read row by
+    /// row, a [`ColumnPrio`](crate::ColumnPrio) matrix column by column.
     ///
-    /// If the row is out of bounds.
+    /// A shape with S != R2 * C2 is rejected at compile time.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix};
-    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
-    /// let mut m = Stacktrix::<16, 4, 4, RowPrio, u8>::from_values(&mut data[..]);
-    /// assert_eq!(m.get_row(0), &[1,1,1,1]);
-    /// ```
-    fn get_row(&self, row: usize) -> &[T] {
-        assert!(
-            row < R,
-            "Row: {} out of bounds {}, be carefull rows are 0 indexed.",
-            row,
-            R
-        );
-        let start = row * C;
-        &self.inner[start..start + C]
+    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix };
+    /// let m = Stacktrix::<6, 2, 3, RowPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let m = m.reshape::<3, 2>();
+    /// assert_eq!(m.get_row(1), &[3, 4]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use mightrix::{ Stacktrix, RowPrio };
+    /// let m = Stacktrix::<6, 2, 3, RowPrio, u8>::with_values([1, 2, 3, 4, 5, 6]);
+    /// let m = m.reshape::<2, 2>();
+    /// ```
+    pub fn reshape<const R2: usize, const C2: usize>(
+        self,
+    ) -> Stacktrix<S, R2, C2, MemoryPriority, T> {
+        let () = Stacktrix::<S, R2, C2, MemoryPriority, T>::SHAPE;
+        Stacktrix {
+            inner: self.inner,
+            _prio: PhantomData,
+        }
     }
 
-    /// Retrieves a mutable slice that represents the row.
+    /// Moves the matrix to the heap and leaks it, e.g. to build a lookup table once at startup
+    /// that lives for the rest of the program. The returned Reftrix keeps the shape and memory
+    /// priority, [`Reftrix::into_inner`] gives back the raw slice and
+    /// [`Reftrix::from_values`] wraps it again.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// If the row is out of bounds.
-    fn get_mut_row(&mut self, row: usize) -> &mut [T] {
-        assert!(
-            row < R,
-            "Row: {} out of bounds {}, be carefull rows are 0 indexed.",
-            row,
-            R
-        );
-        let start = row * C;
-        &mut self.inner[start..start + C]
+    /// ```
+    /// # use mightrix::{ Reftrix, Stacktrix, RowPrio, RowPrioMatrix };
+    /// let table: Reftrix<'static, 2, 2, RowPrio, u16> =
+    ///     Stacktrix::<4, 2, 2, RowPrio, u16>::from_fn(|row, col| (row * 2 + col) as u16).leak();
+    /// assert_eq!(table.get_row(1), &[2, 3]);
+    /// let raw: &'static mut [u16] = table.into_inner();
+    /// let table = Reftrix::<2, 2, RowPrio, u16>::from_values(raw);
+    /// assert_eq!(*table.get((0, 1)), 1);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn leak(self) -> Reftrix<'static, R, C, MemoryPriority, T>
+    where
+        T: 'static,
+    {
+        let inner: &'static mut [T; S] =
+            alloc::boxed::Box::leak(alloc::boxed::Box::new(self.inner));
+        Reftrix::from_values(inner)
     }
+}
 
-    fn rows(&self) -> IterSlices<'_, R, C, T> {
-        IterSlices {
-            matrix_buffer: &self.inner,
-        }
+// The layout guarantees do not depend on the element type, the shape or the memory priority.
+const _: () = {
+    use core::mem::{align_of, size_of};
+    assert!(size_of::<Stacktrix<16, 4, 4, crate::ColumnPrio, u8>>() == size_of::<[u8; 16]>());
+    assert!(align_of::<Stacktrix<6, 2, 3, crate::RowPrio, u64>>() == align_of::<[u64; 6]>());
+    assert!(size_of::<Stacktrix<0, 0, 0, crate::RowPrio, u128>>() == 0);
+};
+
+/// Reorders the rows of an R x C matrix, stored one after the other, into the columns one after
+/// the other. Used by [`stacktrix!`](crate::stacktrix!) in const contexts.
+#[doc(hidden)]
+pub const fn rows_to_columns<const S: usize, const R: usize, const C: usize, T: Copy>(
+    values: [T; S],
+) -> [T; S] {
+    assert!(S == R * C);
+    let mut columns = values;
+    let mut i = 0;
+    while i < S {
+        columns[(i % C) * R + i / C] = values[i];
+        i += 1;
+    }
+    columns
+}
+
+// SAFETY: the constructors reject shapes with S != R * C at compile time.
+unsafe impl<const S: usize, const R: usize, const C: usize, MemoryPriority, T> Storage<R, C, T>
+    for Stacktrix<S, R, C, MemoryPriority, T>
+where
+    MemoryPriority: crate::MemoryPriority,
+{
+    type Priority = MemoryPriority;
+
+    fn buffer(&self) -> &[T] {
+        &self.inner
     }
-    fn rows_mut(&mut self) -> IterSlicesMut<'_, R, C, T> {
-        IterSlicesMut {
-            matrix_buffer: &mut self.inner,
-        }
+}
+
+// SAFETY: buffer_mut returns the same elements as buffer.
+unsafe impl<const S: usize, const R: usize, const C: usize, MemoryPriority, T> StorageMut<R, C, T>
+    for Stacktrix<S, R, C, MemoryPriority, T>
+where
+    MemoryPriority: crate::MemoryPriority,
+{
+    fn buffer_mut(&mut self) -> &mut [T] {
+        &mut self.inner
     }
-    fn cols(&self) -> IterIntermittentSlices<'_, R, C, T> {
-        IterIntermittentSlices {
-            slice_index: 0,
-            matrix_buffer: &self.inner[..],
-        }
+}
+
+impl<const S: usize, const R: usize, const C: usize, MemoryPriority, T> Display
+    for Stacktrix<S, R, C, MemoryPriority, T>
+where
+    MemoryPriority: crate::MemoryPriority,
+    T: Display,
+{
+    /// Formats the matrix as a grid, the precision is applied to every cell.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Grid::<R, C, MemoryPriority, T, _>::new(&self.inner, display_cell(f.precision())).fmt(f)
     }
+}
 
-    fn cols_mut(&mut self) -> IterMutIntermittentSlices<'_, R, C, T> {
-        IterMutIntermittentSlices {
-            slice_index: 0,
-            matrix_buffer: &mut self.inner[..],
-        }
+impl<const S: usize, const R: usize, const C: usize, MemoryPriority, T> Debug
+    for Stacktrix<S, R, C, MemoryPriority, T>
+where
+    MemoryPriority: crate::MemoryPriority,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Grid::<R, C, MemoryPriority, T, _>::new(&self.inner, |el, f| Debug::fmt(el, f)).fmt(f)
     }
+}
 
-    /// Applies a function on all elements of the matrix.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// # use mightrix::{ Stacktrix, RowPrio, RowPrioMatrix };
-    /// let mut data = vec![1,1,1,1,2,2,2,2,3,3,3,3,4,4,4,4];
-    /// let mut m = Stacktrix::<16, 4, 4, RowPrio, u8>::from_values(&mut data[..]);
-    /// m.apply_all(|el| *el *= 2);
-    /// assert_eq!(m.get_row(0), &[2,2,2,2]);
-    /// assert_eq!(m.get_row(1), &[4,4,4,4]);
-    /// assert_eq!(m.get_row(2), &[6,6,6,6]);
-    /// assert_eq!(m.get_row(3), &[8,8,8,8]);
-    /// ```
-    fn apply_all(&mut self, f: fn(&mut T)) {
-        for el in self.inner.iter_mut() {
-            f(el);
-        }
+impl<const S: usize, const R: usize, const C: usize, P, Q, T> PartialEq<Stacktrix<S, R, C, Q, T>>
+    for Stacktrix<S, R, C, P, T>
+where
+    P: crate::MemoryPriority,
+    Q: crate::MemoryPriority,
+    T: PartialEq,
+{
+    /// Matrices are equal if they hold equal elements at every location, independent of their
+    /// memory priorities.
+    fn eq(&self, other: &Stacktrix<S, R, C, Q, T>) -> bool {
+        logical_eq(self, other)
     }
+}
 
-    /// Prints out the matrix, this is only usefull for numeric types.
-    fn pretty_print(&self) {
-        let strings: Vec<String> = self.inner.iter().map(|el| format!("{:02x?}", el)).collect();
-        let _column_width = strings.iter().map(|el| el.len()).max();
-        let mut index = 0;
-        for _ in 0..R {
-            for i in 0..C {
-                print!("{}", strings[index]);
-                if i != C - 1 {
-                    print!("-")
-                }
-                index += 1;
-            }
-            println!();
-        }
+impl<'a, const S: usize, const R: usize, const C: usize, P, Q, T> PartialEq<Reftrix<'a, R, C, Q, T>>
+    for Stacktrix<S, R, C, P, T>
+where
+    P: crate::MemoryPriority,
+    Q: crate::MemoryPriority,
+    T: PartialEq,
+{
+    fn eq(&self, other: &Reftrix<'a, R, C, Q, T>) -> bool {
+        logical_eq(self, other)
+    }
+}
+
+impl<const S: usize, const R: usize, const C: usize, P, T> Eq for Stacktrix<S, R, C, P, T>
+where
+    P: crate::MemoryPriority,
+    T: Eq,
+{
+}
+
+impl<const S: usize, const R: usize, const C: usize, P, T> Hash for Stacktrix<S, R, C, P, T>
+where
+    P: crate::MemoryPriority,
+    T: Hash,
+{
+    /// Hashes the elements in logical order, consistent with equality across memory priorities.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        logical_hash(self, state);
     }
 }
 
@@ -559,8 +628,8 @@ mod test {
     use crate::{ColumnPrio, ColumnPrioMatrix, Stacktrix};
     #[test]
     fn iter_rows_owned() {
-        let mut values = vec![1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4];
-        let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&mut values);
+        let values = [1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4];
+        let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&values);
         for row in m.rows_mut() {
             for (i, el) in row.into_iter().enumerate() {
                 *el += i as u8;
@@ -574,10 +643,10 @@ mod test {
 
     #[test]
     fn iter_cols_owned() {
-        let mut values = vec![1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4];
-        let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&mut values);
+        let values = [1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4];
+        let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&values);
         for col in m.cols_mut() {
-            for (i, el) in col.into_iter().enumerate() {
+            for (i, el) in col.iter_mut().enumerate() {
                 *el += i as u8;
             }
         }
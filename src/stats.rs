@@ -0,0 +1,109 @@
+use crate::{
+    storage::{Storage, StorageMut},
+    MemoryPriority,
+};
+use core::ops::{Add, Div, Mul, Sub};
+
+/// OnlineStats accumulates the per cell mean and variance of a stream of matrices of the same
+/// shape, e.g. sensor frames, without keeping the samples.
+///
+/// The running mean and the sum of squared deviations M2 are kept in two matrices and updated
+/// with Welford's algorithm, which stays accurate for long streams.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ OnlineStats, Stacktrix, ColumnPrio, RowPrio, Export };
+/// let mut stats = OnlineStats::new(
+///     Stacktrix::<2, 1, 2, RowPrio, f64>::zeros(),
+///     Stacktrix::<2, 1, 2, RowPrio, f64>::zeros(),
+/// );
+/// for sample in [[1.0, 10.0], [2.0, 10.0], [3.0, 10.0]] {
+///     stats.update(&Stacktrix::<2, 1, 2, ColumnPrio, f64>::from_rows([sample]));
+/// }
+/// assert_eq!(stats.mean().to_rows(), [[2.0, 10.0]]);
+/// assert_eq!(stats.variance(), Some([[1.0, 0.0]]));
+/// ```
+pub struct OnlineStats<M, const R: usize, const C: usize, T> {
+    mean: M,
+    m2: M,
+    count: usize,
+    // count as T, so the updates do not need to convert it.
+    n: T,
+}
+
+impl<M, const R: usize, const C: usize, T> OnlineStats<M, R, C, T>
+where
+    M: StorageMut<R, C, T>,
+    T: Copy + From<u8> + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Constructs an OnlineStats that has seen no sample, the matrices are set to zero.
+    pub fn new(mut mean: M, mut m2: M) -> Self {
+        mean.buffer_mut().fill(T::from(0));
+        m2.buffer_mut().fill(T::from(0));
+        Self {
+            mean,
+            m2,
+            count: 0,
+            n: T::from(0),
+        }
+    }
+
+    /// Adds a sample, its memory priority may differ from the one of the accumulators.
+    pub fn update<O>(&mut self, sample: &O)
+    where
+        O: Storage<R, C, T>,
+    {
+        self.count += 1;
+        self.n = self.n + T::from(1);
+        let n = self.n;
+        let (mean, m2, sample) = (
+            self.mean.buffer_mut(),
+            self.m2.buffer_mut(),
+            sample.buffer(),
+        );
+        for row in 0..R {
+            for col in 0..C {
+                let at = M::Priority::offset((row, col), R, C);
+                let x = sample[O::Priority::offset((row, col), R, C)];
+                let delta = x - mean[at];
+                mean[at] = mean[at] + delta / n;
+                m2[at] = m2[at] + delta * (x - mean[at]);
+            }
+        }
+    }
+
+    /// Returns the amount of samples seen.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the per cell mean of the samples, zero if no sample was seen.
+    pub fn mean(&self) -> &M {
+        &self.mean
+    }
+
+    /// Returns the per cell sum of squared deviations from the mean, divide it by
+    /// [`count`](OnlineStats::count) for the population variance.
+    pub fn m2(&self) -> &M {
+        &self.m2
+    }
+
+    /// Returns the per cell sample variance M2 / (count - 1) in logical order, or None if fewer
+    /// than two samples were seen.
+    pub fn variance(&self) -> Option<[[T; C]; R]> {
+        if self.count < 2 {
+            return None;
+        }
+        let n = self.n - T::from(1);
+        let m2 = self.m2.buffer();
+        Some(core::array::from_fn(|row| {
+            core::array::from_fn(|col| m2[M::Priority::offset((row, col), R, C)] / n)
+        }))
+    }
+
+    /// Consumes the OnlineStats and returns the mean and the M2 matrix.
+    pub fn into_inner(self) -> (M, M) {
+        (self.mean, self.m2)
+    }
+}
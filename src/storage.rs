@@ -0,0 +1,1186 @@
+use crate::{
+    ColumnPrio, ColumnPrioMatrix, DiagonalSlice, DiagonalSliceMut, Grid, IntermittentSlice,
+    IntermittentSliceMut, IterIntermittentSlices, IterMutIntermittentSlices, IterSlices,
+    IterSlicesMut, MatrixError, MemoryPriority, Position, RowPrio, RowPrioMatrix,
+};
+use core::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+};
+#[cfg(feature = "std")]
+use std::io;
+
+/// Storage grants the matrix implementations read access to the continuous memory of a
+/// matrix type, [`StorageMut`] adds write access.
+///
+/// The matrix traits are implemented once for every Storage, the memory interpretation is
+/// given by Priority. Implementing Storage for a type of your own, e.g. a matrix in a shared
+/// memory segment or in host memory mapped for a GPU, gives it the whole API of [`Reftrix`] and
+/// [`Stacktrix`]: [`ColumnPrioMatrix`] or [`RowPrioMatrix`] depending on the Priority, and the
+/// extension traits like [`Export`](crate::Export) or [`Reduce`](crate::Reduce). Methods that
+/// write to the matrix require [`StorageMut`] as well, a read only backing implements Storage
+/// alone and gets every method that only reads.
+///
+/// The buffer has to be dense: element (row, col) lives at
+/// [`MemoryPriority::offset`](crate::MemoryPriority::offset) and there is no padding between
+/// rows or columns. Backings with a stride of their own are not supported, borrow them as a
+/// [`MatrixView`](crate::MatrixView) or [`Strided`](crate::Strided) instead.
+///
+/// # Safety
+///
+/// [`buffer`](Storage::buffer) has to return a slice of exactly R * C elements. The strided
+/// rows and columns are accessed without bounds checks relying on that length.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::{ ColumnPrio, ColumnPrioMatrix, Export, Reduce, Storage, StorageMut };
+/// /// Samples of three channels, recorded one channel after the other.
+/// struct Recording {
+///     samples: Vec<f32>,
+/// }
+///
+/// // SAFETY: the recording always holds 4 samples of each of the 3 channels.
+/// unsafe impl Storage<4, 3, f32> for Recording {
+///     type Priority = ColumnPrio;
+///
+///     fn buffer(&self) -> &[f32] {
+///         &self.samples
+///     }
+/// }
+///
+/// // SAFETY: buffer_mut returns the same 12 samples as buffer.
+/// unsafe impl StorageMut<4, 3, f32> for Recording {
+///     fn buffer_mut(&mut self) -> &mut [f32] {
+///         &mut self.samples
+///     }
+/// }
+///
+/// let mut recording = Recording { samples: vec![0.0; 12] };
+/// recording.fill_col(1, &[1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(recording.col_sums(), [0.0, 10.0, 0.0]);
+/// assert_eq!(recording.to_rows()[3], [0.0, 4.0, 0.0]);
+/// ```
+///
+/// [`Reftrix`]: crate::Reftrix
+/// [`Stacktrix`]: crate::Stacktrix
+pub unsafe trait Storage<const R: usize, const C: usize, T> {
+    /// The memory priority the buffer is interpreted with.
+    type Priority: MemoryPriority;
+
+    /// Returns the R * C elements in memory order.
+    fn buffer(&self) -> &[T];
+}
+
+/// StorageMut grants the matrix implementations write access to the memory of a [`Storage`].
+///
+/// # Safety
+///
+/// [`buffer_mut`](StorageMut::buffer_mut) has to return the same R * C elements as
+/// [`buffer`](Storage::buffer).
+///
+/// # Examples
+///
+/// A read only backing, e.g. a mapped file, implements [`Storage`] alone and can not be
+/// written to:
+///
+/// ```compile_fail
+/// # use mightrix::{ RowPrio, RowPrioMatrix, Storage };
+/// struct Mapped<'a>(&'a [u8]);
+///
+/// // SAFETY: Mapped is only constructed from 6 bytes.
+/// unsafe impl Storage<2, 3, u8> for Mapped<'_> {
+///     type Priority = RowPrio;
+///
+///     fn buffer(&self) -> &[u8] {
+///         self.0
+///     }
+/// }
+///
+/// let mut m = Mapped(&[1, 2, 3, 4, 5, 6]);
+/// assert_eq!(m.get_row(1), &[4, 5, 6]);
+/// m.fill_row(0, &[0, 0, 0]);
+/// ```
+pub unsafe trait StorageMut<const R: usize, const C: usize, T>: Storage<R, C, T> {
+    /// Returns the R * C elements in memory order mutably.
+    fn buffer_mut(&mut self) -> &mut [T];
+}
+
+/// Whether the memory priorities P and Q lay out a matrix with the given amount of rows and
+/// columns with the same strides, so that its buffers can be compared or copied as a whole.
+pub(crate) fn same_layout<P, Q>(rows: usize, cols: usize) -> bool
+where
+    P: MemoryPriority,
+    Q: MemoryPriority,
+{
+    P::row_stride(rows, cols) == Q::row_stride(rows, cols)
+        && P::col_stride(rows, cols) == Q::col_stride(rows, cols)
+}
+
+/// Compares two matrices element wise in logical order, independent of their memory
+/// priorities.
+pub(crate) fn logical_eq<A, B, const R: usize, const C: usize, T>(a: &A, b: &B) -> bool
+where
+    A: Storage<R, C, T>,
+    B: Storage<R, C, T>,
+    T: PartialEq,
+{
+    let (a, b) = (a.buffer(), b.buffer());
+    if same_layout::<A::Priority, B::Priority>(R, C) {
+        return a == b;
+    }
+    (0..R).all(|row| {
+        (0..C).all(|col| {
+            a[A::Priority::offset((row, col), R, C)] == b[B::Priority::offset((row, col), R, C)]
+        })
+    })
+}
+
+/// Hashes all elements in logical row by row order, so matrices that are equal across memory
+/// priorities hash equally.
+pub(crate) fn logical_hash<M, const R: usize, const C: usize, T, H>(m: &M, state: &mut H)
+where
+    M: Storage<R, C, T>,
+    T: Hash,
+    H: Hasher,
+{
+    let buffer = m.buffer();
+    for row in 0..R {
+        for col in 0..C {
+            buffer[M::Priority::offset((row, col), R, C)].hash(state);
+        }
+    }
+}
+
+/// Applies f to every element of buffer, chunk by chunk, prefetching the following chunk if
+/// requested.
+fn apply_chunked<T, F>(buffer: &mut [T], chunk_len: usize, prefetch: bool, mut f: F)
+where
+    F: FnMut(&mut T),
+{
+    assert!(chunk_len > 0, "chunk_len has to be at least 1");
+    let mut chunks = buffer.chunks_mut(chunk_len).peekable();
+    while let Some(chunk) = chunks.next() {
+        if prefetch {
+            if let Some(next) = chunks.peek() {
+                crate::simd::prefetch(next);
+            }
+        }
+        chunk.iter_mut().for_each(&mut f);
+    }
+}
+
+/// The location of the element at index in the memory of a matrix with the given amount of
+/// rows and columns, the inverse of [`MemoryPriority::offset`].
+pub(crate) fn location<P: MemoryPriority>(index: usize, rows: usize, cols: usize) -> Position {
+    if P::row_stride(rows, cols) == 1 {
+        (index % rows, index / rows)
+    } else {
+        (index / cols, index % cols)
+    }
+}
+
+pub(crate) fn assert_row<const R: usize>(row: usize) {
+    assert!(
+        row < R,
+        "Row: {} out of bounds {}, be carefull rows are 0 indexed.",
+        row,
+        R
+    );
+}
+
+pub(crate) fn assert_col<const C: usize>(col: usize) {
+    assert!(
+        col < C,
+        "Column: {} out of bounds {}, be carefull columns are 0 indexed.",
+        col,
+        C
+    );
+}
+
+fn element<const R: usize, const C: usize, P: MemoryPriority, T>(
+    buffer: &[T],
+    location: Position,
+) -> &T {
+    assert_row::<R>(location.0);
+    assert_col::<C>(location.1);
+    &buffer[P::offset(location, R, C)]
+}
+
+fn element_mut<const R: usize, const C: usize, P: MemoryPriority, T>(
+    buffer: &mut [T],
+    location: Position,
+) -> &mut T {
+    assert_row::<R>(location.0);
+    assert_col::<C>(location.1);
+    &mut buffer[P::offset(location, R, C)]
+}
+
+fn try_element<const R: usize, const C: usize, P: MemoryPriority, T>(
+    buffer: &[T],
+    location: Position,
+) -> Option<&T> {
+    if location.0 < R && location.1 < C {
+        Some(&buffer[P::offset(location, R, C)])
+    } else {
+        None
+    }
+}
+
+fn try_element_mut<const R: usize, const C: usize, P: MemoryPriority, T>(
+    buffer: &mut [T],
+    location: Position,
+) -> Option<&mut T> {
+    if location.0 < R && location.1 < C {
+        Some(&mut buffer[P::offset(location, R, C)])
+    } else {
+        None
+    }
+}
+
+fn disjoint_elements_mut<const R: usize, const C: usize, P: MemoryPriority, T, const N: usize>(
+    buffer: &mut [T],
+    locations: [Position; N],
+) -> Option<[&mut T; N]> {
+    let mut offsets = [0; N];
+    for (offset, location) in offsets.iter_mut().zip(locations) {
+        if location.0 >= R || location.1 >= C {
+            return None;
+        }
+        *offset = P::offset(location, R, C);
+    }
+    buffer.get_disjoint_mut(offsets).ok()
+}
+
+fn check_row<const R: usize>(row: usize) -> Result<(), MatrixError> {
+    if row < R {
+        Ok(())
+    } else {
+        Err(MatrixError::RowOutOfBounds { row, rows: R })
+    }
+}
+
+fn check_col<const C: usize>(col: usize) -> Result<(), MatrixError> {
+    if col < C {
+        Ok(())
+    } else {
+        Err(MatrixError::ColumnOutOfBounds { col, cols: C })
+    }
+}
+
+fn check_len<T>(expected: usize, data: &[T]) -> Result<(), MatrixError> {
+    if data.len() == expected {
+        Ok(())
+    } else {
+        Err(MatrixError::SizeMismatch {
+            expected,
+            got: data.len(),
+        })
+    }
+}
+
+fn out_of_bounds<const R: usize, const C: usize>(location: Position) -> MatrixError {
+    MatrixError::OutOfBounds {
+        row: location.0,
+        col: location.1,
+        rows: R,
+        cols: C,
+    }
+}
+
+/// Returns the index-th continuous lane of length L.
+fn lane<const L: usize, T>(buffer: &[T], index: usize) -> &[T] {
+    let start = index * L;
+    &buffer[start..start + L]
+}
+
+/// Returns the index-th continuous lane of length L.
+fn lane_mut<const L: usize, T>(buffer: &mut [T], index: usize) -> &mut [T] {
+    let start = index * L;
+    &mut buffer[start..start + L]
+}
+
+/// Splits buffer so that a and b can be borrowed mutably at the same time, the first half holds
+/// the lower of both.
+fn split_pair<T>(buffer: &mut [T], a: usize, b: usize) -> (&mut [T], &mut [T]) {
+    assert!(a != b, "Cannot borrow {a} mutably twice.");
+    buffer.split_at_mut(a.max(b))
+}
+
+/// Returns the a-th and the b-th continuous lane of length L.
+fn lanes_mut<const L: usize, T>(buffer: &mut [T], a: usize, b: usize) -> (&mut [T], &mut [T]) {
+    let (lo, hi) = split_pair(buffer, a * L, b * L);
+    let (first, second) = (&mut lo[a.min(b) * L..][..L], &mut hi[..L]);
+    if a < b {
+        (first, second)
+    } else {
+        (second, first)
+    }
+}
+
+/// Returns the a-th and the b-th strided lane of length S with a stride of A.
+fn intermittent_pair_mut<const A: usize, const S: usize, T>(
+    buffer: &mut [T],
+    a: usize,
+    b: usize,
+) -> (
+    IntermittentSliceMut<'_, A, S, T>,
+    IntermittentSliceMut<'_, A, S, T>,
+) {
+    assert!(a != b, "Cannot borrow {a} mutably twice.");
+    assert!(a < A && b < A, "lane out of bounds");
+    assert_eq!(buffer.len(), A * S);
+    // The lanes interleave, so they cannot be split apart; both pointers are derived from the
+    // pointer to the whole buffer instead.
+    let start = buffer.as_mut_ptr();
+    // SAFETY: a and b are distinct lanes of the buffer, which stays borrowed for as long as they.
+    unsafe {
+        (
+            IntermittentSliceMut::from_raw(start.add(a)),
+            IntermittentSliceMut::from_raw(start.add(b)),
+        )
+    }
+}
+
+/// The bodies of the [`ColumnPrioMatrix`] and [`RowPrioMatrix`] methods that do not depend on
+/// the memory priority. Both trait impls forward to them, so the layouts can not drift apart.
+pub(crate) mod dense {
+    use super::*;
+
+    /// Returns the len elements that start at start and lie stride apart.
+    fn strided_mut<T>(
+        buffer: &mut [T],
+        start: usize,
+        stride: usize,
+        len: usize,
+    ) -> impl Iterator<Item = &mut T> {
+        // An empty lane may start past the end of an empty buffer, skip tolerates that.
+        buffer
+            .iter_mut()
+            .skip(start)
+            .step_by(stride.max(1))
+            .take(len)
+    }
+
+    /// Returns the elements of the row row mutably.
+    fn row_mut<P: MemoryPriority, const R: usize, const C: usize, T>(
+        buffer: &mut [T],
+        row: usize,
+    ) -> impl Iterator<Item = &mut T> {
+        assert_row::<R>(row);
+        strided_mut(buffer, P::offset((row, 0), R, C), P::col_stride(R, C), C)
+    }
+
+    /// Returns the elements of the column col mutably.
+    fn col_mut<P: MemoryPriority, const R: usize, const C: usize, T>(
+        buffer: &mut [T],
+        col: usize,
+    ) -> impl Iterator<Item = &mut T> {
+        assert_col::<C>(col);
+        strided_mut(buffer, P::offset((0, col), R, C), P::row_stride(R, C), R)
+    }
+
+    pub(crate) fn insert<M, const R: usize, const C: usize, T>(
+        m: &mut M,
+        location: Position,
+        value: T,
+    ) where
+        M: StorageMut<R, C, T>,
+    {
+        *element_mut::<R, C, M::Priority, T>(m.buffer_mut(), location) = value;
+    }
+
+    pub(crate) fn get<M, const R: usize, const C: usize, T>(m: &M, location: Position) -> &T
+    where
+        M: Storage<R, C, T>,
+    {
+        element::<R, C, M::Priority, T>(m.buffer(), location)
+    }
+
+    pub(crate) fn get_mut<M, const R: usize, const C: usize, T>(
+        m: &mut M,
+        location: Position,
+    ) -> &mut T
+    where
+        M: StorageMut<R, C, T>,
+    {
+        element_mut::<R, C, M::Priority, T>(m.buffer_mut(), location)
+    }
+
+    pub(crate) fn fill_row<M, const R: usize, const C: usize, T>(m: &mut M, row: usize, data: &[T])
+    where
+        M: StorageMut<R, C, T>,
+        T: Copy,
+    {
+        assert_eq!(data.len(), C, "a row holds {C} elements");
+        row_mut::<M::Priority, R, C, T>(m.buffer_mut(), row)
+            .zip(data)
+            .for_each(|(dst, &src)| *dst = src);
+    }
+
+    pub(crate) fn fill_col<M, const R: usize, const C: usize, T>(m: &mut M, col: usize, data: &[T])
+    where
+        M: StorageMut<R, C, T>,
+        T: Copy,
+    {
+        assert_eq!(data.len(), R, "a column holds {R} elements");
+        col_mut::<M::Priority, R, C, T>(m.buffer_mut(), col)
+            .zip(data)
+            .for_each(|(dst, &src)| *dst = src);
+    }
+
+    pub(crate) fn fill<M, const R: usize, const C: usize, T>(m: &mut M, value: T)
+    where
+        M: StorageMut<R, C, T>,
+        T: Copy,
+    {
+        m.buffer_mut().fill(value);
+    }
+
+    pub(crate) fn fill_with<M, const R: usize, const C: usize, T, F>(m: &mut M, f: F)
+    where
+        M: StorageMut<R, C, T>,
+        F: FnMut() -> T,
+    {
+        m.buffer_mut().fill_with(f);
+    }
+
+    pub(crate) fn apply_row<M, const R: usize, const C: usize, T, F>(m: &mut M, row: usize, f: F)
+    where
+        M: StorageMut<R, C, T>,
+        F: FnMut(&mut T),
+    {
+        row_mut::<M::Priority, R, C, T>(m.buffer_mut(), row).for_each(f);
+    }
+
+    pub(crate) fn apply_col<M, const R: usize, const C: usize, T, F>(m: &mut M, col: usize, f: F)
+    where
+        M: StorageMut<R, C, T>,
+        F: FnMut(&mut T),
+    {
+        col_mut::<M::Priority, R, C, T>(m.buffer_mut(), col).for_each(f);
+    }
+
+    pub(crate) fn try_insert<M, const R: usize, const C: usize, T>(
+        m: &mut M,
+        location: Position,
+        value: T,
+    ) -> Result<(), MatrixError>
+    where
+        M: StorageMut<R, C, T>,
+    {
+        let el = try_element_mut::<R, C, M::Priority, T>(m.buffer_mut(), location)
+            .ok_or(out_of_bounds::<R, C>(location))?;
+        *el = value;
+        Ok(())
+    }
+
+    pub(crate) fn try_get<M, const R: usize, const C: usize, T>(
+        m: &M,
+        location: Position,
+    ) -> Option<&T>
+    where
+        M: Storage<R, C, T>,
+    {
+        try_element::<R, C, M::Priority, T>(m.buffer(), location)
+    }
+
+    pub(crate) fn try_get_mut<M, const R: usize, const C: usize, T>(
+        m: &mut M,
+        location: Position,
+    ) -> Option<&mut T>
+    where
+        M: StorageMut<R, C, T>,
+    {
+        try_element_mut::<R, C, M::Priority, T>(m.buffer_mut(), location)
+    }
+
+    pub(crate) fn get_disjoint_mut<M, const R: usize, const C: usize, T, const N: usize>(
+        m: &mut M,
+        locations: [Position; N],
+    ) -> Option<[&mut T; N]>
+    where
+        M: StorageMut<R, C, T>,
+    {
+        disjoint_elements_mut::<R, C, M::Priority, T, N>(m.buffer_mut(), locations)
+    }
+
+    pub(crate) fn try_fill_row<M, const R: usize, const C: usize, T>(
+        m: &mut M,
+        row: usize,
+        data: &[T],
+    ) -> Result<(), MatrixError>
+    where
+        M: StorageMut<R, C, T>,
+        T: Copy,
+    {
+        check_row::<R>(row)?;
+        check_len(C, data)?;
+        fill_row(m, row, data);
+        Ok(())
+    }
+
+    pub(crate) fn try_fill_col<M, const R: usize, const C: usize, T>(
+        m: &mut M,
+        col: usize,
+        data: &[T],
+    ) -> Result<(), MatrixError>
+    where
+        M: StorageMut<R, C, T>,
+        T: Copy,
+    {
+        check_col::<C>(col)?;
+        check_len(R, data)?;
+        fill_col(m, col, data);
+        Ok(())
+    }
+
+    pub(crate) fn diagonal<M, const R: usize, const C: usize, T>(
+        m: &M,
+        anti: bool,
+    ) -> DiagonalSlice<'_, T>
+    where
+        M: Storage<R, C, T>,
+    {
+        DiagonalSlice::new::<M::Priority, R, C>(m.buffer(), anti)
+    }
+
+    pub(crate) fn diagonal_mut<M, const R: usize, const C: usize, T>(
+        m: &mut M,
+        anti: bool,
+    ) -> DiagonalSliceMut<'_, T>
+    where
+        M: StorageMut<R, C, T>,
+    {
+        DiagonalSliceMut::new::<M::Priority, R, C>(m.buffer_mut(), anti)
+    }
+
+    pub(crate) fn fill_diagonal<M, const R: usize, const C: usize, T>(m: &mut M, value: T)
+    where
+        M: StorageMut<R, C, T>,
+        T: Copy,
+    {
+        diagonal_mut(m, false).fill(value);
+    }
+
+    pub(crate) fn apply_all<M, const R: usize, const C: usize, T, F>(m: &mut M, f: F)
+    where
+        M: StorageMut<R, C, T>,
+        F: FnMut(&mut T),
+    {
+        m.buffer_mut().iter_mut().for_each(f);
+    }
+
+    pub(crate) fn apply_all_chunked<M, const R: usize, const C: usize, T, F>(
+        m: &mut M,
+        chunk_len: usize,
+        prefetch: bool,
+        f: F,
+    ) where
+        M: StorageMut<R, C, T>,
+        F: FnMut(&mut T),
+    {
+        apply_chunked(m.buffer_mut(), chunk_len, prefetch, f);
+    }
+
+    pub(crate) fn try_apply_all<M, const R: usize, const C: usize, T, E, F>(
+        m: &mut M,
+        f: F,
+    ) -> Result<(), E>
+    where
+        M: StorageMut<R, C, T>,
+        F: FnMut(&mut T) -> Result<(), E>,
+    {
+        m.buffer_mut().iter_mut().try_for_each(f)
+    }
+
+    pub(crate) fn display_with<M, const R: usize, const C: usize, T, F>(
+        m: &M,
+        cell: F,
+    ) -> Grid<'_, R, C, M::Priority, T, F>
+    where
+        M: Storage<R, C, T>,
+        F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        Grid::new(m.buffer(), cell)
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn write_pretty<M, const R: usize, const C: usize, T, W>(
+        m: &M,
+        w: &mut W,
+    ) -> io::Result<()>
+    where
+        M: Storage<R, C, T>,
+        T: Debug,
+        W: io::Write,
+    {
+        write!(
+            w,
+            "{}",
+            display_with(m, |el: &T, f: &mut fmt::Formatter<'_>| write!(
+                f,
+                "{:02x?}",
+                el
+            ))
+            .separator("-")
+        )
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn pretty_print<M, const R: usize, const C: usize, T>(m: &M)
+    where
+        M: Storage<R, C, T>,
+        T: Debug,
+    {
+        write_pretty(m, &mut io::stdout().lock()).expect("failed printing to stdout");
+    }
+}
+
+impl<'a, M, const R: usize, const C: usize, T> ColumnPrioMatrix<'a, R, C, T> for M
+where
+    M: Storage<R, C, T, Priority = ColumnPrio> + 'a,
+    T: Copy + Default + Debug,
+{
+    fn insert(&mut self, location: Position, value: T)
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::insert(self, location, value);
+    }
+
+    fn get(&'a self, location: Position) -> &'a T {
+        dense::get(self, location)
+    }
+
+    fn get_mut(&'a mut self, location: Position) -> &'a mut T
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::get_mut(self, location)
+    }
+
+    fn fill_col(&mut self, col: usize, data: &[T])
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::fill_col(self, col, data);
+    }
+
+    fn fill_row(&mut self, row: usize, data: &[T])
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::fill_row(self, row, data);
+    }
+
+    fn fill(&mut self, value: T)
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::fill(self, value);
+    }
+
+    fn fill_with<F>(&mut self, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut() -> T,
+    {
+        dense::fill_with(self, f);
+    }
+
+    fn clear(&mut self)
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::fill(self, T::default());
+    }
+
+    fn apply_row<F>(&mut self, row: usize, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T),
+    {
+        dense::apply_row(self, row, f);
+    }
+
+    fn apply_col<F>(&mut self, col: usize, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T),
+    {
+        dense::apply_col(self, col, f);
+    }
+
+    fn get_column(&self, col: usize) -> &[T] {
+        assert_col::<C>(col);
+        lane::<R, T>(self.buffer(), col)
+    }
+
+    fn get_mut_column(&mut self, col: usize) -> &mut [T]
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        assert_col::<C>(col);
+        lane_mut::<R, T>(self.buffer_mut(), col)
+    }
+
+    fn get_row(&self, row: usize) -> IntermittentSlice<'_, R, C, T> {
+        assert_row::<R>(row);
+        IntermittentSlice::new(self.buffer(), row)
+    }
+
+    fn get_mut_row(&mut self, row: usize) -> IntermittentSliceMut<'_, R, C, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        assert_row::<R>(row);
+        IntermittentSliceMut::new(self.buffer_mut(), row)
+    }
+
+    fn get_mut_columns(&mut self, a: usize, b: usize) -> (&mut [T], &mut [T])
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        assert_col::<C>(a);
+        assert_col::<C>(b);
+        lanes_mut::<R, T>(self.buffer_mut(), a, b)
+    }
+
+    fn get_mut_rows(
+        &mut self,
+        a: usize,
+        b: usize,
+    ) -> (
+        IntermittentSliceMut<'_, R, C, T>,
+        IntermittentSliceMut<'_, R, C, T>,
+    )
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        assert_row::<R>(a);
+        assert_row::<R>(b);
+        intermittent_pair_mut(self.buffer_mut(), a, b)
+    }
+
+    fn try_insert(&mut self, location: Position, value: T) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::try_insert(self, location, value)
+    }
+
+    fn try_get(&'a self, location: Position) -> Option<&'a T> {
+        dense::try_get(self, location)
+    }
+
+    fn try_get_mut(&'a mut self, location: Position) -> Option<&'a mut T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::try_get_mut(self, location)
+    }
+
+    fn get_disjoint_mut<const N: usize>(&mut self, locations: [Position; N]) -> Option<[&mut T; N]>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::get_disjoint_mut(self, locations)
+    }
+
+    fn try_fill_col(&mut self, col: usize, data: &[T]) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::try_fill_col(self, col, data)
+    }
+
+    fn try_fill_row(&mut self, row: usize, data: &[T]) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::try_fill_row(self, row, data)
+    }
+
+    fn try_get_column(&self, col: usize) -> Option<&[T]> {
+        (col < C).then(|| self.get_column(col))
+    }
+
+    fn try_get_row(&self, row: usize) -> Option<IntermittentSlice<'_, R, C, T>> {
+        (row < R).then(|| self.get_row(row))
+    }
+
+    fn rows(&self) -> IterIntermittentSlices<'_, R, C, T> {
+        IterIntermittentSlices {
+            slice_index: 0,
+            slice_end: R,
+            matrix_buffer: self.buffer(),
+        }
+    }
+
+    fn rows_mut(&mut self) -> IterMutIntermittentSlices<'_, R, C, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        IterMutIntermittentSlices::new(self.buffer_mut())
+    }
+
+    fn cols(&self) -> IterSlices<'_, C, R, T> {
+        IterSlices {
+            matrix_buffer: self.buffer(),
+        }
+    }
+
+    fn cols_mut(&mut self) -> IterSlicesMut<'_, C, R, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        IterSlicesMut {
+            matrix_buffer: self.buffer_mut(),
+        }
+    }
+
+    fn diagonal(&self) -> DiagonalSlice<'_, T> {
+        dense::diagonal(self, false)
+    }
+
+    fn diagonal_mut(&mut self) -> DiagonalSliceMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::diagonal_mut(self, false)
+    }
+
+    fn anti_diagonal(&self) -> DiagonalSlice<'_, T> {
+        dense::diagonal(self, true)
+    }
+
+    fn anti_diagonal_mut(&mut self) -> DiagonalSliceMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::diagonal_mut(self, true)
+    }
+
+    fn fill_diagonal(&mut self, value: T)
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::fill_diagonal(self, value);
+    }
+
+    fn apply_all<F>(&mut self, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T),
+    {
+        dense::apply_all(self, f);
+    }
+
+    fn apply_all_chunked<F>(&mut self, chunk_len: usize, prefetch: bool, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T),
+    {
+        dense::apply_all_chunked(self, chunk_len, prefetch, f);
+    }
+
+    fn try_apply_all<E, F>(&mut self, f: F) -> Result<(), E>
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T) -> Result<(), E>,
+    {
+        dense::try_apply_all(self, f)
+    }
+
+    fn display_with<F>(&self, cell: F) -> Grid<'_, R, C, ColumnPrio, T, F>
+    where
+        F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        dense::display_with(self, cell)
+    }
+
+    #[cfg(feature = "std")]
+    fn write_pretty<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        dense::write_pretty(self, w)
+    }
+
+    #[cfg(feature = "std")]
+    fn pretty_print(&self) {
+        dense::pretty_print(self);
+    }
+}
+
+impl<'a, M, const R: usize, const C: usize, T> RowPrioMatrix<'a, R, C, T> for M
+where
+    M: Storage<R, C, T, Priority = RowPrio> + 'a,
+    T: Copy + Default + Debug,
+{
+    fn insert(&mut self, location: Position, value: T)
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::insert(self, location, value);
+    }
+
+    fn get(&self, location: Position) -> &T {
+        dense::get(self, location)
+    }
+
+    fn get_mut(&mut self, location: Position) -> &mut T
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::get_mut(self, location)
+    }
+
+    fn fill_row(&mut self, row: usize, data: &[T])
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::fill_row(self, row, data);
+    }
+
+    fn fill_col(&mut self, col: usize, data: &[T])
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::fill_col(self, col, data);
+    }
+
+    fn fill(&mut self, value: T)
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::fill(self, value);
+    }
+
+    fn fill_with<F>(&mut self, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut() -> T,
+    {
+        dense::fill_with(self, f);
+    }
+
+    fn clear(&mut self)
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::fill(self, T::default());
+    }
+
+    fn apply_row<F>(&mut self, row: usize, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T),
+    {
+        dense::apply_row(self, row, f);
+    }
+
+    fn apply_col<F>(&mut self, col: usize, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T),
+    {
+        dense::apply_col(self, col, f);
+    }
+
+    fn get_column(&self, col: usize) -> IntermittentSlice<'_, C, R, T> {
+        assert_col::<C>(col);
+        IntermittentSlice::new(self.buffer(), col)
+    }
+
+    fn get_mut_column(&mut self, col: usize) -> IntermittentSliceMut<'_, C, R, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        assert_col::<C>(col);
+        IntermittentSliceMut::new(self.buffer_mut(), col)
+    }
+
+    fn get_mut_columns(
+        &mut self,
+        a: usize,
+        b: usize,
+    ) -> (
+        IntermittentSliceMut<'_, C, R, T>,
+        IntermittentSliceMut<'_, C, R, T>,
+    )
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        assert_col::<C>(a);
+        assert_col::<C>(b);
+        intermittent_pair_mut(self.buffer_mut(), a, b)
+    }
+
+    fn get_row(&self, row: usize) -> &[T] {
+        assert_row::<R>(row);
+        lane::<C, T>(self.buffer(), row)
+    }
+
+    fn get_mut_row(&mut self, row: usize) -> &mut [T]
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        assert_row::<R>(row);
+        lane_mut::<C, T>(self.buffer_mut(), row)
+    }
+
+    fn get_mut_rows(&mut self, a: usize, b: usize) -> (&mut [T], &mut [T])
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        assert_row::<R>(a);
+        assert_row::<R>(b);
+        lanes_mut::<C, T>(self.buffer_mut(), a, b)
+    }
+
+    fn try_insert(&mut self, location: Position, value: T) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::try_insert(self, location, value)
+    }
+
+    fn try_get(&self, location: Position) -> Option<&T> {
+        dense::try_get(self, location)
+    }
+
+    fn try_get_mut(&mut self, location: Position) -> Option<&mut T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::try_get_mut(self, location)
+    }
+
+    fn get_disjoint_mut<const N: usize>(&mut self, locations: [Position; N]) -> Option<[&mut T; N]>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::get_disjoint_mut(self, locations)
+    }
+
+    fn try_fill_row(&mut self, row: usize, data: &[T]) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::try_fill_row(self, row, data)
+    }
+
+    fn try_fill_col(&mut self, col: usize, data: &[T]) -> Result<(), MatrixError>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::try_fill_col(self, col, data)
+    }
+
+    fn try_get_column(&self, col: usize) -> Option<IntermittentSlice<'_, C, R, T>> {
+        (col < C).then(|| self.get_column(col))
+    }
+
+    fn try_get_row(&self, row: usize) -> Option<&[T]> {
+        (row < R).then(|| self.get_row(row))
+    }
+
+    fn rows(&self) -> IterSlices<'_, R, C, T> {
+        IterSlices {
+            matrix_buffer: self.buffer(),
+        }
+    }
+
+    fn rows_mut(&mut self) -> IterSlicesMut<'_, R, C, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        IterSlicesMut {
+            matrix_buffer: self.buffer_mut(),
+        }
+    }
+
+    fn cols(&self) -> IterIntermittentSlices<'_, C, R, T> {
+        IterIntermittentSlices {
+            slice_index: 0,
+            slice_end: C,
+            matrix_buffer: self.buffer(),
+        }
+    }
+
+    fn cols_mut(&mut self) -> IterMutIntermittentSlices<'_, C, R, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        IterMutIntermittentSlices::new(self.buffer_mut())
+    }
+
+    fn diagonal(&self) -> DiagonalSlice<'_, T> {
+        dense::diagonal(self, false)
+    }
+
+    fn diagonal_mut(&mut self) -> DiagonalSliceMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::diagonal_mut(self, false)
+    }
+
+    fn anti_diagonal(&self) -> DiagonalSlice<'_, T> {
+        dense::diagonal(self, true)
+    }
+
+    fn anti_diagonal_mut(&mut self) -> DiagonalSliceMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::diagonal_mut(self, true)
+    }
+
+    fn fill_diagonal(&mut self, value: T)
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        dense::fill_diagonal(self, value);
+    }
+
+    fn apply_all<F>(&mut self, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T),
+    {
+        dense::apply_all(self, f);
+    }
+
+    fn apply_all_chunked<F>(&mut self, chunk_len: usize, prefetch: bool, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T),
+    {
+        dense::apply_all_chunked(self, chunk_len, prefetch, f);
+    }
+
+    fn try_apply_all<E, F>(&mut self, f: F) -> Result<(), E>
+    where
+        Self: StorageMut<R, C, T>,
+        F: FnMut(&mut T) -> Result<(), E>,
+    {
+        dense::try_apply_all(self, f)
+    }
+
+    fn display_with<F>(&self, cell: F) -> Grid<'_, R, C, RowPrio, T, F>
+    where
+        F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        dense::display_with(self, cell)
+    }
+
+    #[cfg(feature = "std")]
+    fn write_pretty<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        dense::write_pretty(self, w)
+    }
+
+    #[cfg(feature = "std")]
+    fn pretty_print(&self) {
+        dense::pretty_print(self);
+    }
+}
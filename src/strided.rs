@@ -0,0 +1,530 @@
+use crate::{
+    storage::{Storage, StorageMut},
+    IntermittentSlice, IntermittentSliceMut, MemoryPriority,
+};
+use core::{
+    fmt,
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+/// Checks that the len elements stride apart from start lie in a buffer of length bound and
+/// that a stride of 0 does not repeat an element.
+fn check(bound: usize, start: usize, stride: isize, len: usize) -> bool {
+    if len == 0 {
+        return true;
+    }
+    if stride == 0 && len > 1 {
+        return false;
+    }
+    let last = isize::try_from(len - 1)
+        .ok()
+        .and_then(|steps| stride.checked_mul(steps))
+        .and_then(|distance| start.checked_add_signed(distance));
+    start < bound && last.is_some_and(|last| last < bound)
+}
+
+/// Returns the offset of start and the stride in memory of the walk of len elements by step
+/// through an R x C matrix, or None if it leaves the matrix.
+fn walk<P: MemoryPriority, const R: usize, const C: usize>(
+    start: (usize, usize),
+    step: (isize, isize),
+    len: usize,
+) -> Option<(usize, isize)> {
+    let last = |start: usize, step: isize, bound: usize| {
+        let distance = step.checked_mul(isize::try_from(len.checked_sub(1)?).ok()?)?;
+        start
+            .checked_add_signed(distance)
+            .filter(|&last| last < bound)
+    };
+    if len > 0 {
+        (start.0 < R && start.1 < C).then_some(())?;
+        last(start.0, step.0, R)?;
+        last(start.1, step.1, C)?;
+    }
+    let (row_stride, col_stride) = (P::row_stride(R, C), P::col_stride(R, C));
+    let stride = step
+        .0
+        .checked_mul(row_stride as isize)?
+        .checked_add(step.1.checked_mul(col_stride as isize)?)?;
+    let offset = if len > 0 { P::offset(start, R, C) } else { 0 };
+    Some((offset, stride))
+}
+
+/// Strided is a view of len elements of a buffer that lie a constant stride apart, e.g. every
+/// k-th element, a diagonal or a row of a [`ColumnPrio`](crate::ColumnPrio) matrix walked
+/// backwards.
+///
+/// # Examples
+///
+/// ```
+/// # use mightrix::Strided;
+/// let data = [0, 1, 2, 3, 4, 5, 6];
+/// let every_third = Strided::new(&data, 0, 3, 3).unwrap();
+/// assert_eq!(every_third, [0, 3, 6]);
+/// let backwards = Strided::new(&data, 5, -2, 3).unwrap();
+/// assert_eq!(backwards.iter().copied().collect::<Vec<_>>(), vec![5, 3, 1]);
+/// assert!(Strided::new(&data, 5, -2, 4).is_none());
+/// ```
+pub struct Strided<'a, T> {
+    // Invariant: the len elements stride apart from start are valid for reads for 'a.
+    start: *const T,
+    stride: isize,
+    len: usize,
+    _borrow: PhantomData<&'a T>,
+}
+
+// SAFETY: a Strided is a shared borrow of its elements.
+unsafe impl<T: Sync> Send for Strided<'_, T> {}
+unsafe impl<T: Sync> Sync for Strided<'_, T> {}
+
+impl<T> Clone for Strided<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Strided<'_, T> {}
+
+impl<'a, T> Strided<'a, T> {
+    /// Constructs the view of the len elements of buffer at start, start + stride, ...
+    ///
+    /// Returns None if an element lies outside of buffer or if the stride is 0 and len is
+    /// larger than 1.
+    pub fn new(buffer: &'a [T], start: usize, stride: isize, len: usize) -> Option<Self> {
+        check(buffer.len(), start, stride, len).then(|| Self {
+            start: buffer.as_ptr().wrapping_add(start),
+            stride,
+            len,
+            _borrow: PhantomData,
+        })
+    }
+
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the distance in memory from one element to the next.
+    pub fn stride(&self) -> isize {
+        self.stride
+    }
+
+    /// Returns the element i of the view or None if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        // SAFETY: index is in bounds of the view.
+        (index < self.len).then(|| unsafe { &*self.start.offset(index as isize * self.stride) })
+    }
+
+    /// Returns the first element or None if the view is empty.
+    pub fn first(&self) -> Option<&'a T> {
+        self.get(0)
+    }
+
+    /// Returns the last element or None if the view is empty.
+    pub fn last(&self) -> Option<&'a T> {
+        self.len.checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    /// Returns an iterator over the elements of the view.
+    pub fn iter(&self) -> StridedIter<'a, T> {
+        StridedIter {
+            view: *self,
+            index: 0,
+            end: self.len,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Strided<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Index<usize> for Strided<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(el) => el,
+            None => panic!("Index {index} out of bounds {}", self.len),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for Strided<'a, T> {
+    type Item = &'a T;
+
+    type IntoIter = StridedIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for Strided<'_, T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.len == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for Strided<'_, T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<'a, const A: usize, const S: usize, T> From<IntermittentSlice<'a, A, S, T>>
+    for Strided<'a, T>
+{
+    fn from(slice: IntermittentSlice<'a, A, S, T>) -> Self {
+        Self {
+            start: slice.start,
+            stride: A as isize,
+            len: S,
+            _borrow: PhantomData,
+        }
+    }
+}
+
+/// StridedMut is a mutable view of len elements of a buffer that lie a constant stride apart.
+///
+/// See [`Strided`].
+pub struct StridedMut<'a, T> {
+    // Invariant: the len elements stride apart from start are valid and exclusively borrowed
+    // for 'a.
+    start: *mut T,
+    stride: isize,
+    len: usize,
+    _borrow: PhantomData<&'a mut T>,
+}
+
+// SAFETY: a StridedMut is an exclusive borrow of its elements.
+unsafe impl<T: Send> Send for StridedMut<'_, T> {}
+unsafe impl<T: Sync> Sync for StridedMut<'_, T> {}
+
+impl<'a, T> StridedMut<'a, T> {
+    /// Constructs the mutable view of the len elements of buffer at start, start + stride, ...
+    ///
+    /// Returns None if an element lies outside of buffer or if the stride is 0 and len is
+    /// larger than 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::StridedMut;
+    /// let mut data = [0; 6];
+    /// StridedMut::new(&mut data, 1, 2, 3).unwrap().fill(1);
+    /// assert_eq!(data, [0, 1, 0, 1, 0, 1]);
+    /// ```
+    pub fn new(buffer: &'a mut [T], start: usize, stride: isize, len: usize) -> Option<Self> {
+        check(buffer.len(), start, stride, len).then(|| Self {
+            start: buffer.as_mut_ptr().wrapping_add(start),
+            stride,
+            len,
+            _borrow: PhantomData,
+        })
+    }
+
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the distance in memory from one element to the next.
+    pub fn stride(&self) -> isize {
+        self.stride
+    }
+
+    /// Returns the element i of the view or None if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_strided().get(index)
+    }
+
+    /// Returns the element i of the view mutably or None if it is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        // SAFETY: index is in bounds of the view, which is borrowed mutably through self.
+        (index < self.len).then(|| unsafe { &mut *self.start.offset(index as isize * self.stride) })
+    }
+
+    /// Returns a view of the elements that borrows from self.
+    pub fn as_strided(&self) -> Strided<'_, T> {
+        Strided {
+            start: self.start,
+            stride: self.stride,
+            len: self.len,
+            _borrow: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the elements of the view.
+    pub fn iter(&self) -> StridedIter<'_, T> {
+        self.as_strided().iter()
+    }
+
+    /// Returns a mutable iterator over the elements of the view.
+    pub fn iter_mut(&mut self) -> StridedIterMut<'_, T> {
+        StridedMut {
+            start: self.start,
+            stride: self.stride,
+            len: self.len,
+            _borrow: PhantomData,
+        }
+        .into_iter()
+    }
+
+    /// Sets every element of the view to value.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for el in self.iter_mut() {
+            *el = value.clone();
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for StridedMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_strided().fmt(f)
+    }
+}
+
+impl<T> Index<usize> for StridedMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(el) => el,
+            None => panic!("Index {index} out of bounds {}", self.len),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for StridedMut<'_, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(el) => el,
+            None => panic!("Index {index} out of bounds {}", len),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for StridedMut<'a, T> {
+    type Item = &'a mut T;
+
+    type IntoIter = StridedIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        StridedIterMut {
+            index: 0,
+            end: self.len,
+            view: self,
+        }
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for StridedMut<'_, T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_strided() == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for StridedMut<'_, T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.as_strided() == other[..]
+    }
+}
+
+impl<'a, const A: usize, const S: usize, T> From<IntermittentSliceMut<'a, A, S, T>>
+    for StridedMut<'a, T>
+{
+    fn from(slice: IntermittentSliceMut<'a, A, S, T>) -> Self {
+        Self {
+            start: slice.start,
+            stride: A as isize,
+            len: S,
+            _borrow: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the elements of a [`Strided`] / [`StridedMut`].
+pub struct StridedIter<'a, T> {
+    view: Strided<'a, T>,
+    index: usize,
+    end: usize,
+}
+
+impl<'a, T> Iterator for StridedIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        self.index += 1;
+        self.view.get(self.index - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for StridedIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        self.view.get(self.end)
+    }
+}
+
+impl<T> ExactSizeIterator for StridedIter<'_, T> {}
+
+impl<T> FusedIterator for StridedIter<'_, T> {}
+
+/// Mutable iterator over the elements of a [`StridedMut`].
+pub struct StridedIterMut<'a, T> {
+    view: StridedMut<'a, T>,
+    index: usize,
+    end: usize,
+}
+
+impl<'a, T> StridedIterMut<'a, T> {
+    /// Hands out the element index.
+    ///
+    /// # Safety
+    ///
+    /// index has to be in bounds of the view and may only be handed out once.
+    unsafe fn element(&self, index: usize) -> &'a mut T {
+        // SAFETY: the elements of the view do not overlap and are borrowed for 'a.
+        unsafe { &mut *self.view.start.offset(index as isize * self.view.stride) }
+    }
+}
+
+impl<'a, T> Iterator for StridedIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        self.index += 1;
+        // SAFETY: index was below end and moved past the element.
+        Some(unsafe { self.element(self.index - 1) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for StridedIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        // SAFETY: end is at least index and moved past the element.
+        Some(unsafe { self.element(self.end) })
+    }
+}
+
+impl<T> ExactSizeIterator for StridedIterMut<'_, T> {}
+
+impl<T> FusedIterator for StridedIterMut<'_, T> {}
+
+/// StridedView borrows the elements of a matrix along a straight walk, e.g. a diagonal, every
+/// k-th element of a row or a column backwards.
+pub trait StridedView<const R: usize, const C: usize, T> {
+    /// Returns the view of the len elements at start, start + step, start + 2 * step, ... where
+    /// step is the signed distance in (rows, columns) from one element to the next.
+    ///
+    /// Returns None if an element lies outside of the matrix or if step is (0, 0) and len is
+    /// larger than 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, StridedView };
+    /// let m = Stacktrix::<12, 3, 4, ColumnPrio, u8>::from_fn(|row, col| (row * 4 + col) as u8);
+    /// let diagonal = m.strided((0, 1), (1, 1), 3).unwrap();
+    /// assert_eq!(diagonal, [1, 6, 11]);
+    /// let every_other = m.strided((2, 0), (0, 2), 2).unwrap();
+    /// assert_eq!(every_other, [8, 10]);
+    /// let upwards = m.strided((2, 3), (-1, 0), 3).unwrap();
+    /// assert_eq!(upwards, [11, 7, 3]);
+    /// assert!(m.strided((0, 0), (1, 1), 4).is_none());
+    /// ```
+    fn strided(
+        &self,
+        start: (usize, usize),
+        step: (isize, isize),
+        len: usize,
+    ) -> Option<Strided<'_, T>>;
+    /// Returns the mutable view of the len elements at start, start + step, ..., see
+    /// [`StridedView::strided`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, StridedView };
+    /// let mut m = Stacktrix::<9, 3, 3, RowPrio, u8>::zeros();
+    /// m.strided_mut((2, 0), (-1, 1), 3).unwrap().fill(1);
+    /// assert_eq!(m.as_slice(), &[0, 0, 1, 0, 1, 0, 1, 0, 0]);
+    /// ```
+    fn strided_mut(
+        &mut self,
+        start: (usize, usize),
+        step: (isize, isize),
+        len: usize,
+    ) -> Option<StridedMut<'_, T>>
+    where
+        Self: StorageMut<R, C, T>;
+}
+
+impl<M, const R: usize, const C: usize, T> StridedView<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+{
+    fn strided(
+        &self,
+        start: (usize, usize),
+        step: (isize, isize),
+        len: usize,
+    ) -> Option<Strided<'_, T>> {
+        let (offset, stride) = walk::<M::Priority, R, C>(start, step, len)?;
+        Strided::new(self.buffer(), offset, stride, len)
+    }
+
+    fn strided_mut(
+        &mut self,
+        start: (usize, usize),
+        step: (isize, isize),
+        len: usize,
+    ) -> Option<StridedMut<'_, T>>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        let (offset, stride) = walk::<M::Priority, R, C>(start, step, len)?;
+        StridedMut::new(self.buffer_mut(), offset, stride, len)
+    }
+}
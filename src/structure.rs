@@ -0,0 +1,133 @@
+use crate::{storage::Storage, MemoryPriority};
+use core::ops::{Add, Mul, Sub};
+
+/// Structure checks the shape of the values of a matrix, e.g. the preconditions of an
+/// algorithm.
+///
+/// Zeros and ones are constructed with `From<u8>`. All predicates but
+/// [`is_orthogonal`](Structure::is_orthogonal) compare exactly, the result of arithmetic is
+/// compared with a tolerance.
+pub trait Structure<const R: usize, const C: usize, T> {
+    /// Returns true if the matrix is square and equals its transpose.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, Structure };
+    /// let mut data = vec![1, 2, 3, 2, 4, 5, 3, 5, 6];
+    /// let m = Reftrix::<3, 3, RowPrio, u8>::from_values(&mut data[..]);
+    /// assert!(m.is_symmetric());
+    /// ```
+    fn is_symmetric(&self) -> bool
+    where
+        T: PartialEq;
+    /// Returns true if all elements outside the main diagonal are zero, the matrix does not
+    /// have to be square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, Structure };
+    /// let mut data = vec![1, 0, 0, 2, 0, 0];
+    /// let m = Reftrix::<2, 3, ColumnPrio, i32>::from_values(&mut data[..]);
+    /// assert!(m.is_diagonal());
+    /// ```
+    fn is_diagonal(&self) -> bool
+    where
+        T: PartialEq + From<u8>;
+    /// Returns true if all elements below the main diagonal are zero, the matrix does not have
+    /// to be square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, Structure };
+    /// let mut data = vec![1.0, 2.0, 0.0, 3.0];
+    /// let m = Reftrix::<2, 2, RowPrio, f32>::from_values(&mut data[..]);
+    /// assert!(m.is_upper_triangular());
+    /// assert!(!m.is_diagonal());
+    /// ```
+    fn is_upper_triangular(&self) -> bool
+    where
+        T: PartialEq + From<u8>;
+    /// Returns true if the matrix is square and its columns are orthonormal, i.e. every element
+    /// of Q^T * Q differs from the identity by at most eps. For integers eps = 0 checks
+    /// exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, Structure };
+    /// let (s, c) = (0.6f64, 0.8f64);
+    /// let mut data = vec![c, -s, s, c];
+    /// let m = Reftrix::<2, 2, RowPrio, f64>::from_values(&mut data[..]);
+    /// assert!(m.is_orthogonal(1e-12));
+    /// ```
+    fn is_orthogonal(&self, eps: T) -> bool
+    where
+        T: Copy + PartialOrd + From<u8> + Add<Output = T> + Sub<Output = T> + Mul<Output = T>;
+}
+
+impl<M, const R: usize, const C: usize, T> Structure<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+{
+    fn is_symmetric(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        let buffer = self.buffer();
+        R == C
+            && (0..R).all(|row| {
+                (0..row).all(|col| {
+                    buffer[M::Priority::offset((row, col), R, C)]
+                        == buffer[M::Priority::offset((col, row), R, C)]
+                })
+            })
+    }
+
+    fn is_diagonal(&self) -> bool
+    where
+        T: PartialEq + From<u8>,
+    {
+        let zero = T::from(0);
+        let buffer = self.buffer();
+        (0..R).all(|row| {
+            (0..C).all(|col| row == col || buffer[M::Priority::offset((row, col), R, C)] == zero)
+        })
+    }
+
+    fn is_upper_triangular(&self) -> bool
+    where
+        T: PartialEq + From<u8>,
+    {
+        let zero = T::from(0);
+        let buffer = self.buffer();
+        (0..R).all(|row| {
+            (0..row.min(C)).all(|col| buffer[M::Priority::offset((row, col), R, C)] == zero)
+        })
+    }
+
+    fn is_orthogonal(&self, eps: T) -> bool
+    where
+        T: Copy + PartialOrd + From<u8> + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+    {
+        if R != C {
+            return false;
+        }
+        let buffer = self.buffer();
+        let el = |row, col| buffer[M::Priority::offset((row, col), R, C)];
+        (0..C).all(|i| {
+            (i..C).all(|j| {
+                let dot = (0..R).fold(T::from(0), |acc, k| acc + el(k, i) * el(k, j));
+                let expected = T::from((i == j) as u8);
+                let diff = if dot > expected {
+                    dot - expected
+                } else {
+                    expected - dot
+                };
+                diff <= eps
+            })
+        })
+    }
+}
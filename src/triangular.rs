@@ -0,0 +1,267 @@
+use crate::{
+    storage::{Storage, StorageMut},
+    MatrixError, MemoryPriority,
+};
+use core::ops::{Div, Mul, Sub};
+
+/// Diagonal tells the triangular solvers how to treat the main diagonal of the matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagonal {
+    /// The diagonal is read from the matrix.
+    Stored,
+    /// The diagonal is assumed to be all ones and is not read, e.g. for the L of an LU
+    /// decomposition that shares its buffer with U.
+    Unit,
+}
+
+/// TriangularSolve solves linear systems A * x = b where A is a square triangular matrix.
+///
+/// Only the triangle named by the solver is read, the other triangle may hold arbitrary
+/// values. The substitution is done along the rows of a [`RowPrio`](crate::RowPrio) matrix and
+/// along the columns of a [`ColumnPrio`](crate::ColumnPrio) matrix, so the buffer is always
+/// walked continuously.
+pub trait TriangularSolve<const R: usize, const C: usize, T> {
+    /// Solves U * x = b by back substitution, where U is the upper triangle of the matrix.
+    ///
+    /// Returns [`MatrixError::NotSquare`] if R != C and [`MatrixError::Singular`] if a stored
+    /// diagonal element is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, RowPrio, TriangularSolve, Diagonal };
+    /// let mut data = vec![2.0, 1.0, 1.0, 0.0, 4.0, 2.0, 0.0, 0.0, 8.0];
+    /// let u = Reftrix::<3, 3, RowPrio, f64>::from_values(&mut data[..]);
+    /// let x = u.solve_upper_triangular(&[5.5, 10.0, 16.0], Diagonal::Stored).unwrap();
+    /// assert_eq!(x, [1.0, 1.5, 2.0]);
+    /// ```
+    fn solve_upper_triangular(&self, b: &[T; R], diagonal: Diagonal)
+        -> Result<[T; R], MatrixError>;
+    /// Solves L * x = b by forward substitution, where L is the lower triangle of the matrix.
+    ///
+    /// Returns [`MatrixError::NotSquare`] if R != C and [`MatrixError::Singular`] if a stored
+    /// diagonal element is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, ColumnPrio, TriangularSolve, Diagonal };
+    /// // ColumnPrio, the lower triangle is [1, 0; 3, 1], the 9 above the diagonal is ignored.
+    /// let mut data = vec![5, 3, 9, 7];
+    /// let l = Reftrix::<2, 2, ColumnPrio, i32>::from_values(&mut data[..]);
+    /// assert_eq!(l.solve_lower_triangular(&[2, 10], Diagonal::Unit), Ok([2, 4]));
+    /// ```
+    fn solve_lower_triangular(&self, b: &[T; R], diagonal: Diagonal)
+        -> Result<[T; R], MatrixError>;
+    /// Solves U * X = B for every column of B by back substitution, where U is the upper
+    /// triangle of the matrix. B is overwritten with X.
+    ///
+    /// The columns of a [`ColumnPrio`](crate::ColumnPrio) B are solved one after the other, a
+    /// [`RowPrio`](crate::RowPrio) B is solved for all columns at once with operations on its
+    /// rows.
+    ///
+    /// Returns [`MatrixError::NotSquare`] if R != C and [`MatrixError::Singular`] if a stored
+    /// diagonal element is zero, B is left unchanged in both cases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, Stacktrix, RowPrio, ColumnPrio, TriangularSolve, Diagonal };
+    /// let u = Stacktrix::<4, 2, 2, RowPrio, f64>::with_values([2.0, 1.0, 0.0, 4.0]);
+    /// // The right hand sides (4, 8) and (1, 4).
+    /// let mut data = vec![4.0, 8.0, 1.0, 4.0];
+    /// let mut b = Reftrix::<2, 2, ColumnPrio, f64>::from_values(&mut data[..]);
+    /// u.solve_upper_triangular_many(&mut b, Diagonal::Stored).unwrap();
+    /// assert_eq!(&data[..], &[1.0, 2.0, 0.0, 1.0]);
+    /// ```
+    fn solve_upper_triangular_many<B, const K: usize>(
+        &self,
+        b: &mut B,
+        diagonal: Diagonal,
+    ) -> Result<(), MatrixError>
+    where
+        B: StorageMut<R, K, T>;
+    /// Solves L * X = B for every column of B by forward substitution, where L is the lower
+    /// triangle of the matrix. B is overwritten with X.
+    ///
+    /// See [`solve_upper_triangular_many`](TriangularSolve::solve_upper_triangular_many).
+    fn solve_lower_triangular_many<B, const K: usize>(
+        &self,
+        b: &mut B,
+        diagonal: Diagonal,
+    ) -> Result<(), MatrixError>
+    where
+        B: StorageMut<R, K, T>;
+}
+
+impl<M, const R: usize, const C: usize, T> TriangularSolve<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+    T: Copy + PartialEq + From<u8> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    fn solve_upper_triangular(
+        &self,
+        b: &[T; R],
+        diagonal: Diagonal,
+    ) -> Result<[T; R], MatrixError> {
+        validate::<M, R, C, T>(self.buffer(), diagonal)?;
+        let mut x = *b;
+        substitute::<M, R, C, T>(self.buffer(), &mut x, Triangle::Upper, diagonal);
+        Ok(x)
+    }
+
+    fn solve_lower_triangular(
+        &self,
+        b: &[T; R],
+        diagonal: Diagonal,
+    ) -> Result<[T; R], MatrixError> {
+        validate::<M, R, C, T>(self.buffer(), diagonal)?;
+        let mut x = *b;
+        substitute::<M, R, C, T>(self.buffer(), &mut x, Triangle::Lower, diagonal);
+        Ok(x)
+    }
+
+    fn solve_upper_triangular_many<B, const K: usize>(
+        &self,
+        b: &mut B,
+        diagonal: Diagonal,
+    ) -> Result<(), MatrixError>
+    where
+        B: StorageMut<R, K, T>,
+    {
+        substitute_many::<M, B, R, C, K, T>(self.buffer(), b, Triangle::Upper, diagonal)
+    }
+
+    fn solve_lower_triangular_many<B, const K: usize>(
+        &self,
+        b: &mut B,
+        diagonal: Diagonal,
+    ) -> Result<(), MatrixError>
+    where
+        B: StorageMut<R, K, T>,
+    {
+        substitute_many::<M, B, R, C, K, T>(self.buffer(), b, Triangle::Lower, diagonal)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Triangle {
+    Upper,
+    Lower,
+}
+
+/// Checks that the matrix is square and that no stored diagonal element is zero.
+fn validate<M, const R: usize, const C: usize, T>(
+    buffer: &[T],
+    diagonal: Diagonal,
+) -> Result<(), MatrixError>
+where
+    M: Storage<R, C, T>,
+    T: PartialEq + From<u8>,
+{
+    if R != C {
+        return Err(MatrixError::NotSquare { rows: R, cols: C });
+    }
+    let zero = T::from(0);
+    if diagonal == Diagonal::Stored
+        && (0..R).any(|i| buffer[M::Priority::offset((i, i), R, C)] == zero)
+    {
+        return Err(MatrixError::Singular);
+    }
+    Ok(())
+}
+
+/// The order in which the unknowns are resolved, last to first for an upper triangle.
+fn order<const R: usize>(triangle: Triangle, k: usize) -> usize {
+    match triangle {
+        Triangle::Upper => R - 1 - k,
+        Triangle::Lower => k,
+    }
+}
+
+/// Overwrites x with the solution of A * x = x, where A is the given triangle of the validated
+/// R x C matrix in buffer.
+fn substitute<M, const R: usize, const C: usize, T>(
+    buffer: &[T],
+    x: &mut [T],
+    triangle: Triangle,
+    diagonal: Diagonal,
+) where
+    M: Storage<R, C, T>,
+    T: Copy + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    let el = |row, col| buffer[M::Priority::offset((row, col), R, C)];
+    let divide = |value: T, i: usize| match diagonal {
+        Diagonal::Unit => value,
+        Diagonal::Stored => value / el(i, i),
+    };
+    let order = |k| order::<R>(triangle, k);
+    if M::Priority::col_stride(R, C) == 1 {
+        // Rows are continuous, every unknown is the dot product of its row with the solved
+        // unknowns.
+        for k in 0..R {
+            let i = order(k);
+            let mut value = x[i];
+            for l in 0..k {
+                let j = order(l);
+                value = value - el(i, j) * x[j];
+            }
+            x[i] = divide(value, i);
+        }
+    } else {
+        // Columns are continuous, every solved unknown is eliminated from the remaining ones
+        // along its column.
+        for k in 0..R {
+            let j = order(k);
+            x[j] = divide(x[j], j);
+            for l in k + 1..R {
+                let i = order(l);
+                x[i] = x[i] - el(i, j) * x[j];
+            }
+        }
+    }
+}
+
+/// Overwrites the R x K matrix b with the solution of A * X = B.
+fn substitute_many<M, B, const R: usize, const C: usize, const K: usize, T>(
+    buffer: &[T],
+    b: &mut B,
+    triangle: Triangle,
+    diagonal: Diagonal,
+) -> Result<(), MatrixError>
+where
+    M: Storage<R, C, T>,
+    B: StorageMut<R, K, T>,
+    T: Copy + PartialEq + From<u8> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    validate::<M, R, C, T>(buffer, diagonal)?;
+    let rhs = b.buffer_mut();
+    if B::Priority::row_stride(R, K) == 1 {
+        // Every column of B is continuous and solved in place.
+        for col in 0..K {
+            let start = B::Priority::offset((0, col), R, K);
+            substitute::<M, R, C, T>(buffer, &mut rhs[start..start + R], triangle, diagonal);
+        }
+        return Ok(());
+    }
+    // Every row of B is continuous, all columns are solved at once with row operations.
+    let el = |row, col| buffer[M::Priority::offset((row, col), R, C)];
+    let at = |row, col| B::Priority::offset((row, col), R, K);
+    for k in 0..R {
+        let i = order::<R>(triangle, k);
+        for l in 0..k {
+            let j = order::<R>(triangle, l);
+            let factor = el(i, j);
+            for col in 0..K {
+                rhs[at(i, col)] = rhs[at(i, col)] - factor * rhs[at(j, col)];
+            }
+        }
+        if diagonal == Diagonal::Stored {
+            let pivot = el(i, i);
+            for col in 0..K {
+                rhs[at(i, col)] = rhs[at(i, col)] / pivot;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,759 @@
+use crate::{
+    storage::{Storage, StorageMut},
+    windows::{Axis, Chunks, ChunksMut, Windows},
+    MemoryPriority,
+};
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{
+        Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+        RangeToInclusive,
+    },
+    ptr::NonNull,
+    slice,
+};
+
+/// Where the window of a view lies in the memory it borrows.
+///
+/// The window is stored lane by lane, a lane is a column of a
+/// [`ColumnPrio`](crate::ColumnPrio) matrix and a row of a [`RowPrio`](crate::RowPrio) matrix.
+/// Consecutive lanes start stride elements apart. Windows next to each other may interleave
+/// in memory but never share an element.
+#[derive(Clone, Copy)]
+struct Window {
+    rows: usize,
+    cols: usize,
+    stride: usize,
+    column_major: bool,
+}
+
+impl Window {
+    fn lanes(&self) -> usize {
+        match (self.rows, self.cols) {
+            (0, _) | (_, 0) => 0,
+            _ if self.column_major => self.cols,
+            _ => self.rows,
+        }
+    }
+
+    fn lane_len(&self) -> usize {
+        if self.column_major {
+            self.rows
+        } else {
+            self.cols
+        }
+    }
+
+    /// Returns the offset of the (row, col) of the window or None if it is out of bounds.
+    fn offset(&self, (row, col): (usize, usize)) -> Option<usize> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        Some(if self.column_major {
+            col * self.stride + row
+        } else {
+            row * self.stride + col
+        })
+    }
+
+    /// Returns the offset of the sub window and its layout, an empty sub window starts at
+    /// offset 0.
+    ///
+    /// # Panics
+    ///
+    /// If a range is decreasing or out of bounds.
+    fn sub(&self, rows: impl RangeBounds<usize>, cols: impl RangeBounds<usize>) -> (usize, Window) {
+        let rows = resolve(rows, self.rows, "row");
+        let cols = resolve(cols, self.cols, "column");
+        let window = Window {
+            rows: rows.len(),
+            cols: cols.len(),
+            ..*self
+        };
+        let start = match window.lanes() {
+            0 => 0,
+            _ => self
+                .offset((rows.start, cols.start))
+                .expect("a non empty window starts inside its parent"),
+        };
+        (start, window)
+    }
+}
+
+/// Turns range into a Range within 0..len.
+fn resolve(range: impl RangeBounds<usize>, len: usize, what: &str) -> Range<usize> {
+    // Inclusive bounds at usize::MAX end past every view, None sends them to the assert.
+    let start = match range.start_bound() {
+        Bound::Included(&start) => Some(start),
+        Bound::Excluded(&start) => start.checked_add(1),
+        Bound::Unbounded => Some(0),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1),
+        Bound::Excluded(&end) => Some(end),
+        Bound::Unbounded => Some(len),
+    };
+    let range = start
+        .zip(end)
+        .filter(|&(start, end)| start <= end && end <= len);
+    assert!(
+        range.is_some(),
+        "{what} range {}..{} out of bounds of a view with {len} {what}s",
+        Endpoint(start),
+        Endpoint(end)
+    );
+    let (start, end) = range.unwrap();
+    start..end
+}
+
+/// Endpoint displays a resolved range endpoint, None is one past usize::MAX.
+struct Endpoint(Option<usize>);
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(endpoint) => write!(f, "{endpoint}"),
+            None => write!(f, "{}+1", usize::MAX),
+        }
+    }
+}
+
+/// Returns the window of a whole R x C matrix.
+fn window<P: MemoryPriority, const R: usize, const C: usize>() -> Window {
+    // A matrix with a single row or column is continuous in both directions, the lanes are
+    // chosen so that a single lane covers it.
+    let column_major = P::row_stride(R, C) == 1 && (C <= 1 || P::col_stride(R, C) != 1);
+    Window {
+        rows: R,
+        cols: C,
+        stride: if column_major { R } else { C },
+        column_major,
+    }
+}
+
+/// MatrixView is a borrowed view of a rectangular window of a matrix.
+///
+/// Locations are relative to the upper left corner of the window.
+pub struct MatrixView<'a, T> {
+    // Invariant: every lane of window, starting at ptr, is valid for reads for 'a.
+    ptr: NonNull<T>,
+    window: Window,
+    _borrow: PhantomData<&'a T>,
+}
+
+// SAFETY:
+// MatrixView behaves like a &'a [T] restricted to its window.
+unsafe impl<T: Sync> Send for MatrixView<'_, T> {}
+unsafe impl<T: Sync> Sync for MatrixView<'_, T> {}
+
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// Returns the amount of rows and columns of the view.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.window.rows, self.window.cols)
+    }
+
+    /// Returns the element at location or None if it lies outside of the view.
+    pub fn get(&self, location: (usize, usize)) -> Option<&'a T> {
+        let offset = self.window.offset(location)?;
+        // SAFETY:
+        // The location lies inside the window.
+        Some(unsafe { &*self.ptr.as_ptr().add(offset) })
+    }
+
+    /// Returns a view of a window of the view.
+    ///
+    /// # Panics
+    ///
+    /// If a range is decreasing or out of bounds.
+    pub fn view(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> MatrixView<'a, T> {
+        let (start, window) = self.window.sub(rows, cols);
+        MatrixView {
+            // SAFETY:
+            // start is 0 or the offset of an element of the window.
+            ptr: unsafe { NonNull::new_unchecked(self.ptr.as_ptr().add(start)) },
+            window,
+            _borrow: PhantomData,
+        }
+    }
+
+    /// Iterates over the lanes of the view, the columns of a
+    /// [`ColumnPrio`](crate::ColumnPrio) matrix and the rows of a [`RowPrio`](crate::RowPrio)
+    /// matrix.
+    fn lanes(&self) -> impl Iterator<Item = &'a [T]> {
+        let (ptr, window) = (self.ptr, self.window);
+        (0..window.lanes()).map(move |lane| {
+            // SAFETY:
+            // The lane lies inside the window.
+            unsafe {
+                slice::from_raw_parts(ptr.as_ptr().add(lane * window.stride), window.lane_len())
+            }
+        })
+    }
+
+    /// Iterates over the elements of the view in memory order, i.e. column by column for a
+    /// [`ColumnPrio`](crate::ColumnPrio) matrix and row by row for a
+    /// [`RowPrio`](crate::RowPrio) matrix.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.lanes().flatten()
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, location: (usize, usize)) -> &T {
+        self.get(location)
+            .unwrap_or_else(|| out_of_bounds(location, self.shape()))
+    }
+}
+
+/// MatrixViewMut is a mutable borrowed view of a rectangular window of a matrix.
+///
+/// Locations are relative to the upper left corner of the window.
+pub struct MatrixViewMut<'a, T> {
+    // Invariant: every lane of window, starting at ptr, is valid for reads and writes for 'a
+    // and not accessible through any other reference.
+    ptr: NonNull<T>,
+    window: Window,
+    _borrow: PhantomData<&'a mut T>,
+}
+
+// SAFETY:
+// MatrixViewMut behaves like a &'a mut [T] restricted to its window.
+unsafe impl<T: Send> Send for MatrixViewMut<'_, T> {}
+unsafe impl<T: Sync> Sync for MatrixViewMut<'_, T> {}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    /// Returns the amount of rows and columns of the view.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.window.rows, self.window.cols)
+    }
+
+    /// Returns the element at location or None if it lies outside of the view.
+    pub fn get(&self, location: (usize, usize)) -> Option<&T> {
+        self.as_view().get(location)
+    }
+
+    /// Returns the element at location mutably or None if it lies outside of the view.
+    pub fn get_mut(&mut self, location: (usize, usize)) -> Option<&mut T> {
+        let offset = self.window.offset(location)?;
+        // SAFETY:
+        // The location lies inside the window.
+        Some(unsafe { &mut *self.ptr.as_ptr().add(offset) })
+    }
+
+    /// Returns a view of a window of the view.
+    ///
+    /// # Panics
+    ///
+    /// If a range is decreasing or out of bounds.
+    pub fn view(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> MatrixView<'_, T> {
+        self.as_view().view(rows, cols)
+    }
+
+    /// Returns a mutable view of a window of the view.
+    ///
+    /// # Panics
+    ///
+    /// If a range is decreasing or out of bounds.
+    pub fn view_mut(
+        &mut self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> MatrixViewMut<'_, T> {
+        let (start, window) = self.window.sub(rows, cols);
+        // SAFETY:
+        // start is 0 or the offset of an element of the window, the sub window is borrowed
+        // from self.
+        unsafe { MatrixViewMut::from_raw(self.ptr.as_ptr().add(start), window) }
+    }
+
+    /// Narrows the view to a window, keeping the full lifetime 'a.
+    fn view_mut_owned(
+        self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> MatrixViewMut<'a, T> {
+        let (start, window) = self.window.sub(rows, cols);
+        // SAFETY:
+        // The sub window lies inside the window of self, which is consumed.
+        unsafe { MatrixViewMut::from_raw(self.ptr.as_ptr().add(start), window) }
+    }
+
+    /// Splits the view into the rows above row and the rows from row on.
+    ///
+    /// # Panics
+    ///
+    /// If row is larger than the amount of rows.
+    pub fn split_at_row(self, row: usize) -> (MatrixViewMut<'a, T>, MatrixViewMut<'a, T>) {
+        self.split(..row, .., row.., ..)
+    }
+
+    /// Splits the view into the columns left of col and the columns from col on.
+    ///
+    /// # Panics
+    ///
+    /// If col is larger than the amount of columns.
+    pub fn split_at_col(self, col: usize) -> (MatrixViewMut<'a, T>, MatrixViewMut<'a, T>) {
+        self.split(.., ..col, .., col..)
+    }
+
+    /// Splits the view into two windows, which have to be disjoint.
+    fn split(
+        self,
+        first_rows: impl RangeBounds<usize>,
+        first_cols: impl RangeBounds<usize>,
+        second_rows: impl RangeBounds<usize>,
+        second_cols: impl RangeBounds<usize>,
+    ) -> (MatrixViewMut<'a, T>, MatrixViewMut<'a, T>) {
+        let (first_start, first) = self.window.sub(first_rows, first_cols);
+        let (second_start, second) = self.window.sub(second_rows, second_cols);
+        // SAFETY:
+        // Both windows lie inside the window of self, which is consumed, and do not share an
+        // element.
+        unsafe {
+            (
+                MatrixViewMut::from_raw(self.ptr.as_ptr().add(first_start), first),
+                MatrixViewMut::from_raw(self.ptr.as_ptr().add(second_start), second),
+            )
+        }
+    }
+
+    /// # Safety
+    ///
+    /// Every lane of window starting at ptr has to be valid for reads and writes for 'a and
+    /// must not be accessed through another reference during 'a.
+    unsafe fn from_raw(ptr: *mut T, window: Window) -> Self {
+        Self {
+            ptr: NonNull::new_unchecked(ptr),
+            window,
+            _borrow: PhantomData,
+        }
+    }
+
+    /// Reborrows the view immutably.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            ptr: self.ptr,
+            window: self.window,
+            _borrow: PhantomData,
+        }
+    }
+
+    /// Iterates over the elements of the view in memory order, see [`MatrixView::iter`].
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.as_view().iter()
+    }
+
+    /// Iterates mutably over the elements of the view in memory order, see
+    /// [`MatrixView::iter`].
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let (ptr, window) = (self.ptr, self.window);
+        (0..window.lanes()).flat_map(move |lane| {
+            // SAFETY:
+            // The lanes lie inside the window and are disjoint.
+            unsafe {
+                slice::from_raw_parts_mut(ptr.as_ptr().add(lane * window.stride), window.lane_len())
+            }
+        })
+    }
+
+    /// Sets every element of the view to value.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for el in self.iter_mut() {
+            *el = value.clone();
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, location: (usize, usize)) -> &T {
+        self.get(location)
+            .unwrap_or_else(|| out_of_bounds(location, self.shape()))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, location: (usize, usize)) -> &mut T {
+        let shape = self.shape();
+        self.get_mut(location)
+            .unwrap_or_else(|| out_of_bounds(location, shape))
+    }
+}
+
+fn out_of_bounds(location: (usize, usize), shape: (usize, usize)) -> ! {
+    panic!(
+        "location {location:?} out of bounds of a {}x{} view",
+        shape.0, shape.1
+    )
+}
+
+/// The rows or the columns selected by a [`SliceInfo`].
+///
+/// Converted from any range of usize, or from a single index that selects one row or column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SliceAxis {
+    start: Bound<usize>,
+    end: Bound<usize>,
+}
+
+impl RangeBounds<usize> for SliceAxis {
+    fn start_bound(&self) -> Bound<&usize> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&usize> {
+        self.end.as_ref()
+    }
+}
+
+impl From<usize> for SliceAxis {
+    fn from(index: usize) -> Self {
+        Self {
+            start: Bound::Included(index),
+            end: Bound::Included(index),
+        }
+    }
+}
+
+macro_rules! slice_axis_from_range {
+    ($($range:ty),*) => {
+        $(
+            impl From<$range> for SliceAxis {
+                fn from(range: $range) -> Self {
+                    Self {
+                        start: range.start_bound().cloned(),
+                        end: range.end_bound().cloned(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+slice_axis_from_range!(
+    Range<usize>,
+    RangeInclusive<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>,
+    RangeFull
+);
+
+/// The rows and columns of a slice of a matrix, usually built with [`s!`](crate::s!) or from a
+/// tuple of the rows and the columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SliceInfo {
+    rows: SliceAxis,
+    cols: SliceAxis,
+}
+
+impl SliceInfo {
+    /// Constructs a SliceInfo from the selected rows and columns.
+    pub fn new(rows: impl Into<SliceAxis>, cols: impl Into<SliceAxis>) -> Self {
+        Self {
+            rows: rows.into(),
+            cols: cols.into(),
+        }
+    }
+}
+
+impl<A: Into<SliceAxis>, B: Into<SliceAxis>> From<(A, B)> for SliceInfo {
+    fn from((rows, cols): (A, B)) -> Self {
+        Self::new(rows, cols)
+    }
+}
+
+/// View borrows rectangular windows of a matrix.
+pub trait View<const R: usize, const C: usize, T> {
+    /// Returns a view of the rows and columns in the given ranges.
+    ///
+    /// # Panics
+    ///
+    /// If a range is decreasing or out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, View };
+    /// let m = Stacktrix::<12, 3, 4, ColumnPrio, u8>::from_fn(|row, col| (row * 4 + col) as u8);
+    /// let v = m.view(1.., 1..3);
+    /// assert_eq!(v.shape(), (2, 2));
+    /// assert_eq!(v[(0, 0)], 5);
+    /// assert_eq!(v.iter().copied().collect::<Vec<_>>(), vec![5, 9, 6, 10]);
+    /// ```
+    fn view(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> MatrixView<'_, T>;
+    /// Returns a mutable view of the rows and columns in the given ranges.
+    ///
+    /// # Panics
+    ///
+    /// If a range is decreasing or out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, View };
+    /// let mut m = Stacktrix::<9, 3, 3, RowPrio, u8>::zeros();
+    /// m.view_mut(..2, 1..).fill(1);
+    /// assert_eq!(m.as_slice(), &[0, 1, 1, 0, 1, 1, 0, 0, 0]);
+    /// ```
+    fn view_mut(
+        &mut self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> MatrixViewMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>;
+    /// Splits the matrix into two disjoint mutable views, the rows above row and the rows from
+    /// row on.
+    ///
+    /// # Panics
+    ///
+    /// If row > R.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, View };
+    /// let mut m = Stacktrix::<6, 3, 2, ColumnPrio, u8>::from_rows([[1, 2], [3, 4], [5, 6]]);
+    /// let (mut top, bottom) = m.split_at_row(1);
+    /// top.iter_mut().zip(bottom.view(..1, ..).iter()).for_each(|(t, b)| *t += b);
+    /// assert_eq!(m, Stacktrix::<6, 3, 2, ColumnPrio, u8>::from_rows([[4, 6], [3, 4], [5, 6]]));
+    /// ```
+    fn split_at_row(&mut self, row: usize) -> (MatrixViewMut<'_, T>, MatrixViewMut<'_, T>)
+    where
+        Self: StorageMut<R, C, T>;
+    /// Splits the matrix into two disjoint mutable views, the columns left of col and the
+    /// columns from col on.
+    ///
+    /// # Panics
+    ///
+    /// If col > C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, View };
+    /// let mut m = Stacktrix::<6, 2, 3, RowPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let (mut left, mut right) = m.split_at_col(1);
+    /// left.fill(0);
+    /// right[(1, 1)] = 9;
+    /// assert_eq!(m.as_slice(), &[0, 2, 3, 0, 5, 9]);
+    /// ```
+    fn split_at_col(&mut self, col: usize) -> (MatrixViewMut<'_, T>, MatrixViewMut<'_, T>)
+    where
+        Self: StorageMut<R, C, T>;
+    /// Returns a view of a slice given by [`s!`](crate::s!) or a tuple of the rows and the
+    /// columns, see [`View::view`]. A single index selects one row or column.
+    ///
+    /// # Panics
+    ///
+    /// If a range is decreasing or out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ s, Stacktrix, ColumnPrio, View };
+    /// let m = Stacktrix::<12, 3, 4, ColumnPrio, u8>::from_fn(|row, col| (row * 4 + col) as u8);
+    /// assert_eq!(m.slice(s![1..=2, ..2]).iter().copied().collect::<Vec<_>>(), vec![4, 8, 5, 9]);
+    /// let row = m.slice(s![2, ..]);
+    /// assert_eq!(row.shape(), (1, 4));
+    /// assert_eq!(row[(0, 3)], 11);
+    /// assert_eq!(m.slice((.., 1)).shape(), (3, 1));
+    /// ```
+    fn slice(&self, info: impl Into<SliceInfo>) -> MatrixView<'_, T> {
+        let info = info.into();
+        self.view(info.rows, info.cols)
+    }
+    /// Returns a mutable view of a slice given by [`s!`](crate::s!) or a tuple of the rows and
+    /// the columns, see [`View::slice`].
+    ///
+    /// # Panics
+    ///
+    /// If a range is decreasing or out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ s, Stacktrix, RowPrio, View };
+    /// let mut m = Stacktrix::<9, 3, 3, RowPrio, u8>::zeros();
+    /// m.slice_mut(s![1.., 0]).fill(1);
+    /// assert_eq!(m.as_slice(), &[0, 0, 0, 1, 0, 0, 1, 0, 0]);
+    /// ```
+    fn slice_mut(&mut self, info: impl Into<SliceInfo>) -> MatrixViewMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        let info = info.into();
+        self.view_mut(info.rows, info.cols)
+    }
+    /// Iterates over the overlapping windows of size adjacent rows, from the top down.
+    ///
+    /// # Panics
+    ///
+    /// If size is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, View };
+    /// let m = Stacktrix::<8, 4, 2, ColumnPrio, u8>::from_rows([[1, 2], [3, 4], [5, 6], [7, 8]]);
+    /// let sums: Vec<u8> = m.row_windows(3).map(|w| w.iter().sum()).collect();
+    /// assert_eq!(sums, vec![21, 33]);
+    /// ```
+    fn row_windows(&self, size: usize) -> Windows<'_, T> {
+        Windows::new(self.view(.., ..), Axis::Rows, size)
+    }
+    /// Iterates over the overlapping windows of size adjacent columns, from left to right.
+    ///
+    /// # Panics
+    ///
+    /// If size is 0.
+    fn col_windows(&self, size: usize) -> Windows<'_, T> {
+        Windows::new(self.view(.., ..), Axis::Cols, size)
+    }
+    /// Iterates over disjoint chunks of size adjacent rows, from the top down. The last chunk
+    /// has fewer rows if size does not divide R.
+    ///
+    /// # Panics
+    ///
+    /// If size is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, RowPrio, View };
+    /// let m = Stacktrix::<10, 5, 2, RowPrio, u8>::from_fn(|row, _| row as u8);
+    /// let shapes: Vec<_> = m.row_chunks(2).map(|c| c.shape()).collect();
+    /// assert_eq!(shapes, vec![(2, 2), (2, 2), (1, 2)]);
+    /// ```
+    fn row_chunks(&self, size: usize) -> Chunks<'_, T> {
+        Chunks::new(self.view(.., ..), Axis::Rows, size)
+    }
+    /// Iterates over disjoint chunks of size adjacent columns, from left to right. The last
+    /// chunk has fewer columns if size does not divide C.
+    ///
+    /// # Panics
+    ///
+    /// If size is 0.
+    fn col_chunks(&self, size: usize) -> Chunks<'_, T> {
+        Chunks::new(self.view(.., ..), Axis::Cols, size)
+    }
+    /// Iterates mutably over disjoint chunks of size adjacent rows, see [`View::row_chunks`].
+    ///
+    /// # Panics
+    ///
+    /// If size is 0.
+    fn row_chunks_mut(&mut self, size: usize) -> ChunksMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        ChunksMut::new(self.view_mut(.., ..), Axis::Rows, size)
+    }
+    /// Iterates mutably over disjoint chunks of size adjacent columns, see
+    /// [`View::col_chunks`].
+    ///
+    /// # Panics
+    ///
+    /// If size is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, View };
+    /// let mut m = Stacktrix::<6, 1, 6, ColumnPrio, u8>::zeros();
+    /// for (i, mut chunk) in m.col_chunks_mut(4).enumerate() {
+    ///     chunk.fill(i as u8 + 1);
+    /// }
+    /// assert_eq!(m.as_slice(), &[1, 1, 1, 1, 2, 2]);
+    /// ```
+    fn col_chunks_mut(&mut self, size: usize) -> ChunksMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        ChunksMut::new(self.view_mut(.., ..), Axis::Cols, size)
+    }
+}
+
+impl<M, const R: usize, const C: usize, T> View<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+{
+    fn view(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> MatrixView<'_, T> {
+        let buffer = self.buffer();
+        MatrixView {
+            // SAFETY:
+            // A slice is never null.
+            ptr: unsafe { NonNull::new_unchecked(buffer.as_ptr().cast_mut()) },
+            window: window::<M::Priority, R, C>(),
+            _borrow: PhantomData,
+        }
+        .view(rows, cols)
+    }
+
+    fn view_mut(
+        &mut self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> MatrixViewMut<'_, T>
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        whole::<M, R, C, T>(self).view_mut_owned(rows, cols)
+    }
+
+    fn split_at_row(&mut self, row: usize) -> (MatrixViewMut<'_, T>, MatrixViewMut<'_, T>)
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        whole::<M, R, C, T>(self).split_at_row(row)
+    }
+
+    fn split_at_col(&mut self, col: usize) -> (MatrixViewMut<'_, T>, MatrixViewMut<'_, T>)
+    where
+        Self: StorageMut<R, C, T>,
+    {
+        whole::<M, R, C, T>(self).split_at_col(col)
+    }
+}
+
+/// Returns a mutable view of the whole matrix.
+fn whole<M, const R: usize, const C: usize, T>(m: &mut M) -> MatrixViewMut<'_, T>
+where
+    M: StorageMut<R, C, T>,
+{
+    let buffer = m.buffer_mut();
+    // SAFETY:
+    // The window of the whole matrix covers exactly the buffer, which is borrowed mutably.
+    unsafe { MatrixViewMut::from_raw(buffer.as_mut_ptr(), window::<M::Priority, R, C>()) }
+}
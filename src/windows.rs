@@ -0,0 +1,236 @@
+use crate::{MatrixView, MatrixViewMut};
+use core::iter::FusedIterator;
+
+/// The direction groups of adjacent lanes are taken in.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Axis {
+    Rows,
+    Cols,
+}
+
+impl Axis {
+    /// Returns the number of rows or columns of a view with shape.
+    fn len(self, (rows, cols): (usize, usize)) -> usize {
+        match self {
+            Axis::Rows => rows,
+            Axis::Cols => cols,
+        }
+    }
+
+    /// Returns the rows or columns start..end of view.
+    fn sub<'a, T>(self, view: MatrixView<'a, T>, start: usize, end: usize) -> MatrixView<'a, T> {
+        match self {
+            Axis::Rows => view.view(start..end, ..),
+            Axis::Cols => view.view(.., start..end),
+        }
+    }
+
+    /// Splits view in front of the row or column at.
+    fn split<'a, T>(
+        self,
+        view: MatrixViewMut<'a, T>,
+        at: usize,
+    ) -> (MatrixViewMut<'a, T>, MatrixViewMut<'a, T>) {
+        match self {
+            Axis::Rows => view.split_at_row(at),
+            Axis::Cols => view.split_at_col(at),
+        }
+    }
+}
+
+fn assert_size(size: usize) {
+    assert!(size != 0, "the size of windows and chunks must be non zero");
+}
+
+/// Iterator over overlapping windows of size adjacent rows or columns, returned by
+/// [`View::row_windows`](crate::View::row_windows) and
+/// [`View::col_windows`](crate::View::col_windows).
+pub struct Windows<'a, T> {
+    view: MatrixView<'a, T>,
+    axis: Axis,
+    size: usize,
+    // The windows starting in front..back are left.
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Windows<'a, T> {
+    pub(crate) fn new(view: MatrixView<'a, T>, axis: Axis, size: usize) -> Self {
+        assert_size(size);
+        let back = (axis.len(view.shape()) + 1).saturating_sub(size);
+        Self {
+            view,
+            axis,
+            size,
+            front: 0,
+            back,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = MatrixView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let start = self.front;
+        self.front += 1;
+        Some(self.axis.sub(self.view, start, start + self.size))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Windows<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.axis.sub(self.view, self.back, self.back + self.size))
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {}
+
+impl<T> FusedIterator for Windows<'_, T> {}
+
+/// Iterator over disjoint chunks of size adjacent rows or columns, returned by
+/// [`View::row_chunks`](crate::View::row_chunks) and
+/// [`View::col_chunks`](crate::View::col_chunks).
+///
+/// The last chunk is smaller if size does not divide the number of rows or columns.
+pub struct Chunks<'a, T> {
+    view: MatrixView<'a, T>,
+    axis: Axis,
+    size: usize,
+    // The rows or columns front..back are left.
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Chunks<'a, T> {
+    pub(crate) fn new(view: MatrixView<'a, T>, axis: Axis, size: usize) -> Self {
+        assert_size(size);
+        let back = axis.len(view.shape());
+        Self {
+            view,
+            axis,
+            size,
+            front: 0,
+            back,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = MatrixView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let start = self.front;
+        self.front = self.back.min(start + self.size);
+        Some(self.axis.sub(self.view, start, self.front))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front).div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Chunks<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let end = self.back;
+        self.back -= last_chunk(end - self.front, self.size);
+        Some(self.axis.sub(self.view, self.back, end))
+    }
+}
+
+impl<T> ExactSizeIterator for Chunks<'_, T> {}
+
+impl<T> FusedIterator for Chunks<'_, T> {}
+
+/// Iterator over disjoint mutable chunks of size adjacent rows or columns, returned by
+/// [`View::row_chunks_mut`](crate::View::row_chunks_mut) and
+/// [`View::col_chunks_mut`](crate::View::col_chunks_mut).
+///
+/// The last chunk is smaller if size does not divide the number of rows or columns.
+pub struct ChunksMut<'a, T> {
+    // None once every chunk has been handed out.
+    rest: Option<MatrixViewMut<'a, T>>,
+    axis: Axis,
+    size: usize,
+}
+
+impl<'a, T> ChunksMut<'a, T> {
+    pub(crate) fn new(view: MatrixViewMut<'a, T>, axis: Axis, size: usize) -> Self {
+        assert_size(size);
+        Self {
+            rest: Some(view),
+            axis,
+            size,
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        self.rest
+            .as_ref()
+            .map_or(0, |rest| self.axis.len(rest.shape()))
+    }
+}
+
+impl<'a, T> Iterator for ChunksMut<'a, T> {
+    type Item = MatrixViewMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.remaining();
+        if len == 0 {
+            return None;
+        }
+        let (chunk, rest) = self.axis.split(self.rest.take()?, len.min(self.size));
+        self.rest = Some(rest);
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining().div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ChunksMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.remaining();
+        if len == 0 {
+            return None;
+        }
+        let (rest, chunk) = self
+            .axis
+            .split(self.rest.take()?, len - last_chunk(len, self.size));
+        self.rest = Some(rest);
+        Some(chunk)
+    }
+}
+
+impl<T> ExactSizeIterator for ChunksMut<'_, T> {}
+
+impl<T> FusedIterator for ChunksMut<'_, T> {}
+
+/// Returns the size of the last chunk of len rows or columns.
+fn last_chunk(len: usize, size: usize) -> usize {
+    match len % size {
+        0 => size,
+        rest => rest,
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    storage::{Storage, StorageMut},
+    MemoryPriority,
+};
+
+/// Zip combines the elements at the same locations of matrices of the same shape, independent of
+/// their memory priorities and backing types.
+///
+/// The elements are visited in logical row major order.
+pub trait Zip<const R: usize, const C: usize, T> {
+    /// Calls f with every element mutably and the element of other at the same location, e.g.
+    /// to mask or accumulate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Reftrix, Stacktrix, ColumnPrio, RowPrio, Zip };
+    /// let mut data = vec![1u8, 2, 3, 4];
+    /// let mut m = Reftrix::<2, 2, ColumnPrio, u8>::from_values(&mut data[..]);
+    /// let mask = Stacktrix::<4, 2, 2, RowPrio, bool>::with_values([true, false, false, true]);
+    /// m.zip_apply(&mask, |el, &keep| {
+    ///     if !keep {
+    ///         *el = 0;
+    ///     }
+    /// });
+    /// assert_eq!(&data[..], &[1, 0, 0, 4]);
+    /// ```
+    fn zip_apply<O, U, F>(&mut self, other: &O, f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        O: Storage<R, C, U>,
+        F: FnMut(&mut T, &U);
+    /// Writes f of every element and the element of other at the same location to the same
+    /// location of dst.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mightrix::{ Stacktrix, ColumnPrio, RowPrio, Zip };
+    /// let a = Stacktrix::<4, 2, 2, RowPrio, u8>::with_values([1, 2, 3, 4]);
+    /// let b = Stacktrix::<4, 2, 2, ColumnPrio, u8>::with_values([10, 30, 20, 40]);
+    /// let mut sum = Stacktrix::<4, 2, 2, RowPrio, u16>::zeros();
+    /// a.zip_map(&b, &mut sum, |&x, &y| u16::from(x) + u16::from(y));
+    /// assert_eq!(sum.as_slice(), &[11, 22, 33, 44]);
+    /// ```
+    fn zip_map<O, U, D, V, F>(&self, other: &O, dst: &mut D, f: F)
+    where
+        O: Storage<R, C, U>,
+        D: StorageMut<R, C, V>,
+        F: FnMut(&T, &U) -> V;
+}
+
+impl<M, const R: usize, const C: usize, T> Zip<R, C, T> for M
+where
+    M: Storage<R, C, T>,
+{
+    fn zip_apply<O, U, F>(&mut self, other: &O, mut f: F)
+    where
+        Self: StorageMut<R, C, T>,
+        O: Storage<R, C, U>,
+        F: FnMut(&mut T, &U),
+    {
+        let (buffer, other) = (self.buffer_mut(), other.buffer());
+        for row in 0..R {
+            for col in 0..C {
+                f(
+                    &mut buffer[M::Priority::offset((row, col), R, C)],
+                    &other[O::Priority::offset((row, col), R, C)],
+                );
+            }
+        }
+    }
+
+    fn zip_map<O, U, D, V, F>(&self, other: &O, dst: &mut D, mut f: F)
+    where
+        O: Storage<R, C, U>,
+        D: StorageMut<R, C, V>,
+        F: FnMut(&T, &U) -> V,
+    {
+        let (buffer, other) = (self.buffer(), other.buffer());
+        let dst = dst.buffer_mut();
+        for row in 0..R {
+            for col in 0..C {
+                dst[D::Priority::offset((row, col), R, C)] = f(
+                    &buffer[M::Priority::offset((row, col), R, C)],
+                    &other[O::Priority::offset((row, col), R, C)],
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+// Tests that keep several mutable lanes of one matrix alive at the same time. They pass under
+// plain `cargo test`, their purpose is to give Miri access patterns to check:
+// `cargo +nightly miri test --test aliasing`.
+use mightrix::{
+    ColumnPrio, ColumnPrioMatrix, Export, Reftrix, RowPrio, RowPrioMatrix, Stacktrix, StridedMut,
+    View,
+};
+
+#[test]
+fn interleaved_rows_of_column_prio() {
+    let mut m = Stacktrix::<12, 3, 4, ColumnPrio, u32>::from_fn(|r, c| (r * 4 + c) as u32);
+    let mut rows: Vec<_> = m.rows_mut().collect();
+    let last = rows.pop().unwrap();
+    let (first, middle) = rows.split_at_mut(1);
+    // Walk the rows in lock step, so their writes interleave in memory.
+    for ((a, b), c) in first[0].iter_mut().zip(middle[0].iter_mut()).zip(last) {
+        core::mem::swap(a, c);
+        *b += *a + *c;
+    }
+    assert_eq!(
+        m.to_rows(),
+        [[8, 9, 10, 11], [12, 15, 18, 21], [0, 1, 2, 3]]
+    );
+}
+
+#[test]
+fn interleaved_cols_of_row_prio() {
+    let mut m = Stacktrix::<12, 4, 3, RowPrio, u32>::from_fn(|r, c| (r * 3 + c) as u32);
+    let mut cols = m.cols_mut();
+    let mut last = cols.next_back().unwrap();
+    let mut first = cols.next().unwrap();
+    let mut middle = cols.next().unwrap();
+    assert!(cols.next().is_none());
+    first.swap(0, 3);
+    first.swap(1, 2);
+    first.swap(2, 2);
+    for i in 0..4 {
+        middle[i] += last[i];
+        last[i] = 0;
+    }
+    assert_eq!(m.to_rows(), [[9, 3, 0], [6, 9, 0], [3, 15, 0], [0, 21, 0]]);
+}
+
+#[test]
+fn lanes_and_slices_from_both_ends() {
+    let mut buffer: Vec<i32> = (0..12).collect();
+    let mut m = Reftrix::<4, 3, ColumnPrio, i32>::from_values(&mut buffer);
+    let mut cols = m.cols_mut();
+    let back = cols.next_back().unwrap();
+    let front = cols.next().unwrap();
+    back.copy_from_slice(front);
+    let mut rows = m.rows_mut();
+    let mut back = rows.next_back().unwrap();
+    let mut front = rows.next().unwrap();
+    for (a, b) in front.iter_mut().zip(back.iter_mut()) {
+        core::mem::swap(a, b);
+    }
+    assert_eq!(buffer, [3, 1, 2, 0, 7, 5, 6, 4, 3, 1, 2, 0]);
+}
+
+#[test]
+fn pair_accessors_hold_two_lanes() {
+    let mut m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let (mut a, mut b) = m.get_mut_rows(1, 0);
+    for i in 0..3 {
+        core::mem::swap(&mut a[i], &mut b[i]);
+    }
+    assert_eq!(a, [1, 2, 3]);
+    assert_eq!(b, [4, 5, 6]);
+    let (mut a, mut b) = m.get_mut_rows(0, 1);
+    a.fill(0);
+    b.copy_from_slice(&[7, 8, 9]);
+    assert_eq!(m.to_rows(), [[0, 0, 0], [7, 8, 9]]);
+
+    let mut m = Stacktrix::<6, 3, 2, RowPrio, i32>::from_rows([[1, 2], [3, 4], [5, 6]]);
+    let (mut a, b) = m.get_mut_columns(1, 0);
+    let mut b = b.into_iter().rev();
+    for el in &mut a {
+        *el -= *b.next().unwrap();
+    }
+    assert_eq!(m.to_rows(), [[1, -3], [3, 1], [5, 5]]);
+}
+
+#[test]
+fn shared_lanes_outlive_the_iterator() {
+    let m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let rows: Vec<_> = m.rows().rev().collect();
+    let last = rows[0].last().copied();
+    assert_eq!(rows[1], [1, 2, 3]);
+    assert_eq!((rows[0].first(), last), (Some(&4), Some(6)));
+}
+
+#[test]
+fn strided_views_of_interleaved_lanes() {
+    let mut m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let mut rows = m.rows_mut().map(StridedMut::from);
+    let (mut top, mut bottom) = (rows.next().unwrap(), rows.next().unwrap());
+    for (a, b) in top.iter_mut().zip(bottom.iter_mut().rev()) {
+        core::mem::swap(a, b);
+    }
+    top[1] += bottom[1];
+    assert_eq!(top, [6, 7, 4]);
+    assert_eq!(m.to_rows(), [[6, 7, 4], [3, 2, 1]]);
+}
+
+#[test]
+fn interleaved_row_chunks_of_column_prio() {
+    let mut m = Stacktrix::<12, 4, 3, ColumnPrio, i32>::from_fn(|r, c| (r * 3 + c) as i32);
+    let mut chunks = m.row_chunks_mut(2);
+    let (mut top, mut bottom) = (chunks.next().unwrap(), chunks.next_back().unwrap());
+    for (a, b) in top.iter_mut().zip(bottom.iter_mut()) {
+        core::mem::swap(a, b);
+    }
+    assert_eq!(m.to_rows(), [[6, 7, 8], [9, 10, 11], [0, 1, 2], [3, 4, 5]]);
+}
+
+#[test]
+fn empty_lanes_of_zero_width_matrices() {
+    let mut m = Stacktrix::<0, 3, 0, ColumnPrio, u32>::from_fn(|_, _| 0);
+    let mut rows = m.rows_mut();
+    let last = rows.next_back().unwrap();
+    let rows: Vec<_> = rows.collect();
+    assert_eq!(rows.len(), 2);
+    assert!(rows.iter().chain([&last]).all(|row| row.is_empty()));
+
+    let mut m = Stacktrix::<0, 0, 3, RowPrio, u32>::from_fn(|_, _| 0);
+    assert!(m.cols_mut().all(|mut col| col.iter_mut().next().is_none()));
+}
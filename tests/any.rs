@@ -0,0 +1,67 @@
+#![cfg(feature = "alloc")]
+use mightrix::{
+    AnyElement, AnyElements, AnyMatrix, ColumnPrio, ColumnPrioMatrix, MatrixError, Priority,
+    Reftrix, RowPrio, Stacktrix,
+};
+
+#[test]
+fn round_trip_both_layouts() {
+    let value = |r: usize, c: usize| (r * 10 + c) as f64;
+    let col = Stacktrix::<6, 2, 3, ColumnPrio, f64>::from_fn(value);
+    let row = Stacktrix::<6, 2, 3, RowPrio, f64>::from_fn(value);
+    let col = AnyMatrix::from_matrix(&col);
+    let row = AnyMatrix::from_matrix(&row);
+    assert_eq!(col.priority(), Priority::ColumnPrio);
+    assert_eq!(row.priority(), Priority::RowPrio);
+    for m in [&col, &row] {
+        assert_eq!(m.shape(), (2, 3));
+        assert_eq!(m.get((1, 2)), Some(AnyElement::F64(12.0)));
+        assert_eq!(m.get((2, 0)), None);
+        let expected = Stacktrix::<6, 2, 3, RowPrio, f64>::from_fn(value);
+        assert_eq!(
+            m.to_stacktrix::<6, 2, 3, ColumnPrio, f64>().unwrap(),
+            expected
+        );
+        assert_eq!(m.to_stacktrix::<6, 2, 3, RowPrio, f64>().unwrap(), expected);
+        assert!(m.to_stacktrix::<6, 3, 2, RowPrio, f64>().is_none());
+        assert!(m.to_stacktrix::<6, 2, 3, RowPrio, f32>().is_none());
+    }
+    assert_eq!(col.clone().into_priority(Priority::RowPrio), row);
+    assert_eq!(row.into_priority(Priority::ColumnPrio), col);
+}
+
+#[test]
+fn downcast_checks_priority() {
+    let mut data = [1u32, 2, 3, 4];
+    let mut m = AnyMatrix::from_matrix(&Reftrix::<2, 2, ColumnPrio, u32>::from_values(&mut data));
+    assert!(m.as_reftrix::<2, 2, RowPrio, u32>().is_none());
+    m.as_reftrix::<2, 2, ColumnPrio, u32>()
+        .unwrap()
+        .fill_col(1, &[0, 0]);
+    assert_eq!(m.into_elements(), AnyElements::U32(vec![1, 2, 0, 0]));
+}
+
+#[test]
+fn invalid_shape() {
+    assert_eq!(
+        AnyMatrix::new(2, 2, Priority::RowPrio, AnyElements::U8(vec![0; 3])),
+        Err(MatrixError::InvalidShape {
+            size: 3,
+            rows: 2,
+            cols: 2
+        })
+    ); // rows * cols wraps to 0, which must not match the empty buffer.
+    assert_eq!(
+        AnyMatrix::new(
+            1 << (usize::BITS - 1),
+            2,
+            Priority::RowPrio,
+            AnyElements::U8(vec![])
+        ),
+        Err(MatrixError::InvalidShape {
+            size: 0,
+            rows: 1 << (usize::BITS - 1),
+            cols: 2
+        })
+    );
+}
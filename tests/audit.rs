@@ -0,0 +1,105 @@
+#![cfg(feature = "audit")]
+use mightrix::{AuditLog, Audited, ColumnPrio, Operation, Reftrix, RowPrio, Stacktrix};
+
+/// Four rounds of filling a row, swapping two rows and adding a round key.
+fn reference_rounds(audited: &mut Audited<Reftrix<'_, 3, 3, ColumnPrio, u8>, 3, 3, u8>) {
+    for round in 0..4 {
+        let key = round as u8 + 1;
+        audited.fill_row(round % 3, &[key, key * 2, key * 3]);
+        audited.swap_rows(0, 2);
+        audited.apply_all(|el| *el ^= key);
+    }
+}
+
+/// The same rounds on the other memory priority, the key addition of round broken misses the
+/// last element.
+fn candidate_rounds(
+    audited: &mut Audited<Stacktrix<9, 3, 3, RowPrio, u8>, 3, 3, u8>,
+    broken: Option<usize>,
+) {
+    for round in 0..4 {
+        let key = round as u8 + 1;
+        audited.fill_row(round % 3, &[key, key * 2, key * 3]);
+        audited.swap_rows(0, 2);
+        let end = if broken == Some(round) { 8 } else { 9 };
+        audited.apply("add_key", 0..3, 0..3, |m| {
+            m.as_mut_slice()[..end].iter_mut().for_each(|el| *el ^= key)
+        });
+    }
+}
+
+/// Returns the index of the first operation that left different values behind.
+fn first_divergence<T: PartialEq>(a: &AuditLog<T>, b: &AuditLog<T>) -> Option<usize> {
+    a.operations()
+        .iter()
+        .zip(b.operations())
+        .position(|(a, b)| match (a, b) {
+            (Operation::Apply { values: a, .. }, Operation::Apply { values: b, .. }) => a != b,
+            (a, b) => a != b,
+        })
+}
+
+#[test]
+fn replay_on_other_priority_reproduces_the_matrix() {
+    let mut data = [0u8; 9];
+    let mut audited = Audited::new(Reftrix::<3, 3, ColumnPrio, u8>::from_values(&mut data));
+    reference_rounds(&mut audited);
+    audited.insert((1, 1), 0xff);
+    let log = audited.take_log();
+    assert_eq!(log.len(), 13);
+    assert!(audited.log().is_empty());
+    let mut replayed = Stacktrix::<9, 3, 3, RowPrio, u8>::zeros();
+    log.replay_on(&mut replayed);
+    assert_eq!(
+        replayed,
+        Stacktrix::<9, 3, 3, ColumnPrio, u8>::with_values(data)
+    );
+}
+
+#[test]
+fn logs_show_the_first_divergent_operation() {
+    let mut data = [0u8; 9];
+    let mut reference = Audited::new(Reftrix::<3, 3, ColumnPrio, u8>::from_values(&mut data));
+    let mut correct = Audited::new(Stacktrix::<9, 3, 3, RowPrio, u8>::zeros());
+    let mut broken = Audited::new(Stacktrix::<9, 3, 3, RowPrio, u8>::zeros());
+    reference_rounds(&mut reference);
+    candidate_rounds(&mut correct, None);
+    candidate_rounds(&mut broken, Some(2));
+    assert_eq!(first_divergence(reference.log(), correct.log()), None);
+    // Every round fills, swaps and adds the key, the third key addition is broken.
+    assert_eq!(first_divergence(reference.log(), broken.log()), Some(8));
+    assert_eq!(broken.log().operations()[8].name(), "add_key");
+    assert_eq!(
+        broken.log().operations()[8].affected::<3, 3>(),
+        (0..3, 0..3)
+    );
+}
+
+#[test]
+fn records_the_arguments() {
+    let mut audited = Audited::new(Stacktrix::<4, 2, 2, RowPrio, i32>::zeros());
+    audited.fill_col(0, &[1, 2]);
+    audited.insert((0, 1), 3);
+    assert_eq!(
+        audited.log().operations(),
+        &[
+            Operation::FillCol {
+                col: 0,
+                data: vec![1, 2]
+            },
+            Operation::Insert {
+                location: (0, 1),
+                value: 3
+            }
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn replay_checks_the_shape() {
+    let mut audited = Audited::new(Stacktrix::<9, 3, 3, RowPrio, u8>::zeros());
+    audited.fill_row(2, &[1, 2, 3]);
+    let mut small = Stacktrix::<6, 2, 3, RowPrio, u8>::zeros();
+    audited.log().replay_on(&mut small);
+}
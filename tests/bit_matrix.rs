@@ -0,0 +1,47 @@
+use mightrix::{BitMatrix, MatrixError, Reftrix, RowPrio, Stacktrix};
+
+#[test]
+fn systematic_form_of_scrambled_generator() {
+    // The generator of the [8, 4] extended hamming code with rows added onto each other.
+    let mut data = vec![0b1100_1100, 0b0100_1011, 0b0111_1000, 0b0001_1110];
+    let mut g = Reftrix::<4, 1, RowPrio, u8>::from_values(&mut data);
+    assert_eq!(g.to_systematic(), Ok(()));
+    assert_eq!(
+        &data[..],
+        &[0b1000_0111, 0b0100_1011, 0b0010_1101, 0b0001_1110]
+    );
+}
+
+#[test]
+fn parity_check_annihilates_all_codewords() {
+    let g = Stacktrix::<6, 3, 2, RowPrio, u8>::with_values([
+        0b1001_0110,
+        0b1100_0011,
+        0b0101_1010,
+        0b0011_1100,
+        0b0011_0101,
+        0b1110_0001,
+    ]);
+    let h = g.generator_to_parity_check::<13, 26>().unwrap();
+    for message in 0..8u8 {
+        let message = [message & 1 != 0, message & 2 != 0, message & 4 != 0];
+        let codeword = g.encode(&message);
+        assert_eq!(h.syndrome(&codeword), [false; 13]);
+    }
+    assert_eq!(
+        g.generator_to_parity_check::<12, 24>().err(),
+        Some(MatrixError::SizeMismatch {
+            expected: 13,
+            got: 12
+        })
+    );
+}
+
+#[test]
+fn dependent_generator_is_singular() {
+    let g = Stacktrix::<3, 3, 1, RowPrio, u8>::with_values([0b1100_0000, 0b0110_0000, 0b1010_0000]);
+    assert_eq!(
+        g.generator_to_parity_check::<5, 5>().err(),
+        Some(MatrixError::Singular)
+    );
+}
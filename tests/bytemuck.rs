@@ -0,0 +1,35 @@
+#![cfg(feature = "bytemuck")]
+use mightrix::{ByteCast, ColumnPrio, ColumnPrioMatrix, MatrixError, Reftrix, RowPrio, Stacktrix};
+
+#[test]
+fn bytes_round_trip_both_layouts() {
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, u32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let row = Stacktrix::<6, 2, 3, RowPrio, u32>::try_from_bytes(col.as_bytes()).unwrap();
+    assert_eq!(row.as_slice(), col.as_slice());
+    col.as_bytes_mut()[..4].copy_from_slice(&9u32.to_ne_bytes());
+    assert_eq!(*col.get((0, 0)), 9);
+}
+
+#[test]
+fn byte_views_check_size_and_alignment() {
+    let mut words = [0u64; 5];
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut words[..]);
+    assert_eq!(
+        Reftrix::<2, 2, ColumnPrio, u64>::try_from_bytes_mut(&mut bytes[1..33]).err(),
+        Some(MatrixError::Misaligned { align: 8 })
+    );
+    assert_eq!(
+        Reftrix::<2, 2, ColumnPrio, u64>::try_from_bytes_mut(&mut bytes[..24]).err(),
+        Some(MatrixError::SizeMismatch {
+            expected: 32,
+            got: 24
+        })
+    );
+    let mut m = Reftrix::<2, 2, ColumnPrio, u64>::try_from_bytes_mut(&mut bytes[8..40]).unwrap();
+    m.fill_col(1, &[3, 4]);
+    assert_eq!(words, [0, 0, 0, 3, 4]);
+    let unaligned = Stacktrix::<2, 1, 2, RowPrio, u64>::try_from_bytes(
+        &bytemuck::cast_slice::<u64, u8>(&words)[3 * 8 - 1..5 * 8 - 1],
+    );
+    assert!(unaligned.is_ok());
+}
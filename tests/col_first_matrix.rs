@@ -1,4 +1,7 @@
-use mightrix::{ColumnPrio, ColumnPrioMatrix, Reftrix, Stacktrix};
+// The original tests pass their buffers as &mut, Stacktrix only needs a shared borrow, and
+// iterate mutable columns with into_iter.
+#![allow(clippy::unnecessary_mut_passed, clippy::into_iter_on_ref)]
+use mightrix::{ColumnPrio, ColumnPrioMatrix, MatrixError, Reftrix, Stacktrix};
 
 // A Col first Matrix
 // 01-02-03-04
@@ -96,3 +99,88 @@ fn iter_cols_ref() {
         &[1, 2, 3, 4, 2, 3, 4, 5, 3, 4, 5, 6, 4, 5, 6, 7]
     );
 }
+
+#[test]
+fn insert_stack() {
+    let values = vec![1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4];
+    let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&values);
+    m.insert((3, 0), 0);
+    assert_eq!(m.get((3, 0)), &0);
+    assert_eq!(m.get((0, 2)), &3);
+}
+
+#[test]
+fn fill_stack() {
+    let values = vec![1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4];
+    let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&values);
+    m.fill_col(1, &[7, 7, 7, 7]);
+    assert_eq!(m.get_column(1), &[7, 7, 7, 7]);
+    m.fill_row(2, &[9, 9, 9, 9]);
+    for col in 0..4 {
+        assert_eq!(m.get((2, col)), &9);
+    }
+}
+
+#[test]
+fn apply_all_stack() {
+    let values = vec![1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4];
+    let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&values);
+    m.apply_all(|el| *el *= 2);
+    assert_eq!(m.get_column(0), &[2, 2, 2, 2]);
+    assert_eq!(m.get_column(1), &[4, 4, 4, 4]);
+    assert_eq!(m.get_column(2), &[6, 6, 6, 6]);
+    assert_eq!(m.get_column(3), &[8, 8, 8, 8]);
+}
+
+#[test]
+fn checked_accessors_stack() {
+    let values = vec![1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4];
+    let mut m = Stacktrix::<16, 4, 4, ColumnPrio, u8>::from_values(&values);
+    assert_eq!(m.try_get((3, 3)), Some(&4));
+    assert_eq!(m.try_get((4, 0)), None);
+    assert!(m.try_get_row(4).is_none());
+    assert!(m.try_get_column(4).is_none());
+    assert_eq!(
+        m.try_fill_col(0, &[0; 3]),
+        Err(MatrixError::SizeMismatch {
+            expected: 4,
+            got: 3
+        })
+    );
+    assert_eq!(
+        m.try_fill_col(4, &[0; 4]),
+        Err(MatrixError::ColumnOutOfBounds { col: 4, cols: 4 })
+    );
+    assert_eq!(
+        m.try_insert((0, 4), 0),
+        Err(MatrixError::OutOfBounds {
+            row: 0,
+            col: 4,
+            rows: 4,
+            cols: 4
+        })
+    );
+    assert_eq!(m.try_fill_col(0, &[0; 4]), Ok(()));
+    assert_eq!(m.get_column(0), &[0, 0, 0, 0]);
+}
+
+#[test]
+fn display_stack() {
+    let m = Stacktrix::<6, 2, 3, ColumnPrio, f32>::with_values([1.0, 2.5, -3.0, 4.0, 10.0, 0.25]);
+    assert_eq!(format!("{}", m), "  1 -3   10\n2.5  4 0.25\n");
+    assert_eq!(format!("{:.1}", m), "1.0 -3.0 10.0\n2.5  4.0  0.2\n");
+    let m = Stacktrix::<4, 2, 2, ColumnPrio, &str>::with_values(["a", "bc", "d", "e"]);
+    assert_eq!(format!("{:?}", m), " \"a\" \"d\"\n\"bc\" \"e\"\n");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn write_pretty_two_rows() {
+    let m = Stacktrix::<8, 2, 4, ColumnPrio, u8>::with_values([1, 2, 3, 4, 5, 6, 7, 0x10]);
+    let mut out = Vec::new();
+    m.write_pretty(&mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "01-03-05-07\n02-04-06-10\n"
+    );
+}
@@ -0,0 +1,2796 @@
+use mightrix::{
+    s, stacktrix, Accumulate, Arithmetic, Assignment, Bins, BlockMatrix, Boolean, BulkOps,
+    Canonicalize, Categorical, ColumnPrio, ColumnPrioMatrix, Diagonal, Elements, Export,
+    FieldMatrix, Flow, Gather, Histogram2D, Inverse, LanePermutation, MatrixError, MinPlus,
+    OnlineStats, OrthogonalTransform, Paths, Permutation, PingPong, Pivot, PowerIteration,
+    Progress, PseudoRandomFill, RankFilter, Reduce, Reftrix, Reorder, RowPrio, RowPrioMatrix,
+    RowRing, Sample, ScaleAdd, Search, SemiringOps, Smoothing, SortingNetwork, Stacktrix, Storage,
+    StorageMut, Strided, StridedMut, StridedView, Structure, ToExpr, TriangularSolve, Tropical,
+    View, Zip,
+};
+#[cfg(feature = "alloc")]
+use mightrix::{MatrixPatch, Patch};
+#[cfg(feature = "std")]
+use mightrix::{Orthonormalize, ParColMap};
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    ops::ControlFlow,
+};
+
+// Every accessor has to agree on what lives at (row, col), for any shape and memory priority:
+// get(r, c) == get_column(c)[r] == get_row(r)[c] and the same for the rows / cols iterators.
+
+/// A small xorshift generator, so every shape is checked with different data.
+fn values(seed: u32, len: usize) -> Vec<u32> {
+    let mut state = seed.max(1);
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        })
+        .collect()
+}
+
+macro_rules! check_col_prio {
+    ($m:expr, $r:expr, $c:expr) => {{
+        let m = $m;
+        for r in 0..$r {
+            for c in 0..$c {
+                let v = *m.get((r, c));
+                assert_eq!(m.get_column(c)[r], v);
+                assert_eq!(m.get_row(r)[c], v);
+            }
+        }
+        for (r, row) in m.rows().enumerate() {
+            for (c, v) in row.into_iter().enumerate() {
+                assert_eq!(m.get((r, c)), v);
+            }
+        }
+        for (c, col) in m.cols().enumerate() {
+            assert_eq!(col.len(), $r);
+            for (r, v) in col.iter().enumerate() {
+                assert_eq!(m.get((r, c)), v);
+            }
+        }
+        assert_eq!(m.rows().count(), $r);
+        assert_eq!(m.cols().count(), $c);
+        let locations = (0..$r).flat_map(|r| (0..$c).map(move |c| (r, c)));
+        assert!(m.iter_indexed().eq(locations.map(|l| (l, m.get(l)))));
+        assert_eq!(m.iter_indexed().len(), $r * $c);
+        assert!(m.iter().eq(m.iter_indexed().map(|(_, v)| v)));
+        let n = std::cmp::min($r, $c);
+        let (diagonal, anti) = (m.diagonal(), m.anti_diagonal());
+        assert_eq!((diagonal.len(), anti.len()), (n, n));
+        for i in 0..n {
+            assert_eq!(diagonal[i], *m.get((i, i)));
+            assert_eq!(anti[i], *m.get((i, $c - 1 - i)));
+        }
+        assert!(diagonal.get(n).is_none() && anti.get(n).is_none());
+        assert!(diagonal.iter().eq((0..n).map(|i| m.get((i, i)))));
+        assert!(anti.iter().eq((0..n).map(|i| m.get((i, $c - 1 - i)))));
+        let strided = m.strided((0, $c - 1), (1, -1), n).unwrap();
+        assert!(strided.iter().eq(anti.iter()));
+        assert!(m.strided((0, 0), (1, 1), n + 1).is_none());
+        for c in 0..$c {
+            let up = m.strided(($r - 1, c), (-1, 0), $r).unwrap();
+            assert!(up.iter().rev().eq((0..$r).map(|r| m.get((r, c)))));
+        }
+        for r in 0..$r {
+            assert!(Strided::from(m.get_row(r)).iter().eq(m.get_row(r).iter()));
+        }
+    }};
+}
+
+macro_rules! check_row_prio {
+    ($m:expr, $r:expr, $c:expr) => {{
+        let m = $m;
+        for r in 0..$r {
+            for c in 0..$c {
+                let v = *m.get((r, c));
+                assert_eq!(m.get_column(c)[r], v);
+                assert_eq!(m.get_row(r)[c], v);
+            }
+        }
+        for (r, row) in m.rows().enumerate() {
+            assert_eq!(row.len(), $c);
+            for (c, v) in row.iter().enumerate() {
+                assert_eq!(m.get((r, c)), v);
+            }
+        }
+        for (c, col) in m.cols().enumerate() {
+            for (r, v) in col.into_iter().enumerate() {
+                assert_eq!(m.get((r, c)), v);
+            }
+        }
+        assert_eq!(m.rows().count(), $r);
+        assert_eq!(m.cols().count(), $c);
+        let locations = (0..$r).flat_map(|r| (0..$c).map(move |c| (r, c)));
+        assert!(m.iter_indexed().eq(locations.map(|l| (l, m.get(l)))));
+        assert_eq!(m.iter_indexed().len(), $r * $c);
+        assert!(m.iter().eq(m.iter_indexed().map(|(_, v)| v)));
+        let n = std::cmp::min($r, $c);
+        let (diagonal, anti) = (m.diagonal(), m.anti_diagonal());
+        assert_eq!((diagonal.len(), anti.len()), (n, n));
+        for i in 0..n {
+            assert_eq!(diagonal[i], *m.get((i, i)));
+            assert_eq!(anti[i], *m.get((i, $c - 1 - i)));
+        }
+        assert!(diagonal.get(n).is_none() && anti.get(n).is_none());
+        assert!(diagonal.iter().eq((0..n).map(|i| m.get((i, i)))));
+        assert!(anti.iter().eq((0..n).map(|i| m.get((i, $c - 1 - i)))));
+        let strided = m.strided((0, $c - 1), (1, -1), n).unwrap();
+        assert!(strided.iter().eq(anti.iter()));
+        assert!(m.strided((0, 0), (1, 1), n + 1).is_none());
+        for c in 0..$c {
+            let up = m.strided(($r - 1, c), (-1, 0), $r).unwrap();
+            assert!(up.iter().rev().eq((0..$r).map(|r| m.get((r, c)))));
+        }
+        for c in 0..$c {
+            assert!(Strided::from(m.get_column(c))
+                .iter()
+                .eq(m.get_column(c).iter()));
+        }
+    }};
+}
+
+macro_rules! cross_layout {
+    ($($name:ident: $r:literal x $c:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                let data = values(($r * 31 + $c) as u32, $r * $c);
+
+                // ColumnPrio stores column c at data[c * R..(c + 1) * R].
+                let mut col_data = data.clone();
+                let col_ref = Reftrix::<$r, $c, ColumnPrio, u32>::from_values(&mut col_data);
+                for r in 0..$r {
+                    for c in 0..$c {
+                        assert_eq!(*col_ref.get((r, c)), data[c * $r + r]);
+                    }
+                }
+                check_col_prio!(&col_ref, $r, $c);
+                check_col_prio!(
+                    &Stacktrix::<{ $r * $c }, $r, $c, ColumnPrio, u32>::from_values(&data),
+                    $r,
+                    $c
+                );
+
+                // RowPrio stores row r at data[r * C..(r + 1) * C].
+                let mut row_data = data.clone();
+                let row_ref = Reftrix::<$r, $c, RowPrio, u32>::from_values(&mut row_data);
+                for r in 0..$r {
+                    for c in 0..$c {
+                        assert_eq!(*row_ref.get((r, c)), data[r * $c + c]);
+                    }
+                }
+                check_row_prio!(&row_ref, $r, $c);
+                check_row_prio!(
+                    &Stacktrix::<{ $r * $c }, $r, $c, RowPrio, u32>::from_values(&data),
+                    $r,
+                    $c
+                );
+            }
+        )*
+    };
+}
+
+cross_layout! {
+    shape_1x1: 1 x 1,
+    shape_1x5: 1 x 5,
+    shape_5x1: 5 x 1,
+    shape_2x3: 2 x 3,
+    shape_3x2: 3 x 2,
+    shape_4x4: 4 x 4,
+    shape_3x7: 3 x 7,
+    shape_7x3: 7 x 3,
+    shape_6x9: 6 x 9,
+}
+
+#[test]
+fn fill_non_square() {
+    let mut data = vec![0u8; 6];
+    let mut m = Reftrix::<2, 3, ColumnPrio, u8>::from_values(&mut data);
+    m.fill_row(1, &[1, 2, 3]);
+    m.fill_col(0, &[4, 5]);
+    assert_eq!(&data[..], &[4, 5, 0, 2, 0, 3]);
+
+    let mut data = vec![0u8; 6];
+    let mut m = Reftrix::<2, 3, RowPrio, u8>::from_values(&mut data);
+    m.fill_col(2, &[1, 2]);
+    m.fill_row(0, &[4, 5, 6]);
+    assert_eq!(&data[..], &[4, 5, 6, 0, 0, 2]);
+}
+
+#[test]
+fn diagonal_mut_non_square() {
+    for anti in [false, true] {
+        let mut col_data = vec![0u8; 12];
+        let mut row_data = vec![0u8; 12];
+        let mut col = Reftrix::<3, 4, ColumnPrio, u8>::from_values(&mut col_data);
+        let mut row = Reftrix::<3, 4, RowPrio, u8>::from_values(&mut row_data);
+        let (mut a, mut b) = if anti {
+            (col.anti_diagonal_mut(), row.anti_diagonal_mut())
+        } else {
+            (col.diagonal_mut(), row.diagonal_mut())
+        };
+        for (i, el) in a.iter_mut().enumerate() {
+            *el = i as u8 + 1;
+        }
+        for i in 0..b.len() {
+            b[i] = i as u8 + 1;
+        }
+        for r in 0..3 {
+            for c in 0..4 {
+                let expected = if (anti && c == 3 - r) || (!anti && c == r) {
+                    r as u8 + 1
+                } else {
+                    0
+                };
+                assert_eq!(*col.get((r, c)), expected);
+                assert_eq!(*row.get((r, c)), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn iter_mut_non_square() {
+    let mut data = vec![0u8; 6];
+    let mut m = Reftrix::<2, 3, RowPrio, u8>::from_values(&mut data);
+    for (c, col) in m.cols_mut().enumerate() {
+        for (r, el) in col.into_iter().enumerate() {
+            *el = (r * 10 + c) as u8;
+        }
+    }
+    assert_eq!(&data[..], &[0, 1, 2, 10, 11, 12]);
+
+    let mut data = vec![0u8; 6];
+    let mut m = Reftrix::<2, 3, ColumnPrio, u8>::from_values(&mut data);
+    for (c, col) in m.cols_mut().enumerate() {
+        for (r, el) in col.iter_mut().enumerate() {
+            *el = (r * 10 + c) as u8;
+        }
+    }
+    assert_eq!(&data[..], &[0, 10, 1, 11, 2, 12]);
+}
+
+#[test]
+fn permutation_round_trip() {
+    let data = values(7, 15);
+    let p = Permutation::from_indices([3, 0, 4, 1, 2]).unwrap();
+    let mut col = Stacktrix::<15, 5, 3, ColumnPrio, u32>::from_values(&data);
+    let mut row = Stacktrix::<15, 5, 3, RowPrio, u32>::from_values(&data);
+    let before: Vec<u32> = (0..5)
+        .map(|r| *ColumnPrioMatrix::get(&col, (r, 1)))
+        .collect();
+    p.apply_to_rows(&mut col);
+    p.apply_to_rows(&mut row);
+    for r in 0..5 {
+        assert_eq!(*ColumnPrioMatrix::get(&col, (r, 1)), before[p.indices()[r]]);
+    }
+    p.inverse().apply_to_rows(&mut col);
+    assert_eq!(
+        Permutation::<5>::from_matrix(&p.to_matrix::<25, ColumnPrio, u32>()),
+        Some(p)
+    );
+    for (r, expected) in before.iter().enumerate() {
+        assert_eq!(ColumnPrioMatrix::get(&col, (r, 1)), expected);
+    }
+}
+
+#[test]
+fn canonical_form_ignores_lane_order() {
+    let data = values(11, 15);
+    let rows = Permutation::from_indices([3, 0, 4, 1, 2]).unwrap();
+    let cols = Permutation::from_indices([2, 0, 1]).unwrap();
+    let mut a = Stacktrix::<15, 5, 3, RowPrio, u32>::from_values(&data);
+    let mut b = Stacktrix::<15, 5, 3, ColumnPrio, u32>::from_rows(a.to_rows());
+    rows.apply_to_rows(&mut b);
+    cols.apply_to_cols(&mut b);
+    let (p, q) = a.canonicalize();
+    let (p_b, q_b) = b.canonicalize();
+    assert_eq!(a, b);
+    assert_eq!(rows.compose(&p_b), p);
+    assert_eq!(cols.compose(&q_b), q);
+    assert_eq!(
+        a.canonicalize(),
+        (Permutation::identity(), Permutation::identity())
+    );
+
+    // Two labelings of the path 0 - 1 - 2 - 3 - 4 and 3 - 0 - 4 - 1 - 2.
+    let edges = |path: [usize; 5]| {
+        Stacktrix::<25, 5, 5, RowPrio, u8>::from_fn(|r, c| {
+            let (r, c) = (path[r], path[c]);
+            u8::from(r.abs_diff(c) == 1)
+        })
+    };
+    let (mut a, mut b) = (edges([0, 1, 2, 3, 4]), edges([1, 3, 4, 0, 2]));
+    assert_ne!(a, b);
+    a.canonicalize();
+    b.canonicalize();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn strided_mut_both_layouts() {
+    let rows = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+    let mut col = Stacktrix::<12, 3, 4, ColumnPrio, i32>::from_rows(rows);
+    let mut row = Stacktrix::<12, 3, 4, RowPrio, i32>::from_rows(rows);
+    col.strided_mut((1, 3), (0, -2), 2).unwrap().fill(0);
+    row.strided_mut((1, 3), (0, -2), 2).unwrap().fill(0);
+    let mut last = StridedMut::from(col.get_mut_row(2));
+    last[3] = -1;
+    assert_eq!(last.stride(), 3);
+    let mut last = StridedMut::from(row.get_mut_column(3));
+    last[2] = -1;
+    assert_eq!(last.stride(), 4);
+    let expected = [[1, 2, 3, 4], [5, 0, 7, 0], [9, 10, 11, -1]];
+    assert_eq!((col.to_rows(), row.to_rows()), (expected, expected));
+    assert!(row.strided_mut((0, 0), (0, 0), 2).is_none());
+    assert!(row.strided_mut((0, 0), (0, 0), 1).is_some());
+    assert!(row.strided_mut((3, 0), (0, 0), 0).is_some());
+    assert!(col.strided_mut((0, 3), (1, 1), 2).is_none());
+}
+
+fn hash_of<H: Hash>(value: &H) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn equality_across_layouts() {
+    let col = Stacktrix::<6, 2, 3, ColumnPrio, u8>::with_values([1, 4, 2, 5, 3, 6]);
+    let row = Stacktrix::<6, 2, 3, RowPrio, u8>::with_values([1, 2, 3, 4, 5, 6]);
+    assert!(col == row);
+    assert_eq!(hash_of(&col), hash_of(&row));
+
+    let mut data = vec![1, 2, 3, 4, 5, 6];
+    let mut row_ref = Reftrix::<2, 3, RowPrio, u8>::from_values(&mut data);
+    assert!(row_ref == row);
+    assert!(col == row_ref);
+    assert_eq!(hash_of(&row_ref), hash_of(&col));
+
+    row_ref.insert((1, 2), 7);
+    assert!(row_ref != row);
+    assert!(col != row_ref);
+
+    let same = Stacktrix::<6, 2, 3, ColumnPrio, u8>::with_values([1, 4, 2, 5, 3, 6]);
+    assert_eq!(col, same);
+    assert_ne!(
+        col,
+        Stacktrix::<6, 2, 3, ColumnPrio, u8>::with_values([1, 2, 3, 4, 5, 6])
+    );
+}
+
+#[test]
+fn pivot_both_layouts() {
+    let data = [
+        0.5,
+        f64::NAN,
+        2.0,
+        -7.0,
+        3.0,
+        1.0,
+        7.0,
+        0.0,
+        -1.0,
+        4.0,
+        -2.0,
+        6.0,
+    ];
+    let mut col = Stacktrix::<12, 4, 3, ColumnPrio, f64>::from_values(&data);
+    let mut row = Stacktrix::<12, 4, 3, RowPrio, f64>::from_values(&data);
+    // Column 0 of the ColumnPrio matrix is [0.5, NaN, 2.0, -7.0].
+    assert_eq!(col.max_abs_in_col(0, 0), Some(3));
+    assert_eq!(col.max_abs_in_col(1, 2), Some(2));
+    // Column 0 of the RowPrio matrix is [0.5, -7.0, 7.0, 4.0], the first maximum wins.
+    assert_eq!(row.max_abs_in_col(0, 0), Some(1));
+    assert_eq!(row.max_abs_in_col(1, 1), Some(1));
+
+    assert_eq!(row.swap_to_pivot(2, 0), Some(3));
+    assert_eq!(RowPrioMatrix::get_row(&row, 0), &[4.0, -2.0, 6.0]);
+    let moved = RowPrioMatrix::get_row(&row, 3);
+    assert_eq!((moved[0], moved[2]), (0.5, 2.0));
+    assert!(moved[1].is_nan());
+
+    col.swap_rows(0, 3);
+    assert_eq!(
+        ColumnPrioMatrix::get_row(&col, 0)
+            .into_iter()
+            .copied()
+            .collect::<Vec<_>>(),
+        vec![-7.0, 0.0, 6.0]
+    );
+}
+
+#[test]
+fn pivot_with_min_values() {
+    let data = [3, i32::MIN, i32::MAX, -5, i32::MIN, 0];
+    let col = Stacktrix::<6, 3, 2, ColumnPrio, i32>::from_values(&data);
+    let row = Stacktrix::<6, 3, 2, RowPrio, i32>::from_values(&data);
+    // Column 0 of the ColumnPrio matrix is [3, MIN, MAX].
+    assert_eq!(col.max_abs_in_col(0, 0), Some(1));
+    assert_eq!(col.max_abs_in_col(0, 2), Some(2));
+    // Column 1 of the ColumnPrio matrix is [-5, MIN, 0].
+    assert_eq!(col.max_abs_in_col(1, 0), Some(1));
+    // Column 0 of the RowPrio matrix is [3, MAX, MIN].
+    assert_eq!(row.max_abs_in_col(0, 0), Some(2));
+    // Column 1 of the RowPrio matrix is [MIN, -5, 0].
+    assert_eq!(row.max_abs_in_col(1, 0), Some(0));
+    assert_eq!(row.max_abs_in_col(1, 1), Some(1));
+}
+
+#[test]
+fn structure_both_layouts() {
+    let lower = [1, 0, 0, 2, 3, 0, 4, 5, 6];
+    let col = Stacktrix::<9, 3, 3, ColumnPrio, i32>::from_values(&lower);
+    let row = Stacktrix::<9, 3, 3, RowPrio, i32>::from_values(&lower);
+    // The same buffer is upper triangular with ColumnPrio and lower triangular with RowPrio.
+    assert!(col.is_upper_triangular());
+    assert!(!row.is_upper_triangular());
+    assert!(!col.is_symmetric() && !col.is_diagonal());
+
+    let wide = Stacktrix::<6, 2, 3, RowPrio, i32>::with_values([1, 2, 3, 0, 4, 5]);
+    assert!(wide.is_upper_triangular());
+    assert!(!wide.is_symmetric());
+
+    let permutation = Stacktrix::<9, 3, 3, RowPrio, i32>::with_values([0, 1, 0, 0, 0, 1, 1, 0, 0]);
+    assert!(permutation.is_orthogonal(0));
+    assert!(!col.is_orthogonal(0));
+    let nan = Stacktrix::<1, 1, 1, RowPrio, f64>::with_values([f64::NAN]);
+    assert!(!nan.is_orthogonal(1.0));
+}
+
+#[test]
+fn orthogonal_transform_both_layouts() {
+    // The same logical 4 x 3 matrix in both memory priorities.
+    let logical: Vec<f64> = values(11, 12).iter().map(|&v| (v % 100) as f64).collect();
+    let col_data: Vec<f64> = (0..12).map(|i| logical[(i % 4) * 3 + i / 4]).collect();
+    let mut col = Stacktrix::<12, 4, 3, ColumnPrio, f64>::from_values(&col_data);
+    let mut row = Stacktrix::<12, 4, 3, RowPrio, f64>::from_values(&logical);
+    assert!(col == row);
+
+    let v = [1.5, -2.0, 0.5];
+    col.apply_householder_left(&v, 1);
+    row.apply_householder_left(&v, 1);
+    col.apply_givens(3, 0, 0.8, -0.6);
+    row.apply_givens(3, 0, 0.8, -0.6);
+    for r in 0..4 {
+        for c in 0..3 {
+            let (a, b) = (
+                *ColumnPrioMatrix::get(&col, (r, c)),
+                *RowPrioMatrix::get(&row, (r, c)),
+            );
+            assert!((a - b).abs() < 1e-9, "({}, {}): {} != {}", r, c, a, b);
+        }
+    }
+    // Column 0 is only touched by the givens rotation of rows 0 and 3.
+    assert_eq!(*RowPrioMatrix::get(&row, (1, 0)), logical[3]);
+
+    // The rotation is undone by its transpose, reflections are involutions.
+    col.apply_givens(3, 0, 0.8, 0.6);
+    col.apply_householder_left(&v, 1);
+    for r in 0..4 {
+        for c in 0..3 {
+            let a = *ColumnPrioMatrix::get(&col, (r, c));
+            assert!((a - logical[r * 3 + c]).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn triangular_solve_both_layouts() {
+    // Rows of [2 1 3; 4 5 6; 7 8 9], the upper triangle is [2 1 3; 0 5 6; 0 0 9] and the lower
+    // triangle is [2 0 0; 4 5 0; 7 8 9].
+    let rows = [2.0, 1.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+    let cols = [2.0, 4.0, 7.0, 1.0, 5.0, 8.0, 3.0, 6.0, 9.0];
+    let row = Stacktrix::<9, 3, 3, RowPrio, f64>::from_values(&rows);
+    let col = Stacktrix::<9, 3, 3, ColumnPrio, f64>::from_values(&cols);
+    for (m, name) in [(&row as &dyn Solve, "row"), (&col as &dyn Solve, "col")] {
+        assert_eq!(
+            m.upper(&[13.0, 28.0, 27.0], Diagonal::Stored),
+            Ok([1.0, 2.0, 3.0]),
+            "{}",
+            name
+        );
+        assert_eq!(
+            m.upper(&[12.0, 20.0, 3.0], Diagonal::Unit),
+            Ok([1.0, 2.0, 3.0]),
+            "{}",
+            name
+        );
+        assert_eq!(
+            m.lower(&[2.0, 14.0, 50.0], Diagonal::Stored),
+            Ok([1.0, 2.0, 3.0]),
+            "{}",
+            name
+        );
+        assert_eq!(
+            m.lower(&[1.0, 6.0, 26.0], Diagonal::Unit),
+            Ok([1.0, 2.0, 3.0]),
+            "{}",
+            name
+        );
+    }
+
+    let singular = Stacktrix::<4, 2, 2, RowPrio, f64>::with_values([1.0, 2.0, 0.0, 0.0]);
+    assert_eq!(
+        singular.solve_upper_triangular(&[1.0, 1.0], Diagonal::Stored),
+        Err(MatrixError::Singular)
+    );
+    assert!(singular
+        .solve_upper_triangular(&[1.0, 1.0], Diagonal::Unit)
+        .is_ok());
+    let wide = Stacktrix::<6, 2, 3, RowPrio, f64>::with_values([1.0; 6]);
+    assert_eq!(
+        wide.solve_lower_triangular(&[1.0, 1.0], Diagonal::Stored),
+        Err(MatrixError::NotSquare { rows: 2, cols: 3 })
+    );
+}
+
+/// Erases the memory priority, so both layouts can be checked in one loop.
+trait Solve {
+    fn upper(&self, b: &[f64; 3], diagonal: Diagonal) -> Result<[f64; 3], MatrixError>;
+    fn lower(&self, b: &[f64; 3], diagonal: Diagonal) -> Result<[f64; 3], MatrixError>;
+}
+
+impl<M: TriangularSolve<3, 3, f64>> Solve for M {
+    fn upper(&self, b: &[f64; 3], diagonal: Diagonal) -> Result<[f64; 3], MatrixError> {
+        self.solve_upper_triangular(b, diagonal)
+    }
+
+    fn lower(&self, b: &[f64; 3], diagonal: Diagonal) -> Result<[f64; 3], MatrixError> {
+        self.solve_lower_triangular(b, diagonal)
+    }
+}
+
+macro_rules! bulk_ops {
+    ($name:ident, $t:ty) => {
+        #[test]
+        fn $name() {
+            // 5 x 7 leaves remainders after the 16 byte blocks.
+            let a: Vec<$t> = values(3, 35).iter().map(|&v| v as $t).collect();
+            let b: Vec<$t> = values(5, 35).iter().map(|&v| v as $t).collect();
+            let mut col = Stacktrix::<35, 5, 7, ColumnPrio, $t>::from_values(&a);
+            let mut row = Stacktrix::<35, 5, 7, RowPrio, $t>::from_values(&a);
+            let other = Stacktrix::<35, 5, 7, RowPrio, $t>::from_values(&b);
+            let expected = |r: usize, c: usize, start: &[$t], col_prio: bool| {
+                let i = if col_prio { c * 5 + r } else { r * 7 + c };
+                start[i]
+            };
+
+            col.wrapping_add_assign(&other);
+            row.wrapping_add_assign(&other);
+            for r in 0..5 {
+                for c in 0..7 {
+                    let o = b[r * 7 + c];
+                    assert_eq!(
+                        *ColumnPrioMatrix::get(&col, (r, c)),
+                        expected(r, c, &a, true).wrapping_add(o)
+                    );
+                    assert_eq!(
+                        *RowPrioMatrix::get(&row, (r, c)),
+                        expected(r, c, &a, false).wrapping_add(o)
+                    );
+                }
+            }
+
+            col.xor_assign(&other);
+            row.xor_assign(&other);
+            let column: [$t; 5] = core::array::from_fn(|i| i as $t * 3);
+            let line: [$t; 7] = core::array::from_fn(|i| i as $t + 1);
+            col.xor_col(6, &column);
+            row.xor_col(6, &column);
+            col.xor_row(2, &line);
+            row.xor_row(2, &line);
+            let mut lut = [0u8; 256];
+            for (i, el) in lut.iter_mut().enumerate() {
+                *el = (i as u8).wrapping_mul(7) ^ 0x63;
+            }
+            col.substitute(&lut);
+            row.substitute(&lut);
+            let sub = |v: $t| <$t>::from_ne_bytes(v.to_ne_bytes().map(|b| lut[b as usize]));
+            for r in 0..5 {
+                for c in 0..7 {
+                    let o = b[r * 7 + c];
+                    let mut mask = if c == 6 { column[r] } else { 0 };
+                    if r == 2 {
+                        mask ^= line[c];
+                    }
+                    assert_eq!(
+                        *ColumnPrioMatrix::get(&col, (r, c)),
+                        sub((expected(r, c, &a, true).wrapping_add(o) ^ o) ^ mask)
+                    );
+                    assert_eq!(
+                        *RowPrioMatrix::get(&row, (r, c)),
+                        sub((expected(r, c, &a, false).wrapping_add(o) ^ o) ^ mask)
+                    );
+                }
+            }
+            col.substitute_inverse(&lut);
+            row.substitute_inverse(&lut);
+            for r in 0..5 {
+                for c in 0..7 {
+                    let o = b[r * 7 + c];
+                    let mut mask = if c == 6 { column[r] } else { 0 };
+                    if r == 2 {
+                        mask ^= line[c];
+                    }
+                    assert_eq!(
+                        *ColumnPrioMatrix::get(&col, (r, c)),
+                        (expected(r, c, &a, true).wrapping_add(o) ^ o) ^ mask
+                    );
+                    assert_eq!(
+                        *RowPrioMatrix::get(&row, (r, c)),
+                        (expected(r, c, &a, false).wrapping_add(o) ^ o) ^ mask
+                    );
+                }
+            }
+        }
+    };
+}
+
+bulk_ops!(bulk_ops_u8, u8);
+bulk_ops!(bulk_ops_u32, u32);
+
+#[test]
+#[should_panic]
+fn substitute_inverse_requires_permutation() {
+    let mut m = Stacktrix::<4, 2, 2, RowPrio, u8>::with_values([1, 2, 3, 4]);
+    m.substitute_inverse(&[0; 256]);
+}
+
+#[test]
+fn triangular_solve_many_both_layouts() {
+    let rows = [2.0, 1.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+    let a = Stacktrix::<9, 3, 3, RowPrio, f64>::from_values(&rows);
+    // The columns of X are (1, 2, 3), (0, 1, 0) and (-1, 0, 2).
+    let upper_b = [13.0, 28.0, 27.0, 1.0, 5.0, 0.0, 4.0, 12.0, 18.0];
+    let lower_b = [2.0, 14.0, 50.0, 0.0, 5.0, 8.0, -2.0, -4.0, 11.0];
+    let x = [1.0, 2.0, 3.0, 0.0, 1.0, 0.0, -1.0, 0.0, 2.0];
+    let transposed =
+        |m: &[f64; 9]| -> [f64; 9] { core::array::from_fn(|i| m[(i % 3) * 3 + i / 3]) };
+
+    let mut col_b = Stacktrix::<9, 3, 3, ColumnPrio, f64>::from_values(&upper_b);
+    let mut row_b = Stacktrix::<9, 3, 3, RowPrio, f64>::from_values(&transposed(&upper_b));
+    a.solve_upper_triangular_many(&mut col_b, Diagonal::Stored)
+        .unwrap();
+    a.solve_upper_triangular_many(&mut row_b, Diagonal::Stored)
+        .unwrap();
+    assert_eq!(
+        col_b,
+        Stacktrix::<9, 3, 3, ColumnPrio, f64>::from_values(&x)
+    );
+    assert_eq!(row_b, col_b);
+
+    let mut col_b = Stacktrix::<9, 3, 3, ColumnPrio, f64>::from_values(&lower_b);
+    let mut row_b = Stacktrix::<9, 3, 3, RowPrio, f64>::from_values(&transposed(&lower_b));
+    a.solve_lower_triangular_many(&mut col_b, Diagonal::Stored)
+        .unwrap();
+    a.solve_lower_triangular_many(&mut row_b, Diagonal::Stored)
+        .unwrap();
+    assert_eq!(
+        col_b,
+        Stacktrix::<9, 3, 3, ColumnPrio, f64>::from_values(&x)
+    );
+    assert_eq!(row_b, col_b);
+
+    let singular = Stacktrix::<4, 2, 2, RowPrio, f64>::with_values([1.0, 2.0, 0.0, 0.0]);
+    let mut b = Stacktrix::<2, 2, 1, RowPrio, f64>::with_values([1.0, 1.0]);
+    assert_eq!(
+        singular.solve_upper_triangular_many(&mut b, Diagonal::Stored),
+        Err(MatrixError::Singular)
+    );
+    assert_eq!(
+        b,
+        Stacktrix::<2, 2, 1, RowPrio, f64>::with_values([1.0, 1.0])
+    );
+}
+
+#[test]
+fn invert_in_place_both_layouts() {
+    let logical: Vec<f64> = values(13, 16)
+        .iter()
+        .map(|&v| (v % 19) as f64 - 9.0)
+        .collect();
+    let transposed: Vec<f64> = (0..16).map(|i| logical[(i % 4) * 4 + i / 4]).collect();
+    let mut row = Stacktrix::<16, 4, 4, RowPrio, f64>::from_values(&logical);
+    let mut col = Stacktrix::<16, 4, 4, ColumnPrio, f64>::from_values(&transposed);
+    row.invert_in_place().unwrap();
+    col.invert_in_place().unwrap();
+    for r in 0..4 {
+        for c in 0..4 {
+            let product: f64 = (0..4)
+                .map(|k| logical[r * 4 + k] * RowPrioMatrix::get(&row, (k, c)))
+                .sum();
+            let expected = if r == c { 1.0 } else { 0.0 };
+            assert!(
+                (product - expected).abs() < 1e-9,
+                "({}, {}): {}",
+                r,
+                c,
+                product
+            );
+            let (a, b) = (
+                *RowPrioMatrix::get(&row, (r, c)),
+                *ColumnPrioMatrix::get(&col, (r, c)),
+            );
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    let mut singular = Stacktrix::<9, 3, 3, ColumnPrio, f64>::with_values([
+        1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 5.0,
+    ]);
+    assert_eq!(singular.invert_in_place(), Err(MatrixError::Singular));
+    let mut wide = Stacktrix::<6, 2, 3, RowPrio, f64>::with_values([1.0; 6]);
+    assert_eq!(
+        wide.invert_in_place(),
+        Err(MatrixError::NotSquare { rows: 2, cols: 3 })
+    );
+}
+
+#[test]
+fn progress_reports_and_cancels_both_layouts() {
+    let logical: Vec<f64> = values(17, 16)
+        .iter()
+        .map(|&v| (v % 19) as f64 - 9.0)
+        .collect();
+    let mut row = Stacktrix::<16, 4, 4, RowPrio, f64>::from_values(&logical);
+    let mut col = Stacktrix::<16, 4, 4, ColumnPrio, f64>::from_rows(row.to_rows());
+    let mut reports = Vec::new();
+    fn record(reports: &mut Vec<Progress>) -> impl FnMut(Progress) -> ControlFlow<()> + '_ {
+        move |progress| {
+            reports.push(progress);
+            ControlFlow::Continue(())
+        }
+    }
+    row.invert_in_place_with_progress(record(&mut reports))
+        .unwrap();
+    let expected: Vec<_> = (1..=4)
+        .map(|done| Progress {
+            done,
+            total: Some(4),
+        })
+        .collect();
+    assert_eq!(reports, expected);
+    reports.clear();
+    let mut product = Stacktrix::<16, 4, 4, ColumnPrio, f64>::zeros();
+    let result =
+        col.semiring_mul_into_with_progress(&row, &mut product, Arithmetic, record(&mut reports));
+    assert_eq!((result, &reports), (Ok(()), &expected));
+    for r in 0..4 {
+        for c in 0..4 {
+            let expected = if r == c { 1.0 } else { 0.0 };
+            assert!((ColumnPrioMatrix::get(&product, (r, c)) - expected).abs() < 1e-9);
+        }
+    }
+
+    let cancel_after = |steps: usize| {
+        move |progress: Progress| match progress.done < steps {
+            true => ControlFlow::Continue(()),
+            false => ControlFlow::Break(()),
+        }
+    };
+    assert_eq!(
+        col.invert_in_place_with_progress(cancel_after(2)),
+        Err(MatrixError::Cancelled)
+    );
+    assert_eq!(
+        col.semiring_mul_into_with_progress(&row, &mut product, Arithmetic, cancel_after(1)),
+        Err(MatrixError::Cancelled)
+    );
+    let mut reach = Stacktrix::<9, 3, 3, ColumnPrio, bool>::from_fn(|i, j| j == i + 1);
+    assert_eq!(
+        reach.semiring_closure_with_progress(Boolean, cancel_after(3)),
+        Err(MatrixError::Cancelled)
+    );
+    assert_eq!(
+        reach,
+        Stacktrix::<9, 3, 3, RowPrio, bool>::from_fn(|i, j| j > i)
+    );
+    assert_eq!(
+        MatrixError::Cancelled.to_string(),
+        "the operation was cancelled"
+    );
+}
+
+#[test]
+fn get_disjoint_mut_both_layouts() {
+    let mut col = Stacktrix::<12, 3, 4, ColumnPrio, u32>::from_fn(|r, c| (r * 4 + c) as u32);
+    let mut row = Stacktrix::<12, 3, 4, RowPrio, u32>::from_fn(|r, c| (r * 4 + c) as u32);
+    let corners = [(0, 0), (0, 3), (2, 3), (2, 0)];
+    let rotate = |cells: [&mut u32; 4]| {
+        let [a, b, c, d] = cells;
+        (*a, *b, *c, *d) = (*d, *a, *b, *c);
+    };
+    rotate(col.get_disjoint_mut(corners).unwrap());
+    rotate(row.get_disjoint_mut(corners).unwrap());
+    let expected = [[8, 1, 2, 0], [4, 5, 6, 7], [11, 9, 10, 3]];
+    assert_eq!((col.to_rows(), row.to_rows()), (expected, expected));
+    assert!(col.get_disjoint_mut([(1, 1), (2, 2), (1, 1)]).is_none());
+    assert!(row.get_disjoint_mut([(0, 4)]).is_none());
+    // (0, 4) would alias (1, 0) in memory if it was not rejected.
+    assert!(row.get_disjoint_mut([(0, 4), (1, 0)]).is_none());
+    assert_eq!(col.get_disjoint_mut::<0>([]), Some([]));
+}
+
+#[test]
+fn windows_and_chunks_both_layouts() {
+    let col = Stacktrix::<20, 4, 5, ColumnPrio, u32>::from_fn(|r, c| (r * 5 + c) as u32);
+    let row = Stacktrix::<20, 4, 5, RowPrio, u32>::from_fn(|r, c| (r * 5 + c) as u32);
+    // A 3x3 box sum, built from windows of rows and then of columns.
+    let col_sums: Vec<u32> = col
+        .row_windows(3)
+        .flat_map(|w| (0..3).map(move |c| w.view(.., c..c + 3).iter().sum::<u32>()))
+        .collect();
+    let row_sums: Vec<u32> = row
+        .col_windows(3)
+        .flat_map(|w| (0..2).map(move |r| w.view(r..r + 3, ..).iter().sum::<u32>()))
+        .collect();
+    assert_eq!(col_sums, [54, 63, 72, 99, 108, 117]);
+    assert_eq!(row_sums, [54, 99, 63, 108, 72, 117]);
+    assert_eq!(col.row_windows(5).len(), 0);
+    assert_eq!(
+        row.col_windows(1)
+            .next_back()
+            .unwrap()
+            .iter()
+            .copied()
+            .collect::<Vec<_>>(),
+        [4, 9, 14, 19]
+    );
+
+    for chunks in [col.col_chunks(2), row.col_chunks(2)] {
+        let shapes: Vec<_> = chunks.rev().map(|c| (c.shape(), c[(3, 0)])).collect();
+        assert_eq!(shapes, [((4, 1), 19), ((4, 2), 17), ((4, 2), 15)]);
+    }
+    assert_eq!(col.row_chunks(3).len(), 2);
+
+    let mut col = Stacktrix::<20, 4, 5, ColumnPrio, u32>::zeros();
+    let mut row = Stacktrix::<20, 4, 5, RowPrio, u32>::zeros();
+    for (i, mut chunk) in col.row_chunks_mut(3).rev().enumerate() {
+        chunk.fill(i as u32);
+    }
+    let mut chunks = row.row_chunks_mut(3);
+    let (mut top, mut bottom) = (chunks.next().unwrap(), chunks.next().unwrap());
+    assert!(chunks.next().is_none());
+    top.fill(1);
+    bottom.fill(0);
+    assert_eq!(col, row);
+    assert_eq!(col.to_rows()[2], [1; 5]);
+    assert_eq!(col.to_rows()[3], [0; 5]);
+    let mut cols = row.col_chunks_mut(2);
+    let (mut left, mut right) = (cols.next().unwrap(), cols.next_back().unwrap());
+    left[(0, 1)] = 7;
+    right[(3, 0)] = 8;
+    assert_eq!(cols.len(), 1);
+    assert_eq!(row.to_rows()[0], [1, 7, 1, 1, 1]);
+    assert_eq!(row.to_rows()[3], [0, 0, 0, 0, 8]);
+}
+
+#[test]
+#[should_panic]
+fn chunks_of_size_zero() {
+    let m = Stacktrix::<4, 2, 2, RowPrio, u8>::zeros();
+    m.row_chunks(0);
+}
+
+#[test]
+fn constructors_both_layouts() {
+    let col = Stacktrix::<21, 3, 7, ColumnPrio, usize>::from_fn(|r, c| r * 7 + c);
+    let row = Stacktrix::<21, 3, 7, RowPrio, usize>::from_fn(|r, c| r * 7 + c);
+    assert_eq!(col, row);
+    assert_eq!(
+        row,
+        Stacktrix::<21, 3, 7, RowPrio, usize>::from_values(&(0..21).collect::<Vec<_>>())
+    );
+    for (r, c) in [(0, 0), (2, 6), (1, 3)] {
+        assert_eq!(*ColumnPrioMatrix::get(&col, (r, c)), r * 7 + c);
+    }
+
+    let identity = Stacktrix::<12, 4, 3, ColumnPrio, i32>::identity();
+    assert!(identity.is_diagonal());
+    assert!(identity.diagonal().into_iter().all(|&el| el == 1));
+    assert_eq!(
+        Stacktrix::<9, 3, 3, RowPrio, u8>::identity(),
+        Permutation::<3>::identity().to_matrix::<9, ColumnPrio, u8>()
+    );
+    assert!(Stacktrix::<12, 4, 3, RowPrio, f64>::zeros()
+        .diagonal()
+        .into_iter()
+        .all(|&el| el == 0.0));
+}
+
+#[test]
+fn gather_into_both_layouts() {
+    let data = values(17, 20);
+    let col = Stacktrix::<20, 4, 5, ColumnPrio, u32>::from_values(&data);
+    let row = Stacktrix::<20, 4, 5, RowPrio, u32>::from_values(&data);
+    let (row_map, col_map) = ([3, 3, 0], [4, 1, 1, 0, 2, 3]);
+    let mut from_col = Stacktrix::<18, 3, 6, RowPrio, u32>::zeros();
+    let mut from_row = Stacktrix::<18, 3, 6, ColumnPrio, u32>::zeros();
+    col.gather_into(&mut from_col, &row_map, &col_map);
+    row.gather_into(&mut from_row, &row_map, &col_map);
+    for (i, &r) in row_map.iter().enumerate() {
+        for (j, &c) in col_map.iter().enumerate() {
+            let location = (r, c);
+            assert_eq!(
+                RowPrioMatrix::get(&from_col, (i, j)),
+                ColumnPrioMatrix::get(&col, location)
+            );
+            assert_eq!(
+                ColumnPrioMatrix::get(&from_row, (i, j)),
+                RowPrioMatrix::get(&row, location)
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn gather_into_out_of_bounds() {
+    let m = Stacktrix::<4, 2, 2, RowPrio, u8>::zeros();
+    let mut dst = Stacktrix::<1, 1, 1, RowPrio, u8>::zeros();
+    m.gather_into(&mut dst, &[0], &[2]);
+}
+
+#[test]
+fn from_rows_and_cols_both_layouts() {
+    let rows = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+    let cols = [[1, 5, 9], [2, 6, 10], [3, 7, 11], [4, 8, 12]];
+    let expected = Stacktrix::<12, 3, 4, RowPrio, i16>::from_fn(|r, c| (r * 4 + c + 1) as i16);
+    assert_eq!(
+        Stacktrix::<12, 3, 4, ColumnPrio, i16>::from_rows(rows),
+        expected
+    );
+    assert_eq!(
+        Stacktrix::<12, 3, 4, RowPrio, i16>::from_rows(rows),
+        expected
+    );
+    assert_eq!(
+        Stacktrix::<12, 3, 4, ColumnPrio, i16>::from_cols(cols),
+        expected
+    );
+    assert_eq!(
+        Stacktrix::<12, 3, 4, RowPrio, i16>::from_cols(cols),
+        expected
+    );
+}
+
+#[test]
+fn export_both_layouts() {
+    let rows = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+    let cols = [[1, 5, 9], [2, 6, 10], [3, 7, 11], [4, 8, 12]];
+    let col = Stacktrix::<12, 3, 4, ColumnPrio, i16>::from_rows(rows);
+    let row = Stacktrix::<12, 3, 4, RowPrio, i16>::from_rows(rows);
+    assert_eq!(col.to_rows(), rows);
+    assert_eq!(row.to_rows(), rows);
+    assert_eq!(col.to_cols(), cols);
+    assert_eq!(row.to_cols(), cols);
+    #[cfg(feature = "alloc")]
+    {
+        let flat: Vec<i16> = (1..=12).collect();
+        assert_eq!(col.to_row_major_vec(), flat);
+        assert_eq!(row.to_row_major_vec(), flat);
+    }
+}
+
+#[test]
+fn raw_storage_accessors() {
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    col.as_mut_slice()[1] = 9;
+    assert_eq!(*ColumnPrioMatrix::get(&col, (1, 0)), 9);
+    assert_eq!(col.into_inner(), [1, 9, 2, 5, 3, 6]);
+    let mut data = [1u8, 2, 3, 4, 5, 6];
+    let mut row = Reftrix::<2, 3, RowPrio, u8>::from_values(&mut data[..]);
+    row.as_mut_slice()[1] = 9;
+    assert_eq!(*RowPrioMatrix::get(&row, (0, 1)), 9);
+    assert_eq!(row.as_slice(), &[1, 9, 3, 4, 5, 6]);
+    let inner = row.into_inner();
+    inner[0] = 0;
+    assert_eq!(data, [0, 9, 3, 4, 5, 6]);
+}
+
+#[test]
+fn stacktrix_macro_both_layouts() {
+    let rows = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+    let col = stacktrix![ColumnPrio; [1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+    let row = stacktrix![RowPrio; [1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12],];
+    assert_eq!(col, Stacktrix::<12, 3, 4, ColumnPrio, i32>::from_rows(rows));
+    assert_eq!(row, Stacktrix::<12, 3, 4, RowPrio, i32>::from_rows(rows));
+    let single: Stacktrix<3, 3, 1, ColumnPrio, u8> = stacktrix![ColumnPrio; [1], [2], [3]];
+    assert_eq!(single.as_slice(), &[1, 2, 3]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn par_col_map_both_layouts() {
+    let col = Stacktrix::<96, 3, 32, ColumnPrio, u32>::from_fn(|r, c| (r * 32 + c) as u32);
+    let row = Stacktrix::<96, 3, 32, RowPrio, u32>::from_fn(|r, c| (r * 32 + c) as u32);
+    let expected: Vec<u32> = (0..32).map(|c| 3 * c + 96).collect();
+    assert_eq!(
+        col.par_col_map(|column| column.iter().sum::<u32>()),
+        expected
+    );
+    assert_eq!(
+        row.par_col_map(|column| column.iter().sum::<u32>()),
+        expected
+    );
+    assert_eq!(
+        col.par_col_map(|column| column[2]),
+        row.to_rows()[2].to_vec()
+    );
+}
+
+macro_rules! views {
+    ($name:ident, $r:literal, $c:literal) => {
+        #[test]
+        fn $name() {
+            let value = |r: usize, c: usize| (r * $c + c) as u16;
+            let mut col = Stacktrix::<{ $r * $c }, $r, $c, ColumnPrio, u16>::from_fn(value);
+            let mut row = Stacktrix::<{ $r * $c }, $r, $c, RowPrio, u16>::from_fn(value);
+            for rows in [0..$r, 1..$r, 0..1, $r - 1..$r, 0..0] {
+                for cols in [0..$c, 1..$c, 0..1, $c - 1..$c, 1..1] {
+                    let (cv, rv) = (
+                        col.view(rows.clone(), cols.clone()),
+                        row.view(rows.clone(), cols.clone()),
+                    );
+                    assert_eq!(cv.shape(), (rows.len(), cols.len()));
+                    assert_eq!(rv.shape(), (rows.len(), cols.len()));
+                    for r in 0..rows.len() {
+                        for c in 0..cols.len() {
+                            let expected = value(rows.start + r, cols.start + c);
+                            assert_eq!(cv[(r, c)], expected);
+                            assert_eq!(rv[(r, c)], expected);
+                        }
+                    }
+                    assert_eq!(cv.get((rows.len(), 0)), None);
+                    assert_eq!(rv.get((0, cols.len())), None);
+                    let mut cs: Vec<u16> = cv.iter().copied().collect();
+                    let mut rs: Vec<u16> = rv.iter().copied().collect();
+                    cs.sort();
+                    rs.sort();
+                    assert_eq!(cs.len(), rows.len() * cols.len());
+                    assert_eq!(cs, rs);
+                }
+            }
+            col.view_mut(1.., ..1).fill(99);
+            row.view_mut(1.., ..1).fill(99);
+            let mut inner = col.view_mut(.., 1..);
+            inner
+                .view_mut(..=0, ..)
+                .iter_mut()
+                .for_each(|el| *el += 1000);
+            let mut inner = row.view_mut(.., 1..);
+            inner
+                .view_mut(..=0, ..)
+                .iter_mut()
+                .for_each(|el| *el += 1000);
+            assert_eq!(col, row);
+            let expected =
+                Stacktrix::<{ $r * $c }, $r, $c, RowPrio, u16>::from_fn(|r, c| match (r, c) {
+                    (0, 0) => value(0, 0),
+                    (0, c) => value(0, c) + 1000,
+                    (_, 0) => 99,
+                    (r, c) => value(r, c),
+                });
+            assert_eq!(row, expected);
+        }
+    };
+}
+
+views!(views_3x4, 3, 4);
+views!(views_1x5, 1, 5);
+views!(views_4x1, 4, 1);
+views!(views_1x1, 1, 1);
+
+#[test]
+#[should_panic]
+fn view_out_of_bounds() {
+    let m = Stacktrix::<6, 2, 3, RowPrio, u8>::zeros();
+    m.view(..3, ..);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds of a view")]
+fn view_inclusive_end_at_usize_max() {
+    let m = Stacktrix::<6, 2, 3, RowPrio, u8>::zeros();
+    m.view(..=usize::MAX, ..);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds of a view")]
+fn view_excluded_start_at_usize_max() {
+    let m = Stacktrix::<6, 2, 3, RowPrio, u8>::zeros();
+    m.view(
+        (
+            core::ops::Bound::Excluded(usize::MAX),
+            core::ops::Bound::Unbounded,
+        ),
+        ..,
+    );
+}
+
+#[test]
+fn apply_all_chunked_matches_apply_all() {
+    let value = |r: usize, c: usize| (r * 7 + c) as u32;
+    let mut col = Stacktrix::<35, 5, 7, ColumnPrio, u32>::from_fn(value);
+    let mut row = Stacktrix::<35, 5, 7, RowPrio, u32>::from_fn(value);
+    for (chunk_len, prefetch) in [(1, false), (4, true), (16, true), (35, false), (100, true)] {
+        col.apply_all_chunked(chunk_len, prefetch, |el| *el = el.wrapping_mul(3) + 1);
+        row.apply_all_chunked(chunk_len, prefetch, |el| *el = el.wrapping_mul(3) + 1);
+    }
+    let mut expected = Stacktrix::<35, 5, 7, RowPrio, u32>::from_fn(value);
+    for _ in 0..5 {
+        RowPrioMatrix::apply_all(&mut expected, |el| *el = el.wrapping_mul(3) + 1);
+    }
+    assert_eq!(col, expected);
+    assert_eq!(row, expected);
+}
+
+#[test]
+fn apply_all_captures_environment() {
+    let table: [u8; 4] = [3, 2, 1, 0];
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from_rows([[0, 1, 2], [3, 0, 1]]);
+    let mut row = Stacktrix::<6, 2, 3, RowPrio, u8>::from_rows([[0, 1, 2], [3, 0, 1]]);
+    let mut calls = 0;
+    ColumnPrioMatrix::apply_all(&mut col, |el| {
+        *el = table[*el as usize];
+        calls += 1;
+    });
+    row.apply_all_chunked(4, true, |el| {
+        *el = table[*el as usize];
+        calls += 1;
+    });
+    assert_eq!(calls, 12);
+    assert_eq!(col.to_rows(), [[3, 2, 1], [0, 3, 2]]);
+    assert_eq!(row.to_rows(), col.to_rows());
+}
+
+#[test]
+fn try_apply_all_stops_at_first_error() {
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 2, 3], [4, -5, 6]]);
+    let mut row = Stacktrix::<6, 2, 3, RowPrio, i32>::from_rows([[1, 2, 3], [4, -5, 6]]);
+    let to_unsigned = |el: &mut i32| -> Result<(), i32> {
+        if *el < 0 {
+            return Err(*el);
+        }
+        *el *= 10;
+        Ok(())
+    };
+    assert_eq!(
+        ColumnPrioMatrix::try_apply_all(&mut col, to_unsigned),
+        Err(-5)
+    );
+    assert_eq!(row.try_apply_all(to_unsigned), Err(-5));
+    // Memory order decides which elements were processed before the error.
+    assert_eq!(col.to_rows(), [[10, 20, 3], [40, -5, 6]]);
+    assert_eq!(row.to_rows(), [[10, 20, 30], [40, -5, 6]]);
+    let mut ok = Stacktrix::<4, 2, 2, RowPrio, i32>::with_values([1, 2, 3, 4]);
+    assert_eq!(ok.try_apply_all(to_unsigned), Ok(()));
+    assert_eq!(ok.as_slice(), &[10, 20, 30, 40]);
+}
+
+#[test]
+fn split_both_layouts() {
+    let value = |r: usize, c: usize| (r * 5 + c) as u32;
+    let mut col = Stacktrix::<20, 4, 5, ColumnPrio, u32>::from_fn(value);
+    let mut row = Stacktrix::<20, 4, 5, RowPrio, u32>::from_fn(value);
+    for m in [&mut col.split_at_col(2), &mut row.split_at_col(2)] {
+        let (left, right) = m;
+        assert_eq!(left.shape(), (4, 2));
+        assert_eq!(right.shape(), (4, 3));
+        assert_eq!(right[(3, 0)], value(3, 2));
+        // The halves can be mutated from different threads.
+        std::thread::scope(|scope| {
+            scope.spawn(|| left.iter_mut().for_each(|el| *el += 100));
+            scope.spawn(|| {
+                let (top, mut bottom) = right.view_mut(.., ..).split_at_row(3);
+                assert_eq!(top.shape(), (3, 3));
+                bottom.fill(0);
+            });
+        });
+    }
+    let expected = Stacktrix::<20, 4, 5, RowPrio, u32>::from_fn(|r, c| match (r, c) {
+        (_, 0..=1) => value(r, c) + 100,
+        (3, _) => 0,
+        _ => value(r, c),
+    });
+    assert_eq!(col, expected);
+    assert_eq!(row, expected);
+    let (top, bottom) = row.split_at_row(4);
+    assert_eq!((top.shape(), bottom.shape()), ((4, 5), (0, 5)));
+    assert_eq!(bottom.iter().count(), 0);
+}
+
+#[test]
+fn two_lanes_mutably_both_layouts() {
+    let value = |r: usize, c: usize| (r * 4 + c) as i32;
+    let mut col = Stacktrix::<12, 3, 4, ColumnPrio, i32>::from_fn(value);
+    let mut row = Stacktrix::<12, 3, 4, RowPrio, i32>::from_fn(value);
+    // row 2 -= 2 * row 0, then column 3 -= column 1, with the higher index first.
+    let (r2, r0) = ColumnPrioMatrix::get_mut_rows(&mut col, 2, 0);
+    for (x, y) in r2.into_iter().zip(r0) {
+        *x -= 2 * *y;
+    }
+    let (c3, c1) = ColumnPrioMatrix::get_mut_columns(&mut col, 3, 1);
+    c3.iter_mut().zip(c1.iter()).for_each(|(x, y)| *x -= *y);
+    let (r2, r0) = RowPrioMatrix::get_mut_rows(&mut row, 2, 0);
+    r2.iter_mut().zip(r0.iter()).for_each(|(x, y)| *x -= 2 * *y);
+    let (c3, c1) = RowPrioMatrix::get_mut_columns(&mut row, 3, 1);
+    for (x, y) in c3.into_iter().zip(c1) {
+        *x -= *y;
+    }
+    let expected = Stacktrix::<12, 3, 4, RowPrio, i32>::from_fn(|r, c| {
+        let value = |r, c| {
+            if r == 2 {
+                value(2, c) - 2 * value(0, c)
+            } else {
+                value(r, c)
+            }
+        };
+        if c == 3 {
+            value(r, 3) - value(r, 1)
+        } else {
+            value(r, c)
+        }
+    });
+    assert_eq!(col, expected);
+    assert_eq!(row, expected);
+}
+
+#[test]
+#[should_panic]
+fn two_lanes_mutably_same_index() {
+    let mut m = Stacktrix::<4, 2, 2, RowPrio, u8>::with_values([0; 4]);
+    let _ = m.get_mut_rows(1, 1);
+}
+
+#[test]
+fn reshape_and_resize_both_layouts() {
+    let value = |r: usize, c: usize| (r * 3 + c) as u8;
+    let col = Stacktrix::<12, 4, 3, ColumnPrio, u8>::from_fn(value);
+    let row = Stacktrix::<12, 4, 3, RowPrio, u8>::from_fn(value);
+    let expected = |r, c| if r < 4 && c < 3 { value(r, c) } else { 9 };
+    let grown = Stacktrix::<20, 5, 4, RowPrio, u8>::from_fn(expected);
+    let shrunk = Stacktrix::<4, 2, 2, RowPrio, u8>::from_fn(value);
+    assert_eq!(col.resize::<20, 5, 4>(9), grown);
+    assert_eq!(row.resize::<20, 5, 4>(9), grown);
+    assert_eq!(col.resize::<4, 2, 2>(9), shrunk);
+    assert_eq!(row.resize::<4, 2, 2>(9), shrunk);
+    // Reshaping keeps the memory order, which differs between the layouts.
+    let col = col.reshape::<2, 6>();
+    let row = row.reshape::<2, 6>();
+    assert_eq!(col.as_slice(), &[0, 3, 6, 9, 1, 4, 7, 10, 2, 5, 8, 11]);
+    assert_eq!(*col.get((1, 0)), 3);
+    assert_eq!(RowPrioMatrix::get_row(&row, 1), &[6, 7, 8, 9, 10, 11]);
+    let mut data = row.into_inner();
+    let m = Reftrix::<2, 6, RowPrio, u8>::from_values(&mut data[..]).reshape::<3, 4>();
+    assert_eq!(RowPrioMatrix::get_row(&m, 2), &[8, 9, 10, 11]);
+}
+
+#[test]
+#[should_panic]
+fn reshape_reftrix_wrong_size() {
+    let mut data = [0u8; 6];
+    let _ = Reftrix::<2, 3, RowPrio, u8>::from_values(&mut data[..]).reshape::<2, 2>();
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn patch_both_layouts() {
+    let value = |r: usize, c: usize| (r * 4 + c) as u32;
+    let old_col = Stacktrix::<12, 3, 4, ColumnPrio, u32>::from_fn(value);
+    let old_row = Stacktrix::<12, 3, 4, RowPrio, u32>::from_fn(value);
+    let changed = |r: usize, c: usize| if (r, c) == (2, 1) { 0 } else { value(r, c) };
+    let new_col = Stacktrix::<12, 3, 4, ColumnPrio, u32>::from_fn(changed);
+    let new_row = Stacktrix::<12, 3, 4, RowPrio, u32>::from_fn(changed);
+    let expected = MatrixPatch::Cells {
+        rows: 3,
+        cols: 4,
+        cells: vec![((2, 1), 0)],
+    };
+    assert_eq!(new_col.diff_patch(&old_row), expected);
+    assert_eq!(new_row.diff_patch(&old_col), expected);
+    assert!(new_row.diff_patch(&new_col).is_empty());
+    let (mut col, mut row) = (old_col, old_row);
+    col.apply_patch(&expected).unwrap();
+    row.apply_patch(&expected).unwrap();
+    assert_eq!(col, new_row);
+    assert_eq!(row, new_row);
+
+    // Changing every cell falls back to a full copy in row major order.
+    let full = Stacktrix::<12, 3, 4, ColumnPrio, u32>::from_fn(|r, c| value(r, c) + 1);
+    let patch = full.diff_patch(&new_row);
+    assert_eq!(
+        patch,
+        MatrixPatch::Full {
+            rows: 3,
+            cols: 4,
+            data: (1..13).collect(),
+        }
+    );
+    let mut row = Stacktrix::<12, 3, 4, RowPrio, u32>::zeros();
+    row.apply_patch(&patch).unwrap();
+    assert_eq!(row, full);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn patch_errors() {
+    let mut m = Stacktrix::<4, 2, 2, RowPrio, u8>::zeros();
+    let wrong_shape =
+        Stacktrix::<4, 1, 4, RowPrio, u8>::zeros().diff_patch(
+            &Stacktrix::<4, 1, 4, RowPrio, u8>::with_values([1, 0, 0, 0]),
+        );
+    assert_eq!(
+        m.apply_patch(&wrong_shape),
+        Err(MatrixError::InvalidShape {
+            size: 4,
+            rows: 1,
+            cols: 4
+        })
+    );
+    let out_of_bounds = MatrixPatch::Cells {
+        rows: 2,
+        cols: 2,
+        cells: vec![((0, 0), 1), ((0, 2), 1)],
+    };
+    assert_eq!(
+        m.apply_patch(&out_of_bounds),
+        Err(MatrixError::OutOfBounds {
+            row: 0,
+            col: 2,
+            rows: 2,
+            cols: 2
+        })
+    );
+    assert_eq!(m, Stacktrix::<4, 2, 2, RowPrio, u8>::zeros());
+}
+
+#[test]
+fn ping_pong_both_layouts() {
+    // One step of a 3x3 box sum with zero padding, expected after two steps from a single one.
+    fn box_sum<M>(src: &M, dst: &mut M)
+    where
+        M: Export<3, 3, u32> + View<3, 3, u32> + StorageMut<3, 3, u32>,
+    {
+        let rows = src.to_rows();
+        let mut dst = dst.view_mut(.., ..);
+        for r in 0..3 {
+            for c in 0..3 {
+                dst[(r, c)] = (r.saturating_sub(1)..(r + 2).min(3))
+                    .flat_map(|r| (c.saturating_sub(1)..(c + 2).min(3)).map(move |c| (r, c)))
+                    .map(|(r, c)| rows[r][c])
+                    .sum();
+            }
+        }
+    }
+    let start = |r: usize, c: usize| ((r, c) == (0, 0)) as u32;
+    let mut col = PingPong::new(
+        Stacktrix::<9, 3, 3, ColumnPrio, u32>::from_fn(start),
+        Stacktrix::zeros(),
+    );
+    let mut row = PingPong::new(
+        Stacktrix::<9, 3, 3, RowPrio, u32>::from_fn(start),
+        Stacktrix::zeros(),
+    );
+    for _ in 0..2 {
+        col.step(box_sum);
+        row.step(box_sum);
+    }
+    let expected = Stacktrix::<9, 3, 3, RowPrio, u32>::from_rows([[4, 4, 2], [4, 4, 2], [2, 2, 1]]);
+    assert_eq!(*col.current(), expected);
+    assert_eq!(*row.current(), expected);
+    let (current, previous) = row.into_inner();
+    assert_eq!(current, expected);
+    assert_eq!(
+        previous,
+        Stacktrix::<9, 3, 3, RowPrio, u32>::from_rows([[1, 1, 0], [1, 1, 0], [0, 0, 0]])
+    );
+}
+
+#[test]
+fn iter_mut_indexed_both_layouts() {
+    let mut col = Stacktrix::<12, 3, 4, ColumnPrio, usize>::zeros();
+    let mut row = Stacktrix::<12, 3, 4, RowPrio, usize>::zeros();
+    let mut data = [0; 12];
+    let mut reftrix = Reftrix::<3, 4, ColumnPrio, usize>::from_values(&mut data[..]);
+    for iter in [
+        col.iter_mut_indexed(),
+        row.iter_mut_indexed(),
+        reftrix.iter_mut_indexed(),
+    ] {
+        assert_eq!(iter.len(), 12);
+        for (i, ((r, c), el)) in iter.enumerate() {
+            *el = r * 10 + c + i * 100;
+        }
+    }
+    let expected = Stacktrix::<12, 3, 4, RowPrio, usize>::from_fn(|r, c| r * 410 + c * 101);
+    assert_eq!(col, expected);
+    assert_eq!(row, expected);
+    assert_eq!(reftrix, expected);
+}
+
+#[derive(Clone, Copy, Default)]
+struct Entity {
+    id: u8,
+    position: [f32; 2],
+    alive: bool,
+}
+
+#[test]
+fn field_matrix_both_layouts() {
+    let mut entities = [Entity::default(); 6];
+    for (i, entity) in entities.iter_mut().enumerate() {
+        entity.id = i as u8;
+    }
+    let col =
+        FieldMatrix::<2, 3, ColumnPrio, u8>::from_struct_slice_field(&mut entities, |e| &mut e.id);
+    assert!(col
+        .iter_indexed()
+        .all(|((r, c), &id)| id as usize == c * 2 + r));
+    let mut row = FieldMatrix::<2, 3, RowPrio, bool>::from_struct_slice_field(&mut entities, |e| {
+        &mut e.alive
+    });
+    assert_eq!(row.iter_mut_indexed().len(), 6);
+    for ((r, c), alive) in row.iter_mut_indexed() {
+        *alive = r == 1 || c == 0;
+    }
+    *row.get_mut((1, 2)).unwrap() = false;
+    assert!(row.get((2, 0)).is_none());
+    let mut positions =
+        FieldMatrix::<3, 2, RowPrio, [f32; 2]>::from_struct_slice_field(&mut entities, |e| {
+            &mut e.position
+        });
+    positions[(2, 1)] = [1.0, 2.0];
+    let alive: Vec<_> = entities.iter().map(|e| e.alive).collect();
+    assert_eq!(alive, [true, false, false, true, true, false]);
+    assert_eq!(entities[5].position, [1.0, 2.0]);
+    assert!(entities.iter().enumerate().all(|(i, e)| e.id as usize == i));
+}
+
+#[test]
+#[should_panic(expected = "the same field of every struct")]
+fn field_matrix_inconsistent_projector() {
+    let mut pairs = [(0u8, 0u8); 2];
+    let mut first = true;
+    let _ = FieldMatrix::<1, 2, RowPrio, u8>::from_struct_slice_field(&mut pairs, |p| {
+        first = !first;
+        if first {
+            &mut p.0
+        } else {
+            &mut p.1
+        }
+    });
+}
+
+#[test]
+#[should_panic(expected = "a field of the struct")]
+fn field_matrix_projector_outside_of_struct() {
+    let mut entities = [Entity::default(); 2];
+    let _ = FieldMatrix::<2, 1, RowPrio, u8>::from_struct_slice_field(&mut entities, |_| {
+        Box::leak(Box::new(0))
+    });
+}
+
+#[test]
+fn slice_both_layouts() {
+    let value = |r: usize, c: usize| (r * 5 + c) as u32;
+    let mut col = Stacktrix::<20, 4, 5, ColumnPrio, u32>::from_fn(value);
+    let mut row = Stacktrix::<20, 4, 5, RowPrio, u32>::from_fn(value);
+    for (info, rows, cols) in [
+        (s![.., ..], 0..4, 0..5),
+        (s![1..3, 2..], 1..3, 2..5),
+        (s![..=2, ..2], 0..3, 0..2),
+        (s![3, ..], 3..4, 0..5),
+        (s![.., 4], 0..4, 4..5),
+        (s![2, 1..1], 2..3, 1..1),
+        ((1.., ..=0).into(), 1..4, 0..1),
+    ] {
+        for v in [col.slice(info), row.slice(info)] {
+            assert_eq!(v.shape(), (rows.len(), cols.len()));
+            for r in rows.clone() {
+                for c in cols.clone() {
+                    assert_eq!(v[(r - rows.start, c - cols.start)], value(r, c));
+                }
+            }
+        }
+    }
+    col.slice_mut(s![1, 1..4]).fill(0);
+    row.slice_mut((1, 1..4)).fill(0);
+    assert_eq!(col, row);
+    assert_eq!(RowPrioMatrix::get_row(&row, 1), &[5, 0, 0, 0, 9]);
+}
+
+#[test]
+#[should_panic]
+fn slice_out_of_bounds() {
+    let m = Stacktrix::<4, 2, 2, RowPrio, u8>::zeros();
+    let _ = m.slice(s![2, ..]);
+}
+
+#[test]
+fn into_iterator_both_layouts() {
+    let value = |r: usize, c: usize| (r * 3 + c) as u16;
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, u16>::from_fn(value);
+    let mut row = Stacktrix::<6, 2, 3, RowPrio, u16>::from_fn(value);
+    let logical: Vec<u16> = (0..6).collect();
+    assert_eq!((&col).into_iter().copied().collect::<Vec<_>>(), logical);
+    assert_eq!((&row).into_iter().copied().collect::<Vec<_>>(), logical);
+    for el in &mut col {
+        *el *= 2;
+    }
+    row.iter_mut().for_each(|el| *el *= 2);
+    assert_eq!(col, row);
+
+    let mut data = col.into_inner();
+    let reftrix = Reftrix::<2, 3, ColumnPrio, u16>::from_values(&mut data[..]);
+    assert_eq!(reftrix.iter().len(), 6);
+    assert!((&reftrix).into_iter().eq(row.iter()));
+    for (i, el) in reftrix.into_iter().enumerate() {
+        *el = i as u16;
+    }
+    assert_eq!(data, [0, 3, 1, 4, 2, 5]);
+    let doubled: Vec<u16> = logical.iter().map(|v| v * 2).collect();
+    assert_eq!(row.into_iter().collect::<Vec<_>>(), doubled);
+    let col = Stacktrix::<6, 2, 3, ColumnPrio, u16>::with_values(data);
+    assert_eq!(col.into_iter().collect::<Vec<_>>(), logical);
+}
+
+#[test]
+fn semiring_products_both_layouts() {
+    let a = |r: usize, c: usize| (r * 3 + c) as i64 - 4;
+    let b = |r: usize, c: usize| (r as i64 - 2 * c as i64) * 3;
+    let expected =
+        Stacktrix::<8, 2, 4, RowPrio, i64>::from_fn(|i, j| (0..3).map(|k| a(i, k) * b(k, j)).sum());
+    let a_col = Stacktrix::<6, 2, 3, ColumnPrio, i64>::from_fn(a);
+    let a_row = Stacktrix::<6, 2, 3, RowPrio, i64>::from_fn(a);
+    let b_col = Stacktrix::<12, 3, 4, ColumnPrio, i64>::from_fn(b);
+    let b_row = Stacktrix::<12, 3, 4, RowPrio, i64>::from_fn(b);
+    let mut dst_col = Stacktrix::<8, 2, 4, ColumnPrio, i64>::zeros();
+    let mut dst_row = Stacktrix::<8, 2, 4, RowPrio, i64>::zeros();
+    a_col.semiring_mul_into(&b_row, &mut dst_row, Arithmetic);
+    a_row.semiring_mul_into(&b_col, &mut dst_col, Arithmetic);
+    assert_eq!(dst_col, expected);
+    assert_eq!(dst_row, expected);
+    let v = [1, -1, 2];
+    let product: [i64; 2] = core::array::from_fn(|i| (0..3).map(|k| a(i, k) * v[k]).sum());
+    assert_eq!(a_col.semiring_mul_vec(&v, Arithmetic), product);
+    assert_eq!(a_row.semiring_mul_vec(&v, Arithmetic), product);
+    assert_eq!(a_col.semiring_sum_rows(Arithmetic), [-9, 0]);
+    assert_eq!(a_row.semiring_sum_cols(Arithmetic), [-5, -3, -1]);
+    assert_eq!(b_col.semiring_sum_rows(MinPlus), [-18, -15, -12]);
+
+    // Two steps over the edges of a cycle 0 -> 1 -> 2 -> 0 reach the node after the next.
+    let edges = |r: usize, c: usize| c == (r + 1) % 3;
+    let col = Stacktrix::<9, 3, 3, ColumnPrio, bool>::from_fn(edges);
+    let row = Stacktrix::<9, 3, 3, RowPrio, bool>::from_fn(edges);
+    let mut two = Stacktrix::<9, 3, 3, ColumnPrio, bool>::from_fn(|_, _| false);
+    col.semiring_mul_into(&row, &mut two, Boolean);
+    assert_eq!(
+        two,
+        Stacktrix::<9, 3, 3, RowPrio, bool>::from_fn(|r, c| c == (r + 2) % 3)
+    );
+
+    // Saturating tropical products keep missing edges infinite.
+    let inf = u8::INFINITY;
+    let d = Stacktrix::<4, 2, 2, RowPrio, u8>::from_rows([[0, 200], [inf, 0]]);
+    let mut two = Stacktrix::<4, 2, 2, ColumnPrio, u8>::zeros();
+    d.semiring_mul_into(&d, &mut two, MinPlus);
+    assert_eq!(two, d);
+    let i = Stacktrix::<4, 2, 2, RowPrio, i8>::from_rows([[-100, i8::INFINITY], [-100, -100]]);
+    let mut two = Stacktrix::<4, 2, 2, RowPrio, i8>::with_values([0; 4]);
+    i.semiring_mul_into(&i, &mut two, MinPlus);
+    assert_eq!(two.as_slice(), &[-128, i8::INFINITY, -128, -128]);
+}
+
+#[test]
+fn shortest_paths_both_layouts() {
+    const INF: i32 = i32::MAX;
+    // A cycle 0 -> 1 -> 2 -> 3 -> 0 with a negative shortcut 0 -> 2 and node 4 unreachable.
+    let weight = |i: usize, j: usize| match (i, j) {
+        _ if i == j => 0,
+        (0, 1) => 5,
+        (1, 2) => 2,
+        (2, 3) => 1,
+        (3, 0) => 4,
+        (0, 2) => -1,
+        (4, 0) => 1,
+        _ => INF,
+    };
+    let mut col = Stacktrix::<25, 5, 5, ColumnPrio, i32>::from_fn(weight);
+    let mut row = Stacktrix::<25, 5, 5, RowPrio, i32>::from_fn(weight);
+    col.all_pairs_shortest_paths().unwrap();
+    row.all_pairs_shortest_paths().unwrap();
+    let expected = Stacktrix::<25, 5, 5, RowPrio, i32>::from_rows([
+        [0, 5, -1, 0, INF],
+        [7, 0, 2, 3, INF],
+        [5, 10, 0, 1, INF],
+        [4, 9, 3, 0, INF],
+        [1, 6, 0, 1, 0],
+    ]);
+    assert_eq!(col, expected);
+    assert_eq!(row, expected);
+
+    let mut reach =
+        Stacktrix::<25, 5, 5, ColumnPrio, bool>::from_fn(|i, j| i != j && weight(i, j) != INF);
+    reach.transitive_closure().unwrap();
+    assert_eq!(
+        reach,
+        Stacktrix::<25, 5, 5, RowPrio, bool>::from_fn(|_, j| j != 4)
+    );
+    let mut data = [0.0f32; 6];
+    let mut wide = Reftrix::<2, 3, RowPrio, f32>::from_values(&mut data[..]);
+    assert_eq!(
+        wide.all_pairs_shortest_paths(),
+        Err(MatrixError::NotSquare { rows: 2, cols: 3 })
+    );
+}
+
+#[test]
+fn zip_across_backing_types() {
+    let mut data = [1u32, 2, 3, 4, 5, 6];
+    let mut col = Reftrix::<2, 3, ColumnPrio, u32>::from_values(&mut data[..]);
+    let row = Stacktrix::<6, 2, 3, RowPrio, u32>::from_fn(|r, c| (r * 3 + c) as u32);
+    let mut visited = Vec::new();
+    col.zip_apply(&row, |el, &key| {
+        visited.push(key);
+        *el ^= key;
+    });
+    assert_eq!(visited, vec![0, 1, 2, 3, 4, 5]);
+    assert_eq!(col.to_rows(), [[1, 2, 7], [1, 0, 3]]);
+
+    let mut out = Stacktrix::<6, 2, 3, ColumnPrio, bool>::with_values([false; 6]);
+    row.zip_map(&col, &mut out, |&a, &b| a < b);
+    assert_eq!(out.to_rows(), [[true, true, true], [false, false, false]]);
+}
+
+#[test]
+fn min_cost_assignment_matches_brute_force() {
+    let cost = |r: usize, c: usize| ((r * 7 + c * 13) * (c + 3) % 17) as u32;
+    let col = Stacktrix::<25, 5, 5, ColumnPrio, u32>::from_fn(cost);
+    let row = Stacktrix::<25, 5, 5, RowPrio, u32>::from_fn(cost);
+    let total = |assignment: &[usize]| -> u32 {
+        assignment
+            .iter()
+            .enumerate()
+            .map(|(r, &c)| cost(r, c))
+            .sum()
+    };
+    let mut best = u32::MAX;
+    let mut perm = [0, 1, 2, 3, 4];
+    // Heap's algorithm over all permutations of the columns.
+    let mut counters = [0; 5];
+    best = best.min(total(&perm));
+    let mut i = 0;
+    while i < 5 {
+        if counters[i] < i {
+            perm.swap(if i % 2 == 0 { 0 } else { counters[i] }, i);
+            best = best.min(total(&perm));
+            counters[i] += 1;
+            i = 0;
+        } else {
+            counters[i] = 0;
+            i += 1;
+        }
+    }
+    for assignment in [
+        col.min_cost_assignment().unwrap(),
+        row.min_cost_assignment().unwrap(),
+    ] {
+        let mut seen = [false; 5];
+        assignment.iter().for_each(|&c| seen[c] = true);
+        assert!(seen.iter().all(|&s| s));
+        assert_eq!(total(&assignment), best);
+    }
+
+    let wide = Stacktrix::<8, 2, 4, ColumnPrio, f64>::from_rows([
+        [9.0, 2.0, 7.0, 8.0],
+        [6.0, 4.0, 3.0, 7.0],
+    ]);
+    assert_eq!(wide.min_cost_assignment(), Ok([1, 2]));
+    let tall = Stacktrix::<8, 4, 2, RowPrio, f64>::zeros();
+    assert_eq!(
+        tall.min_cost_assignment(),
+        Err(MatrixError::NotSquare { rows: 4, cols: 2 })
+    );
+}
+
+#[test]
+fn max_flow_both_layouts() {
+    // The network of CLRS figure 26.1, the maximum flow is 23.
+    let capacities = [
+        [0, 16, 13, 0, 0, 0],
+        [0, 0, 10, 12, 0, 0],
+        [0, 4, 0, 0, 14, 0],
+        [0, 0, 9, 0, 0, 20],
+        [0, 0, 0, 7, 0, 4],
+        [0, 0, 0, 0, 0, 0],
+    ];
+    let mut col = Stacktrix::<36, 6, 6, ColumnPrio, i64>::from_rows(capacities);
+    let mut row = Stacktrix::<36, 6, 6, RowPrio, i64>::from_rows(capacities);
+    assert_eq!(col.max_flow(0, 5), Ok(23));
+    assert_eq!(row.max_flow(0, 5), Ok(23));
+    assert_eq!(col.to_rows(), row.to_rows());
+    let residual = row.to_rows();
+    // Flow is conserved at every inner node.
+    for node in 1..5 {
+        let flow = |from: usize, to: usize| (capacities[from][to] - residual[from][to]).max(0);
+        let incoming: i64 = (0..6).map(|from| flow(from, node)).sum();
+        let outgoing: i64 = (0..6).map(|to| flow(node, to)).sum();
+        assert_eq!(incoming, outgoing);
+    }
+    // The nodes reachable from the source in the residual network form a minimum cut.
+    let mut reachable = [false; 6];
+    reachable[0] = true;
+    for _ in 0..6 {
+        for from in 0..6 {
+            for to in 0..6 {
+                if reachable[from] && residual[from][to] > 0 {
+                    reachable[to] = true;
+                }
+            }
+        }
+    }
+    let cut: i64 = (0..6)
+        .flat_map(|from| (0..6).map(move |to| (from, to)))
+        .filter(|&(from, to)| reachable[from] && !reachable[to])
+        .map(|(from, to)| capacities[from][to])
+        .sum();
+    assert_eq!(cut, 23);
+
+    assert_eq!(row.max_flow(2, 2), Ok(0));
+    assert_eq!(
+        row.max_flow(0, 6),
+        Err(MatrixError::RowOutOfBounds { row: 6, rows: 6 })
+    );
+    let mut wide = Stacktrix::<6, 2, 3, RowPrio, u8>::zeros();
+    assert_eq!(
+        wide.max_flow(0, 1),
+        Err(MatrixError::NotSquare { rows: 2, cols: 3 })
+    );
+}
+
+#[test]
+fn stationary_distribution_of_periodic_chain() {
+    // State 0 alternates with one of 1 and 2, plain power iteration would oscillate.
+    let p = [[0.0, 0.5, 0.5], [1.0, 0.0, 0.0], [1.0, 0.0, 0.0]];
+    let col = Stacktrix::<9, 3, 3, ColumnPrio, f64>::from_rows(p);
+    let row = Stacktrix::<9, 3, 3, RowPrio, f64>::from_rows(p);
+    let (a, b) = (
+        col.stationary_distribution(1e-12, 500).unwrap(),
+        row.stationary_distribution(1e-12, 500).unwrap(),
+    );
+    assert_eq!(a, b);
+    assert!(a.converged && a.change <= 1e-12);
+    for (got, expected) in a.vector.iter().zip([0.5, 0.25, 0.25]) {
+        assert!((got - expected).abs() < 1e-9);
+    }
+
+    let slow = Stacktrix::<4, 2, 2, ColumnPrio, f64>::from_rows([[0.99, 0.01], [0.02, 0.98]]);
+    let stopped = slow.stationary_distribution(1e-12, 3).unwrap();
+    assert_eq!(stopped.iterations, 3);
+    assert!(!stopped.converged);
+    let untouched = row.stationary_distribution(1e-12, 0).unwrap();
+    assert_eq!(untouched.vector, [1.0 / 3.0; 3]);
+    assert_eq!(
+        Stacktrix::<6, 2, 3, RowPrio, f32>::zeros().stationary_distribution(0.0, 10),
+        Err(MatrixError::NotSquare { rows: 2, cols: 3 })
+    );
+}
+
+#[test]
+fn apply_row_and_col_both_layouts() {
+    let value = |r: usize, c: usize| (r * 4 + c) as u32;
+    let mut col = Stacktrix::<12, 3, 4, ColumnPrio, u32>::from_fn(value);
+    let mut row = Stacktrix::<12, 3, 4, RowPrio, u32>::from_fn(value);
+    let mut seen = Vec::new();
+    col.apply_row(2, |el| seen.push(*el));
+    row.apply_row(2, |el| seen.push(*el));
+    col.apply_col(1, |el| seen.push(*el));
+    row.apply_col(1, |el| seen.push(*el));
+    assert_eq!(seen, [8, 9, 10, 11, 8, 9, 10, 11, 1, 5, 9, 1, 5, 9]);
+
+    col.apply_row(0, |el| *el *= 100);
+    row.apply_row(0, |el| *el *= 100);
+    col.apply_col(3, |el| *el += 1);
+    row.apply_col(3, |el| *el += 1);
+    let expected = [[0, 100, 200, 301], [4, 5, 6, 8], [8, 9, 10, 12]];
+    assert_eq!(col.to_rows(), expected);
+    assert_eq!(row.to_rows(), expected);
+}
+
+#[test]
+fn dominant_eigenpair_with_negative_eigenvalue() {
+    let a = [[-4.0, 1.0, 0.0], [2.0, 1.0, 0.5], [0.0, 0.5, 2.0]];
+    let col = Stacktrix::<9, 3, 3, ColumnPrio, f64>::from_rows(a);
+    let row = Stacktrix::<9, 3, 3, RowPrio, f64>::from_rows(a);
+    let (value, pair) = col.dominant_eigenpair(1e-13, 1000).unwrap();
+    assert_eq!(row.dominant_eigenpair(1e-13, 1000), Ok((value, pair)));
+    assert!(pair.converged);
+    assert!(value < -4.0);
+    // A * v = value * v and the element of the largest magnitude is 1.
+    let av = row.semiring_mul_vec(&pair.vector, Arithmetic);
+    for (x, y) in pair.vector.iter().zip(av) {
+        assert!((value * x - y).abs() < 1e-9);
+    }
+    assert_eq!(pair.vector[0], 1.0);
+
+    let nilpotent = Stacktrix::<4, 2, 2, RowPrio, f64>::from_rows([[1.0, -1.0], [1.0, -1.0]]);
+    let (value, pair) = nilpotent.dominant_eigenpair(1e-12, 10).unwrap();
+    assert_eq!((value, pair.iterations, pair.converged), (0.0, 1, true));
+    assert_eq!(
+        Stacktrix::<6, 3, 2, ColumnPrio, f64>::zeros().dominant_eigenpair(1e-12, 10),
+        Err(MatrixError::NotSquare { rows: 3, cols: 2 })
+    );
+}
+
+#[test]
+fn reductions_both_layouts() {
+    let value = |r: usize, c: usize| (r as f64 - 1.5) * (c as f64 + 0.25);
+    let col = Stacktrix::<12, 3, 4, ColumnPrio, f64>::from_fn(value);
+    let row = Stacktrix::<12, 3, 4, RowPrio, f64>::from_fn(value);
+    assert_eq!(col.sum(), row.sum());
+    assert_eq!(col.min(), Some(-4.875));
+    assert_eq!(row.min(), Some(-4.875));
+    assert_eq!(col.max(), Some(1.625));
+    assert_eq!(row.max(), Some(1.625));
+    assert_eq!(col.row_sums(), row.row_sums());
+    assert_eq!(col.col_sums(), row.col_sums());
+    assert_eq!(row.col_sums(), [-0.375, -1.875, -3.375, -4.875]);
+    let visited = col.fold(Vec::new(), |mut seen, &el| {
+        seen.push(el);
+        seen
+    });
+    assert_eq!(visited, row.iter().copied().collect::<Vec<_>>());
+
+    let nan = Stacktrix::<4, 2, 2, RowPrio, f32>::with_values([f32::NAN, 2.0, -1.0, f32::NAN]);
+    assert_eq!(nan.min(), Some(-1.0));
+    assert_eq!(nan.max(), Some(2.0));
+    assert_eq!(
+        Stacktrix::<1, 1, 1, RowPrio, f32>::with_values([f32::NAN]).max(),
+        None
+    );
+}
+
+#[test]
+fn pseudo_random_is_reproducible() {
+    // The first outputs of the reference SplitMix64 generator seeded with 0.
+    let bits = Stacktrix::<4, 2, 2, RowPrio, u64>::pseudo_random(0);
+    assert_eq!(
+        bits.as_slice(),
+        &[
+            0xe220a8397b1dcdaf,
+            0x6e789e6aa1b965f4,
+            0x06c45d188009454f,
+            0xf88bb8a8724c81ec
+        ]
+    );
+    assert_eq!(
+        Stacktrix::<4, 2, 2, ColumnPrio, u64>::pseudo_random_bits(0).as_slice(),
+        bits.as_slice()
+    );
+
+    let col = Stacktrix::<35, 5, 7, ColumnPrio, i16>::pseudo_random(99);
+    let row = Stacktrix::<35, 5, 7, RowPrio, i16>::pseudo_random(99);
+    assert_eq!(col, row);
+    let mut data = [0i16; 35];
+    let mut reftrix = Reftrix::<5, 7, ColumnPrio, i16>::from_values(&mut data[..]);
+    reftrix.fill_pseudo_random(99);
+    assert_eq!(reftrix, row);
+
+    let coins = Stacktrix::<64, 8, 8, RowPrio, bool>::pseudo_random(3);
+    let heads = coins.as_slice().iter().filter(|&&c| c).count();
+    assert!((16..48).contains(&heads));
+    let unit = Stacktrix::<64, 8, 8, ColumnPrio, f32>::pseudo_random(3);
+    assert!(unit.as_slice().iter().all(|&el| (0.0..1.0).contains(&el)));
+}
+
+#[test]
+fn search_both_layouts() {
+    let value = |r: usize, c: usize| ((r * 5 + c * 3) % 7) as u8;
+    let col = Stacktrix::<20, 4, 5, ColumnPrio, u8>::from_fn(value);
+    let row = Stacktrix::<20, 4, 5, RowPrio, u8>::from_fn(value);
+    // Row 0 is [0, 3, 6, 2, 5], the first 6 and the first 0 win the ties.
+    assert_eq!(col.argmax(), Some((0, 2)));
+    assert_eq!(row.argmax(), Some((0, 2)));
+    assert_eq!(col.argmin(), Some((0, 0)));
+    assert_eq!(row.argmin(), Some((0, 0)));
+    assert_eq!(col.position(|&el| el == 4), Some((1, 2)));
+    assert_eq!(row.position(|&el| el == 4), Some((1, 2)));
+    assert!(col.contains(&6) && !row.contains(&7));
+
+    let nan = Stacktrix::<4, 2, 2, ColumnPrio, f32>::with_values([f32::NAN; 4]);
+    assert_eq!(nan.argmax(), None);
+    assert_eq!(nan.argmin(), None);
+    assert!(!nan.contains(&f32::NAN));
+}
+
+#[test]
+fn fill_fill_with_and_clear_both_layouts() {
+    let mut data = [7u16; 6];
+    let mut col = Reftrix::<2, 3, ColumnPrio, u16>::from_values(&mut data[..]);
+    let mut row = Stacktrix::<6, 2, 3, RowPrio, u16>::zeros();
+    row.fill(7);
+    assert_eq!(col, row);
+    let mut counter = 0;
+    let mut count = || {
+        counter += 1;
+        counter
+    };
+    col.fill_with(&mut count);
+    row.fill_with(&mut count);
+    // Both walk memory order.
+    assert_eq!(col.to_rows(), [[1, 3, 5], [2, 4, 6]]);
+    assert_eq!(row.to_rows(), [[7, 8, 9], [10, 11, 12]]);
+    col.clear();
+    row.clear();
+    assert_eq!(data, [0; 6]);
+    assert_eq!(row.as_slice(), &[0; 6]);
+}
+
+#[test]
+fn categorical_both_layouts() {
+    let rows = [[3u8, 10], [1, 20], [3, 30], [2, 40]];
+    let col = Stacktrix::<8, 4, 2, ColumnPrio, u8>::from_rows(rows);
+    let row = Stacktrix::<8, 4, 2, RowPrio, u8>::from_rows(rows);
+    let mut col_encoded = Stacktrix::<8, 4, 2, RowPrio, u8>::zeros();
+    let mut row_encoded = Stacktrix::<8, 4, 2, ColumnPrio, u8>::zeros();
+    col.one_hot_encode_col(0, &[1, 3], &mut col_encoded);
+    row.one_hot_encode_col(0, &[1, 3], &mut row_encoded);
+    assert_eq!(col_encoded.to_rows(), [[0, 1], [1, 0], [0, 1], [0, 0]]);
+    assert_eq!(col_encoded, row_encoded);
+
+    let (mut col, mut row) = (col, row);
+    let lookup = |el: &u8| match el {
+        3 => Some(0),
+        2 => Some(9),
+        _ => None,
+    };
+    assert_eq!(col.map_col_categorical(0, lookup), 3);
+    assert_eq!(row.map_col_categorical(0, lookup), 3);
+    assert_eq!(col.to_rows(), [[0, 10], [1, 20], [0, 30], [9, 40]]);
+    assert_eq!(col, row);
+}
+
+#[test]
+fn row_ring_both_layouts() {
+    let mut data = [0u16; 8];
+    let mut col = RowRing::new(Reftrix::<4, 2, ColumnPrio, u16>::from_values(&mut data[..]));
+    let mut row = RowRing::new(Stacktrix::<8, 4, 2, RowPrio, u16>::zeros());
+    assert!(col.is_empty());
+    assert_eq!(row.rows_in_order().count(), 0);
+    for sample in 0..6u16 {
+        col.push_row_rotating(&[sample, sample * 10]);
+        row.push_row_rotating(&[sample, sample * 10]);
+    }
+    let expected = vec![[2, 20], [3, 30], [4, 40], [5, 50]];
+    assert_eq!(col.rows_in_order().collect::<Vec<_>>(), expected);
+    assert_eq!(row.rows_in_order().collect::<Vec<_>>(), expected);
+    assert!(row.is_full());
+    assert_eq!(row.len(), 4);
+    // Rows 0 and 1 were overwritten by the samples 4 and 5.
+    assert_eq!(row.physical_row(0), Some(2));
+    assert_eq!(row.physical_row(4), None);
+    assert_eq!(row.matrix().get_row(0), &[4, 40]);
+    assert_eq!(
+        col.into_inner().to_rows(),
+        [[4, 40], [5, 50], [2, 20], [3, 30]]
+    );
+}
+
+#[test]
+#[should_panic]
+fn row_ring_wrong_row_len() {
+    let mut ring = RowRing::new(Stacktrix::<8, 4, 2, RowPrio, u16>::zeros());
+    ring.push_row_rotating(&[1, 2, 3]);
+}
+
+#[test]
+fn online_stats_match_two_pass() {
+    let samples: Vec<_> = (0..50)
+        .map(Stacktrix::<6, 2, 3, RowPrio, f64>::pseudo_random)
+        .collect();
+    let mut data = [1.0; 12];
+    let (mean, m2) = data.split_at_mut(6);
+    let mut col = OnlineStats::new(
+        Reftrix::<2, 3, ColumnPrio, f64>::from_values(mean),
+        Reftrix::<2, 3, ColumnPrio, f64>::from_values(m2),
+    );
+    assert_eq!(col.variance(), None);
+    for sample in &samples {
+        col.update(sample);
+    }
+    assert_eq!(col.count(), 50);
+    let means = col.mean().to_rows();
+    let variances = col.variance().unwrap();
+    for (r, (mean_row, variance_row)) in means.iter().zip(&variances).enumerate() {
+        for (c, (got_mean, got_variance)) in mean_row.iter().zip(variance_row).enumerate() {
+            let values: Vec<f64> = samples.iter().map(|s| s.get_row(r)[c]).collect();
+            let mean = values.iter().sum::<f64>() / 50.0;
+            let expected = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / 49.0;
+            assert!((got_mean - mean).abs() < 1e-12);
+            assert!((got_variance - expected).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn ema_update_both_layouts() {
+    let mut row = Stacktrix::<6, 2, 3, RowPrio, f64>::from_rows([[0.0; 3]; 2]);
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, f64>::from_rows([[0.0; 3]; 2]);
+    let sample =
+        Stacktrix::<6, 2, 3, RowPrio, f64>::from_rows([[8.0, 16.0, -8.0], [0.0, 4.0, 2.0]]);
+    for _ in 0..3 {
+        row.ema_update(&sample, 0.5);
+        col.ema_update(&sample, 0.5);
+    }
+    let expected = [[7.0, 14.0, -7.0], [0.0, 3.5, 1.75]];
+    assert_eq!(row.to_rows(), expected);
+    assert_eq!(col.to_rows(), expected);
+}
+
+#[test]
+fn reftrix_views_array_without_copy() {
+    let mut data = [1u16, 2, 3, 4, 5, 6];
+    let mut col: Reftrix<3, 2, ColumnPrio, u16> = (&mut data).into();
+    col.fill_col(1, &[0, 0, 0]);
+    assert_eq!(col.to_rows(), [[1, 0], [2, 0], [3, 0]]);
+    let mut row = Reftrix::<3, 2, RowPrio, u16>::from_array_mut(&mut data);
+    row.fill_row(0, &[9, 9]);
+    assert_eq!(data, [9, 9, 3, 0, 0, 0]);
+}
+
+#[test]
+fn accumulate_saturating_both_layouts() {
+    let frame = Stacktrix::<6, 2, 3, RowPrio, i8>::with_values([100, -100, 1, 0, 27, -28]);
+    let mut row = Stacktrix::<6, 2, 3, RowPrio, i8>::with_values([0; 6]);
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, i8>::with_values([0; 6]);
+    assert_eq!(row.accumulate_saturating(&frame), 0);
+    assert_eq!(col.accumulate_saturating(&frame), 0);
+    assert_eq!(row.accumulate_saturating(&frame), 2);
+    assert_eq!(col.accumulate_saturating(&frame), 2);
+    let expected = [[127, -128, 2], [0, 54, -56]];
+    assert_eq!(row.to_rows(), expected);
+    assert_eq!(col.to_rows(), expected);
+    assert_eq!(row.accumulate_saturating(&frame), 2);
+}
+
+#[test]
+fn conversions_between_reftrix_and_stacktrix() {
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let mut borrowed: Reftrix<2, 3, ColumnPrio, u8> = (&mut col).into();
+    borrowed.fill_col(2, &[0, 0]);
+    let copy = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from(&borrowed);
+    assert_eq!(copy.to_rows(), [[1, 2, 0], [4, 5, 0]]);
+    let mut data = [1, 2, 3, 4, 5, 6];
+    let row = Reftrix::<2, 3, RowPrio, u8>::from_array_mut(&mut data);
+    let owned: Stacktrix<6, 2, 3, RowPrio, u8> = row.to_stacktrix();
+    assert_eq!(owned.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(col.as_reftrix(), copy);
+}
+
+#[test]
+fn histogram_2d_both_layouts() {
+    let pairs = [
+        (0.1, 0.1),
+        (0.9, 2.5),
+        (0.5, 2.9),
+        (1.5, 1.0),
+        (-0.1, 1.0),
+        (0.5, f64::NAN),
+    ];
+    let mut row = Histogram2D::new(
+        Stacktrix::<6, 2, 3, RowPrio, u16>::zeros(),
+        Bins::uniform(0.0, 2.0),
+        Bins::from_edges([0.0, 1.0, 2.0], 3.0),
+    );
+    let mut data = [7u16; 6];
+    let mut col = Histogram2D::new(
+        Reftrix::<2, 3, ColumnPrio, u16>::from_array_mut(&mut data),
+        *row.row_bins(),
+        *row.col_bins(),
+    );
+    for (x, y) in pairs {
+        assert_eq!(row.record(x, y), col.record(x, y));
+    }
+    let expected = [[1, 0, 2], [0, 1, 0]];
+    assert_eq!(row.counts().to_rows(), expected);
+    assert_eq!(col.counts().to_rows(), expected);
+    assert_eq!((col.recorded(), col.outside()), (4, 2));
+    assert_eq!(col.normalized(), [[0.25, 0.0, 0.5], [0.0, 0.25, 0.0]]);
+    assert_eq!(col.to_string(), "1 0 2\n0 1 0\n");
+}
+
+#[test]
+#[should_panic]
+fn histogram_bins_reject_unsorted_edges() {
+    Bins::from_edges([0.0, 2.0, 1.0], 3.0);
+}
+
+#[test]
+fn expressions_fuse_mixed_layouts() {
+    let a = Stacktrix::<6, 2, 3, RowPrio, f64>::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let mut data = [2.0; 6];
+    let b = Reftrix::<2, 3, ColumnPrio, f64>::from_array_mut(&mut data);
+    let expr = -(a.expr() * b.expr()) / 2.0 + 10.0 - 1.0 * a.expr();
+    let mut row = Stacktrix::<6, 2, 3, RowPrio, f64>::zeros();
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, f64>::zeros();
+    expr.eval_into(&mut row);
+    expr.eval_into(&mut col);
+    let expected = [[8.0, 6.0, 4.0], [2.0, 0.0, -2.0]];
+    assert_eq!(row.to_rows(), expected);
+    assert_eq!(col.to_rows(), expected);
+}
+
+#[test]
+fn intermittent_slices_iterate_by_reference() {
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, u32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let mut row = Stacktrix::<6, 2, 3, RowPrio, u32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let strided = col.get_row(1);
+    assert_eq!(strided.iter().sum::<u32>(), 15);
+    assert_eq!((&strided).into_iter().max(), Some(&6));
+    assert_eq!(strided[2], 6);
+    let mut strided = row.get_mut_column(2);
+    for el in &mut strided {
+        *el += 1;
+    }
+    assert_eq!(strided.iter().copied().collect::<Vec<_>>(), vec![4, 7]);
+    let mut strided = col.get_mut_row(0);
+    strided.iter_mut().for_each(|el| *el *= 2);
+    assert_eq!((&strided).into_iter().count(), 3);
+    strided[1] += strided.iter().sum::<u32>();
+    assert_eq!(strided[1], 16);
+    assert_eq!(col.to_rows(), [[2, 16, 6], [4, 5, 6]]);
+    assert_eq!(row.to_rows(), [[1, 2, 4], [4, 5, 7]]);
+}
+
+#[test]
+fn sample_with_index_policies_both_layouts() {
+    use mightrix::policy::{Clamp, Panic, Wrap};
+    let row = Stacktrix::<6, 2, 3, RowPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    for (r, c) in [
+        (-3, -4),
+        (-1, 0),
+        (0, 2),
+        (1, 3),
+        (2, 7),
+        (isize::MIN, isize::MAX),
+    ] {
+        assert_eq!(row.sample::<Clamp>(r, c), col.sample::<Clamp>(r, c));
+        assert_eq!(row.sample::<Wrap>(r, c), col.sample::<Wrap>(r, c));
+    }
+    assert_eq!(*row.sample::<Clamp>(-1, 9), 3);
+    assert_eq!(*row.sample::<Wrap>(-1, -4), 6);
+    assert_eq!(*row.sample::<Panic>(1, 1), 5);
+    *col.sample_mut::<Wrap>(3, 5) = 0;
+    assert_eq!(col.to_rows(), [[1, 2, 3], [4, 5, 0]]);
+}
+
+#[test]
+#[should_panic]
+fn sample_with_panic_policy_rejects_negative() {
+    Stacktrix::<4, 2, 2, RowPrio, u8>::from_rows([[1, 2], [3, 4]])
+        .sample::<mightrix::policy::Panic>(0, -1);
+}
+
+#[test]
+fn convert_prio_into_spans_several_blocks() {
+    let col =
+        Stacktrix::<1050, 35, 30, ColumnPrio, u32>::from_fn(|row, col| (row * 100 + col) as u32);
+    let mut row = Stacktrix::<1050, 35, 30, RowPrio, u32>::zeros();
+    col.convert_prio_into(&mut row);
+    assert_eq!(row, col);
+    let mut back = Stacktrix::<1050, 35, 30, ColumnPrio, u32>::zeros();
+    row.convert_prio_into(&mut back);
+    assert_eq!(back.as_slice(), col.as_slice());
+    col.convert_prio_into(&mut back);
+    assert_eq!(back.as_slice(), col.as_slice());
+}
+
+#[test]
+fn gather_cols_into_all_layout_pairs() {
+    let rows = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+    let src_row = Stacktrix::<12, 3, 4, RowPrio, u16>::from_rows(rows);
+    let src_col = Stacktrix::<12, 3, 4, ColumnPrio, u16>::from_rows(rows);
+    let cols = [2, 2, 0];
+    let expected = [[3, 3, 1], [7, 7, 5], [11, 11, 9]];
+    let mut dst_row = Stacktrix::<9, 3, 3, RowPrio, u16>::zeros();
+    let mut dst_col = Stacktrix::<9, 3, 3, ColumnPrio, u16>::zeros();
+    src_row.gather_cols_into(&cols, &mut dst_row);
+    src_row.gather_cols_into(&cols, &mut dst_col);
+    assert_eq!((dst_row.to_rows(), dst_col.to_rows()), (expected, expected));
+    dst_row.clear();
+    dst_col.clear();
+    src_col.gather_cols_into(&cols, &mut dst_row);
+    src_col.gather_cols_into(&cols, &mut dst_col);
+    assert_eq!((dst_row.to_rows(), dst_col.to_rows()), (expected, expected));
+}
+
+#[test]
+fn block_matrix_bounds_without_overflow() {
+    let empty = Stacktrix::<0, 0, 2, RowPrio, u8>::zeros();
+    let m = BlockMatrix::<4, 2, 2, RowPrio, u8>::new(1)
+        .block((0, 0), &empty)
+        .unwrap()
+        .block((2, 0), &empty)
+        .unwrap()
+        .build();
+    assert_eq!(m.to_rows(), [[1, 1], [1, 1]]);
+    let a = Stacktrix::<2, 1, 2, RowPrio, u8>::with_values([1, 2]);
+    assert_eq!(
+        BlockMatrix::<4, 2, 2, RowPrio, u8>::new(0)
+            .block((usize::MAX, 0), &a)
+            .err(),
+        Some(MatrixError::OutOfBounds {
+            row: usize::MAX,
+            col: 2,
+            rows: 2,
+            cols: 2
+        })
+    );
+}
+
+#[test]
+fn fill_col_wrong_length_both_layouts() {
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, u8>::zeros();
+    let mut row = Stacktrix::<6, 2, 3, RowPrio, u8>::zeros();
+    let expected = Err(MatrixError::SizeMismatch {
+        expected: 2,
+        got: 3,
+    });
+    assert_eq!(col.try_fill_col(1, &[1, 2, 3]), expected);
+    assert_eq!(row.try_fill_col(1, &[1, 2, 3]), expected);
+    let col_panic = std::panic::catch_unwind(move || col.fill_col(1, &[1, 2, 3]));
+    let row_panic = std::panic::catch_unwind(move || row.fill_col(1, &[1, 2, 3]));
+    let message = |panic: Box<dyn std::any::Any + Send>| *panic.downcast::<String>().unwrap();
+    assert_eq!(
+        message(col_panic.unwrap_err()),
+        message(row_panic.unwrap_err())
+    );
+}
+
+#[test]
+fn gather_cols_into_without_rows() {
+    let src_row = Stacktrix::<0, 0, 4, RowPrio, u16>::zeros();
+    let src_col = Stacktrix::<0, 0, 4, ColumnPrio, u16>::zeros();
+    let mut dst_row = Stacktrix::<0, 0, 2, RowPrio, u16>::zeros();
+    let mut dst_col = Stacktrix::<0, 0, 2, ColumnPrio, u16>::zeros();
+    src_row.gather_cols_into(&[3, 0], &mut dst_row);
+    src_row.gather_cols_into(&[3, 0], &mut dst_col);
+    src_col.gather_cols_into(&[3, 0], &mut dst_row);
+    src_col.gather_cols_into(&[3, 0], &mut dst_col);
+    assert!(dst_row.as_slice().is_empty() && dst_col.as_slice().is_empty());
+}
+
+/// A matrix on the heap, standing in for storage defined outside of the crate.
+struct HeapMatrix<P> {
+    elements: Vec<i64>,
+    _prio: std::marker::PhantomData<P>,
+}
+
+impl<P> HeapMatrix<P> {
+    fn new() -> Self {
+        Self {
+            elements: vec![0; 6],
+            _prio: std::marker::PhantomData,
+        }
+    }
+}
+
+// SAFETY: the Vec always holds 2 * 3 elements.
+unsafe impl<P: mightrix::MemoryPriority> Storage<2, 3, i64> for HeapMatrix<P> {
+    type Priority = P;
+
+    fn buffer(&self) -> &[i64] {
+        &self.elements
+    }
+}
+
+// SAFETY: buffer_mut returns the same Vec as buffer.
+unsafe impl<P: mightrix::MemoryPriority> StorageMut<2, 3, i64> for HeapMatrix<P> {
+    fn buffer_mut(&mut self) -> &mut [i64] {
+        &mut self.elements
+    }
+}
+
+/// A read only matrix, e.g. in a mapped file.
+struct Frozen<'a>(&'a [i64]);
+
+// SAFETY: Frozen is only constructed from 6 elements.
+unsafe impl Storage<2, 3, i64> for Frozen<'_> {
+    type Priority = ColumnPrio;
+
+    fn buffer(&self) -> &[i64] {
+        self.0
+    }
+}
+
+#[test]
+fn user_storage_inherits_the_api() {
+    let source = Stacktrix::<6, 2, 3, RowPrio, i64>::from_rows([[1, -2, 3], [4, 5, -6]]);
+    let mut row = HeapMatrix::<RowPrio>::new();
+    let mut col = HeapMatrix::<ColumnPrio>::new();
+    source.convert_prio_into(&mut row);
+    source.convert_prio_into(&mut col);
+    row.fill_row(0, &[7, 7, 7]);
+    col.fill_row(0, &[7, 7, 7]);
+    assert_eq!(row.to_rows(), [[7, 7, 7], [4, 5, -6]]);
+    assert_eq!(col.to_rows(), row.to_rows());
+    assert_eq!(col.sum(), 24);
+    assert_eq!(row.argmin(), Some((1, 2)));
+    assert_eq!(col.elements, vec![7, 4, 7, 5, 7, -6]);
+
+    let frozen = Frozen(&col.elements);
+    assert_eq!(frozen.get_row(1), [4, 5, -6]);
+    assert_eq!(frozen.to_rows(), row.to_rows());
+    assert_eq!(frozen.view(1.., ..2).iter().sum::<i64>(), 9);
+    let mut copy = HeapMatrix::<RowPrio>::new();
+    frozen.convert_prio_into(&mut copy);
+    assert_eq!(copy.elements, row.elements);
+}
+
+#[test]
+fn stacktrix_references_cast_from_arrays() {
+    let mut data = [1u32, 2, 3, 4, 5, 6];
+    let row: &mut Stacktrix<6, 2, 3, RowPrio, u32> = Stacktrix::from_array_mut(&mut data);
+    row.fill_row(1, &[0, 0, 0]);
+    assert_eq!(row.as_array(), &[1, 2, 3, 0, 0, 0]);
+    let col: &Stacktrix<6, 2, 3, ColumnPrio, u32> = Stacktrix::from_array_ref(&data);
+    assert_eq!(col.to_rows(), [[1, 3, 0], [2, 0, 0]]);
+    assert_eq!(std::mem::size_of_val(col), std::mem::size_of_val(&data));
+}
+
+/// Sorts every 0-1 lane of length n, by the 0-1 principle the network then sorts everything.
+fn sorts_every_binary_lane<const S: usize, const N: usize, const L: usize>() {
+    let mut cols = Stacktrix::<S, N, L, ColumnPrio, u8>::from_fn(|r, c| (c >> r & 1) as u8);
+    let mut rows = Stacktrix::<S, L, N, RowPrio, u8>::from_fn(|r, c| (r >> c & 1) as u8);
+    cols.sort_cols_network();
+    rows.sort_rows_network();
+    for (lane, row) in rows.to_rows().iter().enumerate() {
+        let ones = lane.count_ones() as usize;
+        assert!(row
+            .iter()
+            .enumerate()
+            .all(|(i, &el)| el == u8::from(i >= N - ones)));
+        assert!((0..N).all(|i| *cols.get((i, lane)) == row[i]));
+    }
+}
+
+#[test]
+fn sorting_networks_sort_every_lane() {
+    sorts_every_binary_lane::<2, 1, 2>();
+    sorts_every_binary_lane::<8, 2, 4>();
+    sorts_every_binary_lane::<24, 3, 8>();
+    sorts_every_binary_lane::<64, 4, 16>();
+    sorts_every_binary_lane::<160, 5, 32>();
+    sorts_every_binary_lane::<384, 6, 64>();
+    sorts_every_binary_lane::<896, 7, 128>();
+    sorts_every_binary_lane::<2048, 8, 256>();
+    sorts_every_binary_lane::<4608, 9, 512>();
+    sorts_every_binary_lane::<10240, 10, 1024>();
+    sorts_every_binary_lane::<22528, 11, 2048>();
+    sorts_every_binary_lane::<49152, 12, 4096>();
+    sorts_every_binary_lane::<106496, 13, 8192>();
+}
+
+#[test]
+fn sorting_networks_keep_lanes_apart() {
+    let mut m = Stacktrix::<12, 3, 4, RowPrio, f64>::from_rows([
+        [4.0, -1.0, 0.5, 2.0],
+        [1.0, 3.0, -0.5, 2.0],
+        [2.5, 0.0, 9.0, 1.0],
+    ]);
+    m.sort_cols_network();
+    assert_eq!(
+        m.to_rows(),
+        [
+            [1.0, -1.0, -0.5, 1.0],
+            [2.5, 0.0, 0.5, 2.0],
+            [4.0, 3.0, 9.0, 2.0]
+        ]
+    );
+    m.sort_rows_network();
+    assert_eq!(
+        m.to_rows(),
+        [
+            [-1.0, -0.5, 1.0, 1.0],
+            [0.0, 0.5, 2.0, 2.5],
+            [2.0, 3.0, 4.0, 9.0]
+        ]
+    );
+}
+
+#[test]
+fn intermittent_slices_copy_into_contiguous_buffers() {
+    let mut m = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    #[cfg(feature = "alloc")]
+    {
+        assert_eq!(m.get_row(1).to_vec(), vec![4, 5, 6]);
+        assert_eq!(m.get_mut_row(0).to_vec(), vec![1, 2, 3]);
+    }
+    let mut row = [0; 3];
+    m.get_row(1).copy_to_slice(&mut row);
+    assert_eq!(row, [4, 5, 6]);
+    m.get_mut_row(0).clone_into(&mut row);
+    assert_eq!(row, [1, 2, 3]);
+    let numbers = Stacktrix::<6, 2, 3, RowPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let mut col = [0; 2];
+    numbers.get_column(2).copy_to_slice(&mut col);
+    assert_eq!(col, [3, 6]);
+}
+
+#[test]
+#[should_panic]
+fn intermittent_slice_copy_rejects_other_lengths() {
+    let m = Stacktrix::<4, 2, 2, ColumnPrio, u8>::zeros();
+    m.get_row(0).copy_to_slice(&mut [0; 3]);
+}
+
+#[test]
+fn intermittent_slices_are_overwritten_in_place() {
+    let mut m = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    m.get_mut_row(0).fill(9);
+    m.get_mut_row(1).copy_from_slice(&[-1, -2, -3]);
+    assert_eq!(m.to_rows(), [[9, 9, 9], [-1, -2, -3]]);
+    let mut r = Stacktrix::<6, 2, 3, RowPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    r.get_mut_column(1).fill(0);
+    r.fill_col(2, &[7, 8]);
+    assert_eq!(r.to_rows(), [[1, 0, 7], [4, 0, 8]]);
+}
+
+#[test]
+#[should_panic]
+fn column_prio_fill_row_rejects_other_lengths() {
+    let mut m = Stacktrix::<4, 2, 2, ColumnPrio, u8>::zeros();
+    m.fill_row(0, &[1, 2, 3]);
+}
+
+/// Sorts the K x K neighbourhood of (row, col) the slow way, clamping at the edges.
+fn sorted_neighbourhood<const R: usize, const C: usize>(
+    rows: &[[i32; C]; R],
+    row: usize,
+    col: usize,
+    k: usize,
+) -> Vec<i32> {
+    let radius = (k / 2) as isize;
+    let clamp = |index: isize, len: usize| index.clamp(0, len as isize - 1) as usize;
+    let mut window: Vec<_> = (-radius..=radius)
+        .flat_map(|dr| (-radius..=radius).map(move |dc| (dr, dc)))
+        .map(|(dr, dc)| rows[clamp(row as isize + dr, R)][clamp(col as isize + dc, C)])
+        .collect();
+    window.sort();
+    window
+}
+
+#[test]
+fn rank_filters_match_sorted_neighbourhoods() {
+    use mightrix::policy::Clamp;
+    let m = Stacktrix::<42, 6, 7, RowPrio, i32>::from_fn(|r, c| ((r * 37 + c * 11) % 17) as i32);
+    let rows = m.to_rows();
+    let mut median3 = Stacktrix::<42, 6, 7, ColumnPrio, i32>::zeros();
+    let mut median5 = Stacktrix::<42, 6, 7, RowPrio, i32>::zeros();
+    let mut low5 = Stacktrix::<42, 6, 7, ColumnPrio, i32>::zeros();
+    m.median_filter_into::<Clamp, 3, _>(&mut median3);
+    m.median_filter_into::<Clamp, 5, _>(&mut median5);
+    m.percentile_filter_into::<Clamp, 5, _>(10.0, &mut low5);
+    for row in 0..6 {
+        for col in 0..7 {
+            assert_eq!(
+                median3.to_rows()[row][col],
+                sorted_neighbourhood(&rows, row, col, 3)[4]
+            );
+            let window = sorted_neighbourhood(&rows, row, col, 5);
+            assert_eq!(median5.to_rows()[row][col], window[12]);
+            assert_eq!(low5.to_rows()[row][col], window[2]);
+        }
+    }
+    let mut same = Stacktrix::<42, 6, 7, RowPrio, i32>::zeros();
+    m.median_filter_into::<Clamp, 1, _>(&mut same);
+    assert_eq!(same, m);
+}
+
+#[test]
+#[should_panic]
+fn percentile_filter_rejects_percentiles_above_100() {
+    use mightrix::policy::Clamp;
+    let m = Stacktrix::<4, 2, 2, RowPrio, u8>::zeros();
+    let mut out = Stacktrix::<4, 2, 2, RowPrio, u8>::zeros();
+    m.percentile_filter_into::<Clamp, 3, _>(100.5, &mut out);
+}
+
+#[test]
+fn scaled_lanes_match_between_layouts() {
+    let rows = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    let mut row = Stacktrix::<9, 3, 3, RowPrio, i32>::from_rows(rows);
+    let mut col = Stacktrix::<9, 3, 3, ColumnPrio, i32>::from_rows(rows);
+    for (src, dst, k) in [(0, 2, 3), (2, 0, -1), (1, 1, 2)] {
+        row.add_scaled_row(src, dst, k);
+        col.add_scaled_row(src, dst, k);
+        row.add_scaled_col(dst, src, k);
+        col.add_scaled_col(dst, src, k);
+    }
+    row.copy_scaled_row(1, 0, 2);
+    col.copy_scaled_row(1, 0, 2);
+    row.copy_scaled_col(2, 2, -1);
+    col.copy_scaled_col(2, 2, -1);
+    assert_eq!(row.to_rows(), col.to_rows());
+    assert_eq!(row.to_rows(), [[132, 90, 96], [66, 45, 48], [64, 42, 46]]);
+}
+
+#[test]
+fn scaled_lanes_of_empty_lanes() {
+    let mut row = Stacktrix::<0, 0, 3, RowPrio, i32>::zeros();
+    row.add_scaled_col(1, 1, 2);
+    row.copy_scaled_col(1, 2, 2);
+    let mut col = Stacktrix::<0, 3, 0, ColumnPrio, i32>::zeros();
+    col.add_scaled_row(1, 1, 2);
+    col.copy_scaled_row(2, 1, 2);
+    assert!(row.as_slice().is_empty() && col.as_slice().is_empty());
+}
+
+#[test]
+#[should_panic]
+fn scaled_rows_reject_out_of_bounds_rows() {
+    let mut m = Stacktrix::<4, 2, 2, ColumnPrio, u8>::zeros();
+    m.add_scaled_row(0, 2, 1);
+}
+
+#[test]
+fn lane_iterators_are_exact_and_double_ended() {
+    let rows = [[1, 2, 3], [4, 5, 6]];
+    let mut row = Stacktrix::<6, 2, 3, RowPrio, i32>::from_rows(rows);
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows(rows);
+
+    let mut lanes = RowPrioMatrix::rows(&row);
+    assert_eq!(lanes.len(), 2);
+    assert_eq!(lanes.next_back(), Some(&[4, 5, 6][..]));
+    assert_eq!(lanes.len(), 1);
+    assert_eq!(lanes.next(), Some(&[1, 2, 3][..]));
+    assert_eq!(
+        (lanes.next(), lanes.next_back(), lanes.len()),
+        (None, None, 0)
+    );
+    let mut lanes = ColumnPrioMatrix::cols(&col);
+    assert_eq!(lanes.size_hint(), (3, Some(3)));
+    assert_eq!(lanes.next_back(), Some(&[3, 6][..]));
+    assert_eq!(lanes.rev().collect::<Vec<_>>(), [&[2, 5][..], &[1, 4][..]]);
+
+    let lanes = RowPrioMatrix::cols(&row);
+    assert_eq!(lanes.len(), 3);
+    let last: Vec<_> = lanes
+        .rev()
+        .map(|lane| lane.iter().rev().copied().collect::<Vec<_>>())
+        .collect();
+    assert_eq!(last, [[6, 3], [5, 2], [4, 1]]);
+    let mut lanes = ColumnPrioMatrix::rows(&col);
+    let second = lanes.next_back().unwrap();
+    let mut elements = second.iter();
+    assert_eq!(elements.len(), 3);
+    assert_eq!(
+        (elements.next_back(), elements.next()),
+        (Some(&6), Some(&4))
+    );
+    assert_eq!(elements.len(), 1);
+    assert_eq!(
+        (elements.next_back(), elements.next_back(), elements.next()),
+        (Some(&5), None, None)
+    );
+    assert_eq!(lanes.len(), 1);
+
+    for (lane, k) in RowPrioMatrix::rows_mut(&mut row).rev().zip([10, 100]) {
+        lane.iter_mut().for_each(|el| *el *= k);
+    }
+    for (mut lane, k) in ColumnPrioMatrix::rows_mut(&mut col).rev().zip([10, 100]) {
+        lane.iter_mut().rev().for_each(|el| *el *= k);
+    }
+    assert_eq!(row.to_rows(), [[100, 200, 300], [40, 50, 60]]);
+    assert_eq!(col.to_rows(), row.to_rows());
+    let mut lanes = ColumnPrioMatrix::cols_mut(&mut col);
+    lanes.next_back().unwrap().fill(0);
+    assert_eq!(lanes.len(), 2);
+    let mut lanes = RowPrioMatrix::cols_mut(&mut row);
+    lanes.next_back().unwrap().fill(0);
+    assert_eq!(lanes.len(), 2);
+    assert_eq!(col.to_rows(), [[100, 200, 0], [40, 50, 0]]);
+    assert_eq!(row.to_rows(), col.to_rows());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn orthonormalized_columns_match_between_layouts() {
+    let rows = [
+        [2.0, 1.0, 3.0, 0.5],
+        [0.0, 3.0, 3.0, -1.0],
+        [1.0, -1.0, 0.0, 2.0],
+        [4.0, 0.0, 4.0, 1.0],
+    ];
+    let mut row = Stacktrix::<16, 4, 4, RowPrio, f64>::from_rows(rows);
+    let mut col = Stacktrix::<16, 4, 4, ColumnPrio, f64>::from_rows(rows);
+    // The third column is the first plus the second.
+    assert_eq!(row.orthonormalize_cols(1e-9), 3);
+    assert_eq!(col.orthonormalize_cols(1e-9), 3);
+    let q = col.to_rows();
+    assert_eq!(row.to_rows(), q);
+    for a in 0..4 {
+        for b in 0..4 {
+            let dot: f64 = q.iter().map(|r| r[a] * r[b]).sum();
+            let expected = if a == b && a != 2 { 1.0 } else { 0.0 };
+            assert!((dot - expected).abs() < 1e-12, "({a}, {b}) = {dot}");
+        }
+    }
+    // The first basis vector keeps the direction of the first column.
+    assert!(q
+        .iter()
+        .zip(&rows)
+        .all(|(q, r)| (q[0] * 21f64.sqrt() - r[0]).abs() < 1e-12));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn stacktrix_collects_rows_of_several_matrices() {
+    let a = Stacktrix::<6, 2, 3, RowPrio, i32>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let b = Stacktrix::<6, 2, 3, ColumnPrio, i32>::from_rows([[7, 8, 9], [10, 11, 12]]);
+    let rows = RowPrioMatrix::rows(&a)
+        .map(|row| row.to_vec())
+        .chain(ColumnPrioMatrix::rows(&b).rev().map(|row| row.to_vec()));
+    let m = Stacktrix::<12, 4, 3, ColumnPrio, i32>::try_from_row_iter(rows).unwrap();
+    assert_eq!(m.to_rows(), [[1, 2, 3], [4, 5, 6], [10, 11, 12], [7, 8, 9]]);
+    let m = Stacktrix::<6, 2, 3, RowPrio, i32>::try_from_row_iter([b.get_row(1), b.get_row(1)]);
+    assert_eq!(m.unwrap().to_rows(), [[10, 11, 12], [10, 11, 12]]);
+
+    type Target = Stacktrix<6, 2, 3, RowPrio, i32>;
+    assert_eq!(
+        Target::try_from_row_iter([&[1, 2, 3][..], &[4, 5]]).err(),
+        Some(MatrixError::SizeMismatch {
+            expected: 3,
+            got: 2
+        })
+    );
+    assert_eq!(
+        Target::try_from_row_iter([&[1, 2, 3, 4][..], &[4, 5, 6]]).err(),
+        Some(MatrixError::SizeMismatch {
+            expected: 3,
+            got: 4
+        })
+    );
+    assert_eq!(
+        Target::try_from_row_iter([[1, 2, 3], [4, 5, 6], [7, 8, 9]]).err(),
+        Some(MatrixError::SizeMismatch {
+            expected: 2,
+            got: 3
+        })
+    );
+    assert_eq!(
+        Target::try_from_row_iter([&[1, 2, 3][..], &[4, 5, 6], &[]]).err(),
+        Some(MatrixError::SizeMismatch {
+            expected: 2,
+            got: 3
+        })
+    );
+}
+
+#[test]
+fn intermittent_slices_access_without_panicking() {
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let mut row = Stacktrix::<6, 2, 3, RowPrio, u8>::from_rows([[1, 2, 3], [4, 5, 6]]);
+    let strided = col.get_row(0);
+    assert_eq!((strided.len(), strided.is_empty()), (3, false));
+    assert_eq!((strided.get(1), strided.get(usize::MAX)), (Some(&2), None));
+    // The element outlives the temporary slice it was taken from.
+    let first = col.get_row(0).first();
+    assert_eq!(first, Some(&1));
+
+    let mut strided = row.get_mut_column(1);
+    assert_eq!(
+        (strided.len(), strided.first(), strided.last()),
+        (2, Some(&2), Some(&5))
+    );
+    assert_eq!(strided.get(2), None);
+    *strided.first_mut().unwrap() = 20;
+    *strided.get_mut(1).unwrap() = 50;
+    assert!(strided.get_mut(2).is_none());
+    assert_eq!(row.to_rows(), [[1, 20, 3], [4, 50, 6]]);
+    let mut strided = col.get_mut_row(1);
+    *strided.last_mut().unwrap() = 0;
+    assert_eq!(strided.last(), Some(&0));
+    assert_eq!(col.to_rows(), [[1, 2, 3], [4, 5, 0]]);
+}
+
+#[test]
+fn intermittent_slices_compare_with_slices() {
+    let mut col = Stacktrix::<8, 2, 4, ColumnPrio, u8>::from_rows([[1, 2, 3, 4], [7, 7, 7, 7]]);
+    let mut row = Stacktrix::<8, 2, 4, RowPrio, u8>::from_rows([[1, 2, 3, 4], [7, 7, 7, 7]]);
+    assert_eq!(col.get_row(1), [7, 7, 7, 7]);
+    assert_eq!(row.get_column(3), [4, 7]);
+    assert_eq!(col.get_row(0), RowPrioMatrix::get_row(&row, 0));
+    assert_ne!(col.get_row(0), [1, 2, 3]);
+    assert_ne!(col.get_row(0), [1, 2, 3, 4, 5]);
+    assert_ne!(col.get_row(0), [1, 2, 3, 5]);
+    assert_eq!(col.get_mut_row(1), vec![7, 7, 7, 7][..]);
+    assert_eq!(row.get_mut_column(0), [1, 7]);
+    assert_eq!(format!("{:?}", row.get_mut_column(1)), "[2, 7]");
+    assert_eq!(format!("{:?}", col.get_row(1)), "[7, 7, 7, 7]");
+}
+
+#[test]
+fn moved_lanes_match_vec_remove_and_insert() {
+    let rows: [[usize; 4]; 3] = core::array::from_fn(|r| core::array::from_fn(|c| r * 4 + c));
+    for from in 0..3 {
+        for to in 0..3 {
+            let mut row = Stacktrix::<12, 3, 4, RowPrio, usize>::from_rows(rows);
+            let mut col = Stacktrix::<12, 3, 4, ColumnPrio, usize>::from_rows(rows);
+            row.move_row(from, to);
+            col.move_row(from, to);
+            let mut expected = rows.to_vec();
+            let moved = expected.remove(from);
+            expected.insert(to, moved);
+            assert_eq!(row.to_rows().to_vec(), expected);
+            assert_eq!(col.to_rows().to_vec(), expected);
+        }
+    }
+    for from in 0..4 {
+        for to in 0..4 {
+            let mut row = Stacktrix::<12, 3, 4, RowPrio, usize>::from_rows(rows);
+            let mut col = Stacktrix::<12, 3, 4, ColumnPrio, usize>::from_rows(rows);
+            row.move_col(from, to);
+            col.move_col(from, to);
+            let expected: Vec<Vec<usize>> = rows
+                .iter()
+                .map(|r| {
+                    let mut r = r.to_vec();
+                    let moved = r.remove(from);
+                    r.insert(to, moved);
+                    r
+                })
+                .collect();
+            assert_eq!(row.to_rows().map(|r| r.to_vec()).to_vec(), expected);
+            assert_eq!(col.to_rows().map(|r| r.to_vec()).to_vec(), expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn move_col_rejects_out_of_bounds_columns() {
+    let mut m = Stacktrix::<4, 2, 2, RowPrio, u8>::zeros();
+    m.move_col(2, 0);
+}
+
+#[test]
+fn lane_permutations_are_found_across_layouts() {
+    let rows = [[5, 1, 2], [0, 0, 0], [5, 1, 2], [7, 8, 9]];
+    let a = Stacktrix::<12, 4, 3, RowPrio, i32>::from_rows(rows);
+    let mut b =
+        Stacktrix::<12, 4, 3, ColumnPrio, i32>::from_rows([rows[3], rows[2], rows[1], rows[0]]);
+    let p = a.row_permutation_of(&b).unwrap();
+    assert_eq!(p.indices(), &[1, 2, 3, 0]);
+    p.apply_to_rows(&mut b);
+    assert_eq!(b.to_rows(), rows);
+    assert!(b.is_row_permutation_of(&a));
+
+    let c = Stacktrix::<12, 4, 3, ColumnPrio, i32>::from_rows([rows[0], rows[1], rows[3], rows[3]]);
+    assert!(!a.is_row_permutation_of(&c));
+    assert!(!c.is_row_permutation_of(&a));
+
+    let mut d =
+        Stacktrix::<12, 4, 3, RowPrio, i32>::from_cols([[2, 0, 2, 9], [5, 0, 5, 7], [1, 0, 1, 8]]);
+    let p = a.col_permutation_of(&d).unwrap();
+    assert_eq!(p.indices(), &[1, 2, 0]);
+    p.apply_to_cols(&mut d);
+    assert_eq!(d, a);
+    assert_eq!(a.col_permutation_of(&b).unwrap().indices(), &[0, 1, 2]);
+    let e = Stacktrix::<12, 4, 3, ColumnPrio, i32>::from_cols([
+        [2, 0, 2, 9],
+        [5, 0, 5, 7],
+        [1, 0, 1, 9],
+    ]);
+    assert!(!e.is_col_permutation_of(&a));
+}
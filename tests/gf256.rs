@@ -0,0 +1,57 @@
+use mightrix::{
+    gf256::{self, inv_mix_single_column, mix_single_column},
+    ColumnPrio, ColumnPrioMatrix, Stacktrix,
+};
+
+#[test]
+fn tables_match_mul() {
+    for b in 0..=255u8 {
+        let i = b as usize;
+        assert_eq!(gf256::MUL2[i], gf256::xtime(b));
+        assert_eq!(gf256::MUL3[i], gf256::xtime(b) ^ b);
+        assert_eq!(gf256::MUL9[i], gf256::mul(b, 9));
+        assert_eq!(gf256::MUL11[i], gf256::mul(b, 11));
+        assert_eq!(gf256::MUL13[i], gf256::mul(b, 13));
+        assert_eq!(gf256::MUL14[i], gf256::mul(b, 14));
+    }
+}
+
+#[test]
+fn mix_columns_of_fips_197_state() {
+    // The state of the first round of the FIPS-197 appendix B example after ShiftRows.
+    let before = [
+        0xd4, 0xbf, 0x5d, 0x30, 0xe0, 0xb4, 0x52, 0xae, 0xb8, 0x41, 0x11, 0xf1, 0x1e, 0x27, 0x98,
+        0xe5,
+    ];
+    let mut state = Stacktrix::<16, 4, 4, ColumnPrio, u8>::with_values(before);
+    for col in 0..4 {
+        let col: &mut [u8; 4] = state.get_mut_column(col).try_into().unwrap();
+        mix_single_column(col);
+    }
+    assert_eq!(
+        state,
+        Stacktrix::<16, 4, 4, ColumnPrio, u8>::with_values([
+            0x04, 0x66, 0x81, 0xe5, 0xe0, 0xcb, 0x19, 0x9a, 0x48, 0xf8, 0xd3, 0x7a, 0x28, 0x06,
+            0x26, 0x4c,
+        ])
+    );
+    for col in 0..4 {
+        let col: &mut [u8; 4] = state.get_mut_column(col).try_into().unwrap();
+        inv_mix_single_column(col);
+    }
+    assert_eq!(
+        state,
+        Stacktrix::<16, 4, 4, ColumnPrio, u8>::with_values(before)
+    );
+}
+
+#[test]
+fn inv_mix_reverts_mix() {
+    for seed in 0..=255u8 {
+        let original = [seed, seed.wrapping_mul(7), seed ^ 0x5a, seed.rotate_left(3)];
+        let mut col = original;
+        mix_single_column(&mut col);
+        inv_mix_single_column(&mut col);
+        assert_eq!(col, original);
+    }
+}
@@ -0,0 +1,53 @@
+use mightrix::{
+    expand_key_aes192, expand_key_aes256, gf256, ColumnPrio, ColumnPrioMatrix, Stacktrix,
+};
+
+// The key expansion examples of FIPS-197 appendix A.
+
+#[test]
+fn aes192_key_schedule() {
+    let key = Stacktrix::<24, 4, 6, ColumnPrio, u8>::with_values([
+        0x8e, 0x73, 0xb0, 0xf7, 0xda, 0x0e, 0x64, 0x52, 0xc8, 0x10, 0xf3, 0x2b, 0x80, 0x90, 0x79,
+        0xe5, 0x62, 0xf8, 0xea, 0xd2, 0x52, 0x2c, 0x6b, 0x7b,
+    ]);
+    let round_keys = expand_key_aes192(&key);
+    // The words w4 and w5 end the key and start the second round key.
+    assert_eq!(round_keys[1].get_column(0), &[0x62, 0xf8, 0xea, 0xd2]);
+    assert_eq!(round_keys[1].get_column(2), &[0xfe, 0x0c, 0x91, 0xf7]);
+    assert_eq!(
+        round_keys[12],
+        Stacktrix::<16, 4, 4, ColumnPrio, u8>::with_values([
+            0xe9, 0x8b, 0xa0, 0x6f, 0x44, 0x8c, 0x77, 0x3c, 0x8e, 0xcc, 0x72, 0x04, 0x01, 0x00,
+            0x22, 0x02,
+        ])
+    );
+}
+
+#[test]
+fn aes256_key_schedule() {
+    let key = Stacktrix::<32, 4, 8, ColumnPrio, u8>::with_values([
+        0x60, 0x3d, 0xeb, 0x10, 0x15, 0xca, 0x71, 0xbe, 0x2b, 0x73, 0xae, 0xf0, 0x85, 0x7d, 0x77,
+        0x81, 0x1f, 0x35, 0x2c, 0x07, 0x3b, 0x61, 0x08, 0xd7, 0x2d, 0x98, 0x10, 0xa3, 0x09, 0x14,
+        0xdf, 0xf4,
+    ]);
+    let round_keys = expand_key_aes256(&key);
+    assert_eq!(round_keys[2].get_column(0), &[0x9b, 0xa3, 0x54, 0x11]);
+    // w12 is the first word that only goes through SubWord.
+    assert_eq!(round_keys[3].get_column(0), &[0xa8, 0xb0, 0x9c, 0x1a]);
+    assert_eq!(
+        round_keys[14],
+        Stacktrix::<16, 4, 4, ColumnPrio, u8>::with_values([
+            0xfe, 0x48, 0x90, 0xd1, 0xe6, 0x18, 0x8d, 0x0b, 0x04, 0x6d, 0xf3, 0x44, 0x70, 0x6c,
+            0x63, 0x1e,
+        ])
+    );
+}
+
+#[test]
+fn sub_byte_matches_sbox() {
+    for b in 0..=255u8 {
+        assert_eq!(gf256::sub_byte(b), gf256::SBOX[b as usize]);
+        assert_eq!(gf256::INV_SBOX[gf256::SBOX[b as usize] as usize], b);
+    }
+    assert_eq!(gf256::SBOX[0], 0x63);
+}
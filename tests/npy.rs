@@ -0,0 +1,81 @@
+#![cfg(feature = "npy")]
+use mightrix::{ColumnPrio, Export, Npy, RowPrio, Stacktrix};
+
+/// `np.save` of `np.arange(6, dtype='>i2').reshape(2, 3)` in Fortran order.
+fn numpy_file() -> Vec<u8> {
+    let header = "{'descr': '>i2', 'fortran_order': True, 'shape': (2, 3), }";
+    let mut file = b"\x93NUMPY\x01\x00".to_vec();
+    let padded = format!("{header:<117}\n");
+    file.extend((padded.len() as u16).to_le_bytes());
+    file.extend(padded.as_bytes());
+    for el in [0i16, 3, 1, 4, 2, 5] {
+        file.extend(el.to_be_bytes());
+    }
+    file
+}
+
+#[test]
+fn reads_numpy_files_into_both_layouts() {
+    let file = numpy_file();
+    let row = Stacktrix::<6, 2, 3, RowPrio, i16>::read_npy(&mut &file[..]).unwrap();
+    let mut col = Stacktrix::<6, 2, 3, ColumnPrio, i16>::zeros();
+    col.read_npy_into(&mut &file[..]).unwrap();
+    assert_eq!(row.to_rows(), [[0, 1, 2], [3, 4, 5]]);
+    assert_eq!(col.to_rows(), row.to_rows());
+}
+
+#[test]
+fn round_trips_both_layouts() {
+    let row =
+        Stacktrix::<6, 3, 2, RowPrio, f32>::from_rows([[1.5, -2.0], [0.0, 7.25], [9.0, 1e-3]]);
+    let mut file = Vec::new();
+    row.write_npy(&mut file).unwrap();
+    assert_eq!((file.len() - 6 * 4) % 64, 0);
+    let col = Stacktrix::<6, 3, 2, ColumnPrio, f32>::read_npy(&mut &file[..]).unwrap();
+    assert_eq!(col, row);
+    let mut again = Vec::new();
+    col.write_npy(&mut again).unwrap();
+    let back = Stacktrix::<6, 3, 2, RowPrio, f32>::read_npy(&mut &again[..]).unwrap();
+    assert_eq!(back.as_slice(), row.as_slice());
+}
+
+#[test]
+fn rejects_other_dtypes_and_shapes() {
+    let file = numpy_file();
+    let dtype = Stacktrix::<6, 2, 3, RowPrio, u16>::read_npy(&mut &file[..]).unwrap_err();
+    assert_eq!(dtype.kind(), std::io::ErrorKind::InvalidData);
+    let shape = Stacktrix::<4, 2, 2, RowPrio, i16>::read_npy(&mut &file[..]).unwrap_err();
+    assert_eq!(shape.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(
+        shape.to_string(),
+        "expected shape (2, 2) but the file holds [2, 3]"
+    );
+    assert!(Stacktrix::<6, 2, 3, RowPrio, i16>::read_npy(&mut &file[..20]).is_err());
+}
+
+#[test]
+fn rejects_transposed_shapes() {
+    let file = numpy_file();
+    let transposed = Stacktrix::<6, 3, 2, RowPrio, i16>::read_npy(&mut &file[..]).unwrap_err();
+    assert_eq!(transposed.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(
+        transposed.to_string(),
+        "expected shape (3, 2) but the file holds [2, 3]"
+    );
+}
+
+#[test]
+fn rejects_malformed_headers() {
+    let header = "{'descr': '<i2', 'fortran_order': False, 'shape': (4294967296, 4294967296), }";
+    let mut file = b"\x93NUMPY\x01\x00".to_vec();
+    file.extend((header.len() as u16).to_le_bytes());
+    file.extend(header.as_bytes());
+    let huge = Stacktrix::<6, 2, 3, RowPrio, i16>::read_npy(&mut &file[..]).unwrap_err();
+    assert_eq!(huge.kind(), std::io::ErrorKind::InvalidData);
+
+    // A version 2 header claiming to be 4 GiB long.
+    let mut file = b"\x93NUMPY\x02\x00".to_vec();
+    file.extend(u32::MAX.to_le_bytes());
+    let long = Stacktrix::<6, 2, 3, RowPrio, i16>::read_npy(&mut &file[..]).unwrap_err();
+    assert_eq!(long.kind(), std::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,30 @@
+#![cfg(all(feature = "arbitrary", feature = "proptest"))]
+use arbitrary::{Arbitrary, Unstructured};
+use mightrix::{ColumnPrio, Export, RowPrio, Stacktrix};
+use proptest::prelude::*;
+
+#[test]
+fn arbitrary_fills_memory_order() {
+    let bytes = [1, 2, 3, 4, 5, 6];
+    let col =
+        Stacktrix::<6, 2, 3, ColumnPrio, u8>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+    assert_eq!(col.as_slice(), &bytes);
+    assert_eq!(
+        Stacktrix::<6, 2, 3, RowPrio, u16>::size_hint(0),
+        (12, Some(12))
+    );
+}
+
+proptest! {
+    #[test]
+    fn convert_prio_keeps_logical_order(row in any::<Stacktrix<12, 3, 4, RowPrio, i64>>()) {
+        let mut col = Stacktrix::<12, 3, 4, ColumnPrio, i64>::zeros();
+        row.convert_prio_into(&mut col);
+        prop_assert_eq!(col.to_rows(), row.to_rows());
+    }
+
+    #[test]
+    fn strategy_draws_from_element(m in Stacktrix::<4, 2, 2, ColumnPrio, u8>::strategy(5..9u8)) {
+        prop_assert!(m.as_slice().iter().all(|el| (5..9).contains(el)));
+    }
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "rand")]
+use mightrix::{ColumnPrio, Export, RandomFill, Reftrix, RowPrio, Stacktrix};
+use rand::{distr::Uniform, rngs::SmallRng, SeedableRng};
+
+#[test]
+fn same_rng_state_same_matrix_both_layouts() {
+    let distribution = Uniform::new(10u16, 20).unwrap();
+    let row = Stacktrix::<12, 3, 4, RowPrio, u16>::random_with(
+        &distribution,
+        &mut SmallRng::seed_from_u64(3),
+    );
+    let mut data = [0u16; 12];
+    let mut col = Reftrix::<3, 4, ColumnPrio, u16>::from_array_mut(&mut data);
+    col.fill_random_with(&distribution, &mut SmallRng::seed_from_u64(3));
+    assert_eq!(row.to_rows(), col.to_rows());
+    assert!(row.as_slice().iter().all(|el| (10..20).contains(el)));
+    let mut rng = SmallRng::seed_from_u64(3);
+    col.fill_random_with(&distribution, &mut rng);
+    col.fill_random_with(&distribution, &mut rng);
+    assert_ne!(row.to_rows(), col.to_rows());
+}
+
+#[test]
+fn standard_uniform_fills_every_element() {
+    let mut rng = SmallRng::seed_from_u64(11);
+    let mut m = Stacktrix::<64, 8, 8, ColumnPrio, f64>::zeros();
+    m.fill_random(&mut rng);
+    assert!(m.as_slice().iter().all(|&el| el > 0.0 && el < 1.0));
+    let flags = Stacktrix::<64, 8, 8, RowPrio, bool>::random(&mut rng);
+    assert!(flags.as_slice().contains(&true) && flags.as_slice().contains(&false));
+}
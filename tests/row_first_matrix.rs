@@ -1,4 +1,6 @@
-use mightrix::{Reftrix, RowPrio, Stacktrix, RowPrioMatrix};
+// The original tests pass their buffers as &mut, Stacktrix only needs a shared borrow.
+#![allow(clippy::unnecessary_mut_passed)]
+use mightrix::{Reftrix, RowPrio, RowPrioMatrix, Stacktrix};
 
 // A Row first Matrix
 // 01-01-01-01
@@ -56,3 +58,35 @@ fn row_out_of_bounds_row_ref() {
     let m = Reftrix::<4, 4, RowPrio, u8>::from_values(&mut values);
     m.get_row(4);
 }
+
+#[test]
+fn insert_stack() {
+    let values = vec![1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4];
+    let mut m = Stacktrix::<16, 4, 4, RowPrio, u8>::from_values(&values);
+    m.insert((3, 1), 0);
+    assert_eq!(m.get((3, 1)), &0);
+    assert_eq!(m.get((0, 2)), &1);
+}
+
+#[test]
+fn fill_stack() {
+    let values = vec![1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4];
+    let mut m = Stacktrix::<16, 4, 4, RowPrio, u8>::from_values(&values);
+    m.fill_row(1, &[7, 7, 7, 7]);
+    assert_eq!(m.get_row(1), &[7, 7, 7, 7]);
+    m.fill_col(2, &[9, 9, 9, 9]);
+    for row in 0..4 {
+        assert_eq!(m.get((row, 2)), &9);
+    }
+}
+
+#[test]
+fn apply_all_stack() {
+    let values = vec![1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4];
+    let mut m = Stacktrix::<16, 4, 4, RowPrio, u8>::from_values(&values);
+    m.apply_all(|el| *el *= 2);
+    assert_eq!(m.get_row(0), &[2, 2, 2, 2]);
+    assert_eq!(m.get_row(1), &[4, 4, 4, 4]);
+    assert_eq!(m.get_row(2), &[6, 6, 6, 6]);
+    assert_eq!(m.get_row(3), &[8, 8, 8, 8]);
+}
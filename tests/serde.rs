@@ -0,0 +1,49 @@
+#![cfg(feature = "serde")]
+use mightrix::{
+    ColumnPrio, ColumnPrioMatrix, MatrixPatch, Patch, Reftrix, RowPrio, RowPrioMatrix, Stacktrix,
+};
+
+#[test]
+fn stacktrix_round_trip() {
+    let m = Stacktrix::<6, 2, 3, RowPrio, u8>::with_values([1, 2, 3, 4, 5, 6]);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(
+        json,
+        r#"{"rows":2,"cols":3,"layout":"RowPrio","data":[1,2,3,4,5,6]}"#
+    );
+    let back: Stacktrix<6, 2, 3, RowPrio, u8> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, m);
+}
+
+#[test]
+fn reftrix_snapshot_changes_layout() {
+    let mut data = vec![1, 2, 3, 4, 5, 6];
+    let m = Reftrix::<2, 3, ColumnPrio, u16>::from_values(&mut data);
+    let json = serde_json::to_string(&m).unwrap();
+    let snapshot: Stacktrix<6, 2, 3, RowPrio, u16> = serde_json::from_str(&json).unwrap();
+    assert_eq!(snapshot, m);
+    assert_eq!(snapshot.get_row(0), &[1, 3, 5]);
+    assert_eq!(ColumnPrioMatrix::get_column(&m, 2), &[5, 6]);
+}
+
+#[test]
+fn rejects_wrong_shape() {
+    let json = r#"{"rows":3,"cols":2,"layout":"RowPrio","data":[1,2,3,4,5,6]}"#;
+    assert!(serde_json::from_str::<Stacktrix<6, 2, 3, RowPrio, u8>>(json).is_err());
+    let json = r#"{"rows":2,"cols":3,"layout":"RowPrio","data":[1,2,3]}"#;
+    assert!(serde_json::from_str::<Stacktrix<6, 2, 3, RowPrio, u8>>(json).is_err());
+    let json = r#"{"rows":2,"cols":3,"layout":"Diagonal","data":[1,2,3,4,5,6]}"#;
+    assert!(serde_json::from_str::<Stacktrix<6, 2, 3, RowPrio, u8>>(json).is_err());
+}
+
+#[test]
+fn patch_round_trip() {
+    let old = Stacktrix::<4, 2, 2, RowPrio, u64>::zeros();
+    let new = Stacktrix::<4, 2, 2, RowPrio, u64>::with_values([0, 0, 5, 0]);
+    let json = serde_json::to_string(&new.diff_patch(&old)).unwrap();
+    assert_eq!(json, r#"{"Cells":{"rows":2,"cols":2,"cells":[[[1,0],5]]}}"#);
+    let patch: MatrixPatch<u64> = serde_json::from_str(&json).unwrap();
+    let mut synced = Stacktrix::<4, 2, 2, ColumnPrio, u64>::zeros();
+    synced.apply_patch(&patch).unwrap();
+    assert_eq!(synced, new);
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "verify")]
+use mightrix::{ColumnPrio, Reftrix, RowPrio, Shadow, Stacktrix};
+
+#[test]
+fn layout_operations_agree_with_reference() {
+    let mut data = [0u16; 12];
+    let mut col = Shadow::new(Reftrix::<3, 4, ColumnPrio, u16>::from_values(&mut data[..]));
+    let mut row = Shadow::new(Stacktrix::<12, 3, 4, RowPrio, u16>::zeros());
+    for (r, values) in [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]
+        .iter()
+        .enumerate()
+    {
+        col.fill_row(r, values);
+        row.fill_row(r, values);
+    }
+    col.fill_col(3, &[0, 0, 1]);
+    row.fill_col(3, &[0, 0, 1]);
+    col.insert((1, 2), 42);
+    row.insert((1, 2), 42);
+    col.apply_all(|el| *el = el.wrapping_mul(3));
+    row.apply_all(|el| *el = el.wrapping_mul(3));
+    assert_eq!(col.reference(), row.reference());
+    assert_eq!(row.reference()[1], [15, 18, 126, 0]);
+}
+
+#[test]
+#[should_panic(expected = "broken fill diverged from the reference at (0, 1)")]
+fn divergence_panics_with_operation_and_location() {
+    let mut shadow = Shadow::new(Stacktrix::<4, 2, 2, ColumnPrio, u8>::zeros());
+    // Writes the second element of the buffer, which is (1, 0) and not (0, 1).
+    shadow.apply("broken fill", |m| m.as_mut_slice()[1] = 1, |r| r[0][1] = 1);
+}